@@ -0,0 +1,68 @@
+use crate::traits::{
+    BasicGameState, BasicSerializedGameState, GameRulesAuthority, GameStateSerializer,
+};
+use std::collections::HashSet;
+
+/// Finds every game state reachable from the given one in which a player gets to pick their move,
+/// each along with the index of that player, by walking the whole game tree.
+/// Game states reached through different orders of moves are only found once, as told apart by their serialized game states.
+/// Chance events are branched on and forced passes are played out, as neither of them leaves a player anything to pick.
+pub fn find_decision_game_states<
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+>(
+    game_rules_authority: &dyn GameRulesAuthority<GameState>,
+    game_state_serializer: &dyn GameStateSerializer<GameState, SerializedGameState>,
+    number_of_players: i32,
+    current_player_index: i32,
+    game_state: &GameState,
+) -> Vec<(i32, GameState)> {
+    let mut decision_game_states: Vec<(i32, GameState)> = vec![];
+    let mut seen_serialized_game_states: HashSet<SerializedGameState> = HashSet::new();
+    let mut game_states_to_visit: Vec<(i32, GameState)> =
+        vec![(current_player_index, game_state.clone())];
+
+    while let Some((current_player_index, game_state)) = game_states_to_visit.pop() {
+        // the player responsible for the game state is the one before the player about to move
+        let responsible_player_index =
+            (current_player_index + number_of_players - 1) % number_of_players;
+        if !seen_serialized_game_states.insert(
+            game_state_serializer.serialize_game_state(responsible_player_index, &game_state),
+        ) {
+            continue;
+        }
+
+        if game_rules_authority
+            .analyze_game_state_for_terminality(&game_state, current_player_index)
+            .is_some()
+        {
+            continue;
+        }
+
+        let chance_outcomes =
+            game_rules_authority.find_chance_outcomes(current_player_index, &game_state);
+        if !chance_outcomes.is_empty() {
+            for (chance_outcome, _) in chance_outcomes.into_iter() {
+                game_states_to_visit.push((current_player_index, chance_outcome));
+            }
+            continue;
+        }
+
+        let next_player_index = (current_player_index + 1) % number_of_players;
+
+        if game_rules_authority.is_passing_forced(current_player_index, &game_state) {
+            game_states_to_visit.push((next_player_index, game_state));
+            continue;
+        }
+
+        for next_game_state in game_rules_authority
+            .find_available_next_game_states(current_player_index, &game_state)
+            .into_iter()
+        {
+            game_states_to_visit.push((next_player_index, next_game_state));
+        }
+        decision_game_states.push((current_player_index, game_state));
+    }
+
+    return decision_game_states;
+}
@@ -0,0 +1,5 @@
+mod find_decision_game_states;
+pub use find_decision_game_states::find_decision_game_states;
+
+mod perft;
+pub use perft::{perft, perft_divide};
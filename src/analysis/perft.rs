@@ -0,0 +1,96 @@
+use crate::traits::{BasicGameState, GameRulesAuthority};
+
+/// Counts the leaf nodes of the game tree to the given depth, in moves, from the given game state
+/// with the given player about to move. Known perft ("performance test") counts of a game
+/// make for a thorough check of its rules, as a single missing or extra move throws the count off.
+/// Games that are over before reaching the given depth don't count as leaf nodes.
+/// Forced passes count as moves, while chance events are branched on without using up any of the depth.
+pub fn perft<GameState: BasicGameState>(
+    game_rules_authority: &dyn GameRulesAuthority<GameState>,
+    number_of_players: i32,
+    current_player_index: i32,
+    game_state: &GameState,
+    depth: u32,
+) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    if game_rules_authority
+        .analyze_game_state_for_terminality(game_state, current_player_index)
+        .is_some()
+    {
+        return 0;
+    }
+
+    let chance_outcomes =
+        game_rules_authority.find_chance_outcomes(current_player_index, game_state);
+    if !chance_outcomes.is_empty() {
+        return chance_outcomes
+            .iter()
+            .map(|(chance_outcome, _)| {
+                return perft(
+                    game_rules_authority,
+                    number_of_players,
+                    current_player_index,
+                    chance_outcome,
+                    depth,
+                );
+            })
+            .sum();
+    }
+
+    let next_player_index = (current_player_index + 1) % number_of_players;
+
+    if game_rules_authority.is_passing_forced(current_player_index, game_state) {
+        return perft(
+            game_rules_authority,
+            number_of_players,
+            next_player_index,
+            game_state,
+            depth - 1,
+        );
+    }
+
+    return game_rules_authority
+        .find_available_next_game_states(current_player_index, game_state)
+        .iter()
+        .map(|next_game_state| {
+            return perft(
+                game_rules_authority,
+                number_of_players,
+                next_player_index,
+                next_game_state,
+                depth - 1,
+            );
+        })
+        .sum();
+}
+
+/// Splits the perft count by the available next game states of the player about to move,
+/// which tells the moves with a wrong count apart when comparing against known counts.
+/// The given game state must not have a chance event pending.
+pub fn perft_divide<GameState: BasicGameState>(
+    game_rules_authority: &dyn GameRulesAuthority<GameState>,
+    number_of_players: i32,
+    current_player_index: i32,
+    game_state: &GameState,
+    depth: u32,
+) -> Vec<(GameState, u64)> {
+    let next_player_index = (current_player_index + 1) % number_of_players;
+
+    return game_rules_authority
+        .find_available_next_game_states(current_player_index, game_state)
+        .into_iter()
+        .map(|next_game_state| {
+            let leaf_nodes_count = perft(
+                game_rules_authority,
+                number_of_players,
+                next_player_index,
+                &next_game_state,
+                depth.max(1) - 1,
+            );
+            return (next_game_state, leaf_nodes_count);
+        })
+        .collect();
+}
@@ -0,0 +1,31 @@
+use crate::structs::GameReport;
+use crate::traits::{BasicSerializedGameState, GameReportsProcessor};
+
+/// Prints the notations of the actions played in each game, for games whose actions the game runner records.
+pub struct ActionsPrintingGameReportsProcessor {}
+
+impl ActionsPrintingGameReportsProcessor {
+    pub fn new() -> ActionsPrintingGameReportsProcessor {
+        return ActionsPrintingGameReportsProcessor {};
+    }
+}
+
+impl<SerializedGameState: BasicSerializedGameState, ErrorType>
+    GameReportsProcessor<SerializedGameState, ErrorType> for ActionsPrintingGameReportsProcessor
+{
+    fn process_game_report(
+        &self,
+        game_report: &mut GameReport<SerializedGameState>,
+    ) -> Result<(), ErrorType> {
+        if let Some(actions) = game_report.actions.as_ref() {
+            let action_notations: Vec<&str> = actions
+                .iter()
+                .flatten()
+                .map(|(action_notation, _)| &action_notation[..])
+                .collect();
+            println!("Actions played: {}", action_notations.join(" "));
+        }
+
+        return Ok(());
+    }
+}
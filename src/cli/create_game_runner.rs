@@ -0,0 +1,33 @@
+use crate::game_runners::{SimultaneousGameRunner, StandardTurnBasedGameRunner};
+use crate::traits::{BasicGameState, BasicSerializedGameState, GameDefinition, GameRunner};
+
+/// Creates the game runner for the given game, depending on whether its players take turns or all move at once.
+pub fn create_game_runner<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+>(
+    game_definition: &'a dyn GameDefinition<GameState, SerializedGameState>,
+) -> Box<dyn GameRunner<GameState, SerializedGameState> + 'a> {
+    let game_state_serializer = game_definition.get_game_state_serializer();
+
+    return match game_definition.get_simultaneous_game_rules_authority() {
+        Some(simultaneous_game_rules_authority) => Box::new(SimultaneousGameRunner::new(
+            simultaneous_game_rules_authority,
+            game_state_serializer,
+        )),
+        None => match game_definition.get_game_action_notations_finder() {
+            Some(game_action_notations_finder) => {
+                Box::new(StandardTurnBasedGameRunner::new_with_action_notations(
+                    game_definition.get_game_rules_authority(),
+                    game_state_serializer,
+                    game_action_notations_finder,
+                ))
+            }
+            None => Box::new(StandardTurnBasedGameRunner::new(
+                game_definition.get_game_rules_authority(),
+                game_state_serializer,
+            )),
+        },
+    };
+}
@@ -0,0 +1,36 @@
+use crate::composites::CanonicalizingGameStateSerializer;
+use crate::persistence::SqliteGameStateRecordsDAL;
+use crate::traits::{BasicGameState, BasicSerializedGameState, GameDefinition};
+
+/// Creates the DAL of the game state records of the given game, along with the serializer the records are looked up by
+/// when the game has symmetries, since equivalent game states by the game's symmetries share their game state records.
+/// Games without symmetries look their records up by their own game state serializer.
+pub fn create_game_state_records_dal<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState + Ord,
+>(
+    game_definition: &'a dyn GameDefinition<GameState, SerializedGameState>,
+    sqlite_db_path: &str,
+) -> (
+    SqliteGameStateRecordsDAL,
+    Option<CanonicalizingGameStateSerializer<'a, GameState, SerializedGameState>>,
+) {
+    let game_state_records_dal = SqliteGameStateRecordsDAL::new(
+        game_definition.get_name(),
+        game_definition.get_game_state_records_version(),
+        sqlite_db_path,
+    )
+    .expect("Failed to create SqliteGameStateRecordsDAL.");
+
+    let canonicalizing_game_state_serializer = game_definition
+        .get_game_state_symmetries_finder()
+        .map(|game_state_symmetries_finder| {
+            CanonicalizingGameStateSerializer::new(
+                game_definition.get_game_state_serializer(),
+                game_state_symmetries_finder,
+            )
+        });
+
+    return (game_state_records_dal, canonicalizing_game_state_serializer);
+}
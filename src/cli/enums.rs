@@ -1,35 +1,99 @@
+use serde::Deserialize;
 use std::str::FromStr;
 
+#[derive(Clone, Debug)]
+pub enum AgentKind {
+    /// Picks the best weighted move according to the neural net with the weights from the given file.
+    #[cfg(feature = "torch")]
+    Cnn(String),
+    /// Picks the best weighted move according to the recorded game outcomes.
+    Greedy,
+    /// Picks moves uniformly at random.
+    Random,
+    /// Picks moves at random, in proportion to their weights according to the recorded game outcomes.
+    WeightedRandom,
+}
+
+impl FromStr for AgentKind {
+    type Err = ();
+    fn from_str(src: &str) -> Result<AgentKind, ()> {
+        return match src {
+            "greedy" => Ok(AgentKind::Greedy),
+            "random" => Ok(AgentKind::Random),
+            "weighted-random" => Ok(AgentKind::WeightedRandom),
+            #[cfg(feature = "torch")]
+            _ if src.starts_with("cnn:") => Ok(AgentKind::Cnn(String::from(&src[4..]))),
+            _ => Err(()),
+        };
+    }
+}
+
 #[derive(Debug)]
 pub enum Command {
+    EvaluateRecords,
     InteractiveGame,
+    Perft,
     SimulateGames,
+    Tournament,
 }
 
 impl FromStr for Command {
     type Err = ();
     fn from_str(src: &str) -> Result<Command, ()> {
         return match src {
+            "evaluate-records" => Ok(Command::EvaluateRecords),
             "interactive-game" => Ok(Command::InteractiveGame),
+            "perft" => Ok(Command::Perft),
             "simulate-games" => Ok(Command::SimulateGames),
+            "tournament" => Ok(Command::Tournament),
             _ => Err(()),
         };
     }
 }
 
-#[derive(Debug)]
-pub enum Game {
-    Checkers,
-    TicTacToe,
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum GameReportsProcessorKind {
+    /// Logs every game to the database.
+    GameReportsLog,
+    /// Records the outcomes of games for every game state they went through.
+    GameStateRecords,
+    /// Trains the policy and value net on the searches of MCTS players and the outcomes of their games.
+    PolicyValueNetTrainer,
+    /// Trains the value net on the outcomes of games.
+    ValueNetTrainer,
 }
 
-impl FromStr for Game {
-    type Err = ();
-    fn from_str(src: &str) -> Result<Game, ()> {
-        return match src {
-            "checkers" => Ok(Game::Checkers),
-            "tic-tac-toe" => Ok(Game::TicTacToe),
-            _ => Err(()),
-        };
-    }
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case", tag = "kind")]
+pub enum TurnTakerConfig {
+    BestWeight,
+    Mcts {
+        exploration_constant: f32,
+        simulations: u32,
+        /// 0 always plays the most visited move, 1 samples moves in proportion to their visits.
+        #[serde(default)]
+        temperature: f32,
+    },
+    Minimax {
+        depth: u32,
+        /// Searches one ply deeper at a time within this budget instead of searching the full depth right away.
+        time_budget_ms: Option<u64>,
+    },
+    WeightedRandom,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case", tag = "kind")]
+pub enum WeightsCalculatorConfig {
+    GameStateRecords {
+        draws_weight: f32,
+        losses_weight: f32,
+        visits_deficit_weight: f32,
+        wins_weight: f32,
+    },
+    /// Also guides MCTS players with the policy of the net.
+    PolicyValueNet,
+    Uniform,
+    ValueNet,
 }
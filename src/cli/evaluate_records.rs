@@ -0,0 +1,222 @@
+use crate::analysis::find_decision_game_states;
+use crate::cli::create_game_state_records_dal::create_game_state_records_dal;
+use crate::cli::structs::{EvaluateRecordsOptions, RecordValuesWeights};
+use crate::cli::GameRegistry;
+use crate::game_state_records_providers::LruCacheFrontedGameStateRecordsProvider;
+use crate::traits::{
+    BasicGameState, ByteArrayConvertibleSerializedGameState, GameDefinition, GameStateSerializer,
+    GameStateWeightsCalculator, TurnTaker,
+};
+use crate::turn_takers::BestWeightSelectionTurnTaker;
+use crate::weights_calculators::{
+    RecordValuesWeightedSumGameStateWeightsCalculator, SolvedGameStateWeightsCalculator,
+};
+use std::time::Instant;
+
+pub fn evaluate_records(args: Vec<String>, game_registry: &GameRegistry) -> Result<(), ()> {
+    let mut game_name = String::new();
+    let mut draws_weight = 5.0;
+    let mut losses_weight = -10.0;
+    let mut wins_weight = 10.0;
+    let mut visits_deficit_weight = 20.0;
+    let mut sqlite_db_path = String::from("./GamesHistory.db");
+
+    {
+        let game_help = format!(
+            "Game to run (either {})",
+            game_registry.describe_game_names()
+        );
+        let mut arg_parser = argparse::ArgumentParser::new();
+
+        arg_parser.refer(&mut game_name).required().add_option(
+            &["-g", "--game"],
+            argparse::Store,
+            &game_help,
+        );
+
+        arg_parser.refer(&mut draws_weight).add_option(
+            &["--draws-weight"],
+            argparse::Parse,
+            "Weight of draws for state decisions",
+        );
+
+        arg_parser.refer(&mut losses_weight).add_option(
+            &["--losses-weight"],
+            argparse::Parse,
+            "Weight of losses for state decisions",
+        );
+
+        arg_parser.refer(&mut wins_weight).add_option(
+            &["--wins-weight"],
+            argparse::Parse,
+            "Weight of wins for state decisions",
+        );
+
+        arg_parser.refer(&mut visits_deficit_weight).add_option(
+            &["--visits-deficit-weight"],
+            argparse::Parse,
+            "Weight of visits deficit for state decisions",
+        );
+
+        arg_parser.refer(&mut sqlite_db_path).add_option(
+            &["--sqlite-db-path"],
+            argparse::Store,
+            "Path to the sqlite database holding the game state records",
+        );
+
+        match arg_parser.parse(args, &mut std::io::stdout(), &mut std::io::stderr()) {
+            Ok(()) => (),
+            Err(x) => {
+                println!("Failed to parse arguments, please try again");
+                std::process::exit(x);
+            }
+        }
+    }
+
+    let registered_game = match game_registry.find_registered_game(&game_name) {
+        Some(registered_game) => registered_game,
+        None => {
+            println!(
+                "Unknown game {}, expected either {}.",
+                game_name,
+                game_registry.describe_game_names()
+            );
+            return Err(());
+        }
+    };
+
+    return registered_game.run_evaluate_records(&EvaluateRecordsOptions {
+        record_values_weights: RecordValuesWeights {
+            draws_weight: draws_weight,
+            losses_weight: losses_weight,
+            visits_deficit_weight: visits_deficit_weight,
+            wins_weight: wins_weight,
+        },
+        sqlite_db_path: sqlite_db_path,
+    });
+}
+
+/// Measures how close the recorded game outcomes are to perfect play, by solving every game state
+/// in which a player gets to pick their move and counting how often picking the best weighted move
+/// according to the records gives away some of the value of the game state.
+pub fn run_evaluate_records<
+    GameState: BasicGameState,
+    SerializedGameState: ByteArrayConvertibleSerializedGameState + Ord + Send + Sync,
+>(
+    game_definition: &dyn GameDefinition<GameState, SerializedGameState>,
+    options: &EvaluateRecordsOptions,
+) -> Result<(), ()> {
+    let game_name = game_definition.get_name();
+
+    if !game_definition.is_solvable() {
+        println!(
+            "{} is too big for its game states to be solved by searching its whole game tree.",
+            game_name
+        );
+        return Err(());
+    }
+
+    let lru_cache_max_capacity: usize = 1_000_000;
+    let (game_state_records_dal, canonicalizing_game_state_serializer) =
+        create_game_state_records_dal(game_definition, &options.sqlite_db_path);
+    let game_state_records_provider = LruCacheFrontedGameStateRecordsProvider::new(
+        lru_cache_max_capacity,
+        &game_state_records_dal,
+    );
+
+    let game_rules_authority = game_definition.get_game_rules_authority();
+    let game_state_serializer = game_definition.get_game_state_serializer();
+    let game_state_records_serializer: &(dyn GameStateSerializer<GameState, SerializedGameState>
+          + Sync) = match &canonicalizing_game_state_serializer {
+        Some(canonicalizing_game_state_serializer) => canonicalizing_game_state_serializer,
+        None => game_state_serializer,
+    };
+
+    let record_values_weights = options.record_values_weights;
+    let game_state_records_weights_calculator =
+        RecordValuesWeightedSumGameStateWeightsCalculator::new(
+            &game_state_records_provider,
+            game_state_records_serializer,
+            record_values_weights.draws_weight,
+            record_values_weights.losses_weight,
+            record_values_weights.wins_weight,
+            record_values_weights.visits_deficit_weight,
+        );
+    let solved_weights_calculator =
+        SolvedGameStateWeightsCalculator::new(game_rules_authority, game_state_serializer);
+
+    let start_instant = Instant::now();
+    let initial_game_state = game_definition.create_initial_game_state();
+    let decision_game_states = find_decision_game_states(
+        game_rules_authority,
+        game_state_serializer,
+        game_definition.get_number_of_players(),
+        0,
+        &initial_game_state,
+    );
+    // the first player to move picks the best of the moves available from the initial game state
+    let initial_game_state_value = solved_weights_calculator
+        .weigh_game_states(
+            0,
+            &game_rules_authority.find_available_next_game_states(0, &initial_game_state),
+        )
+        .iter()
+        .cloned()
+        .fold(f32::NEG_INFINITY, f32::max);
+    println!(
+        "With perfect play, the game is worth {} to the first player to move.",
+        initial_game_state_value
+    );
+
+    let players_turn_takers: Vec<BestWeightSelectionTurnTaker<GameState>> = (0..2)
+        .map(|player_index| {
+            return BestWeightSelectionTurnTaker::new(
+                game_rules_authority,
+                &game_state_records_weights_calculator,
+                player_index,
+            );
+        })
+        .collect();
+
+    // Game states in which all the moves are worth the same leave no room for picking a suboptimal move
+    let mut contested_game_states_count = 0;
+    let mut suboptimal_moves_count = 0;
+    for (current_player_index, game_state) in decision_game_states.iter() {
+        let next_game_states =
+            game_rules_authority.find_available_next_game_states(*current_player_index, game_state);
+        let next_game_states_values =
+            solved_weights_calculator.weigh_game_states(*current_player_index, &next_game_states);
+        let best_value = next_game_states_values
+            .iter()
+            .cloned()
+            .fold(f32::NEG_INFINITY, f32::max);
+        let worst_value = next_game_states_values
+            .iter()
+            .cloned()
+            .fold(f32::INFINITY, f32::min);
+        if best_value == worst_value {
+            continue;
+        }
+        contested_game_states_count += 1;
+
+        let picked_next_game_state = players_turn_takers[*current_player_index as usize]
+            .decide_next_game_state(game_state)
+            .expect("Failed to pick a move.");
+        let picked_value = solved_weights_calculator
+            .weigh_game_states(*current_player_index, &vec![picked_next_game_state])[0];
+        if picked_value < best_value {
+            suboptimal_moves_count += 1;
+        }
+    }
+
+    println!(
+        "The records pick a suboptimal move in {} of the {} game states in which some moves are better than others ({:.2}%), out of {} game states in which a player gets to pick their move. Duration: {:?}.",
+        suboptimal_moves_count,
+        contested_game_states_count,
+        100.0 * suboptimal_moves_count as f32 / contested_game_states_count.max(1) as f32,
+        decision_game_states.len(),
+        start_instant.elapsed()
+    );
+
+    return Ok(());
+}
@@ -0,0 +1,101 @@
+use crate::cli::traits::RegisteredGame;
+use crate::cli::RegisteredGameDefinition;
+use crate::games;
+
+/// All the games the commands can be run for, keyed by their names.
+pub struct GameRegistry {
+    registered_games: Vec<Box<dyn RegisteredGame>>,
+}
+
+impl GameRegistry {
+    pub fn new() -> GameRegistry {
+        return GameRegistry {
+            registered_games: vec![
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::bitboard_checkers::GameDefinition::new(),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::checkers::GameDefinition::new_with_rules(
+                        "brazilian-checkers",
+                        games::checkers::CheckersRules::brazilian(),
+                    ),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::checkers::GameDefinition::new(),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::connect_four::GameDefinition::new(),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::draughts::GameDefinition::new(),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::goofspiel::GameDefinition::new(),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::checkers::GameDefinition::new_with_rules(
+                        "italian-checkers",
+                        games::checkers::CheckersRules::italian(),
+                    ),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::kuhn_poker::GameDefinition::new(),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::othello::GameDefinition::new(),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::checkers::GameDefinition::new_with_rules(
+                        "pool-checkers",
+                        games::checkers::CheckersRules::pool(),
+                    ),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::checkers::GameDefinition::new_with_rules(
+                        "russian-checkers",
+                        games::checkers::CheckersRules::russian(),
+                    ),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::simplified_backgammon::GameDefinition::new(),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::three_player_tic_tac_toe::GameDefinition::new(),
+                ))),
+                Box::new(RegisteredGameDefinition::new(Box::new(
+                    games::tic_tac_toe::GameDefinition::new(),
+                ))),
+            ],
+        };
+    }
+
+    pub fn find_registered_game(&self, game_name: &str) -> Option<&dyn RegisteredGame> {
+        return self
+            .registered_games
+            .iter()
+            .find(|registered_game| registered_game.get_name() == game_name)
+            .map(|registered_game| registered_game.as_ref());
+    }
+
+    /// Describes the game names for the help text of the game option, e.g. "checkers" or "tic-tac-toe".
+    pub fn describe_game_names(&self) -> String {
+        let quoted_game_names: Vec<String> = self
+            .registered_games
+            .iter()
+            .map(|registered_game| format!(r#""{}""#, registered_game.get_name()))
+            .collect();
+
+        return match quoted_game_names.split_last() {
+            Some((last_quoted_game_name, other_quoted_game_names))
+                if !other_quoted_game_names.is_empty() =>
+            {
+                format!(
+                    "{} or {}",
+                    other_quoted_game_names.join(", "),
+                    last_quoted_game_name
+                )
+            }
+            _ => quoted_game_names.join(""),
+        };
+    }
+}
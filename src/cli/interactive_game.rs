@@ -1,33 +1,53 @@
-use crate::cli::enums::Game;
-use crate::composites::GameReportsIterativeProcessor;
-use crate::game_runners::StandardTurnBasedGameRunner;
+use crate::cli::actions_printing_game_reports_processor::ActionsPrintingGameReportsProcessor;
+use crate::cli::create_game_runner::create_game_runner;
+use crate::cli::create_game_state_records_dal::create_game_state_records_dal;
+use crate::cli::structs::{InteractiveGameOptions, RecordValuesWeights};
+use crate::cli::GameRegistry;
+use crate::composites::{CanonicalizingGameReportsProcessor, GameReportsIterativeProcessor};
+use crate::enums::MinimaxSearchLimit;
 use crate::game_state_records_providers::LruCacheFrontedGameStateRecordsProvider;
-use crate::games;
-use crate::persistence::{SqliteByteArrayLogGameReportsProcessor, SqliteGameStateRecordsDAL};
+use crate::persistence::SqliteByteArrayLogGameReportsProcessor;
 use crate::simulating::StandardSimulator;
+#[cfg(feature = "torch")]
+use crate::structs::ReplayBufferTrainingParameters;
+#[cfg(feature = "torch")]
 use crate::training::TorchNetTrainer;
-use crate::traits::{GameReportsProcessor, PendingUpdatesManager, TurnTaker};
-use crate::turn_takers::{BestWeightSelectionTurnTaker, CLIInputPlayerTurnTaker};
-use crate::weights_calculators::{
-    CnnGameStateWeightsCalculator, RecordValuesWeightedSumGameStateWeightsCalculator,
+use crate::traits::{
+    BasicGameState, ByteArrayConvertibleSerializedGameState, GameDefinition, GameReportsProcessor,
+    GameStateSerializer, GameStateWeightsCalculator, PendingUpdatesManager, TurnTaker,
 };
+use crate::turn_takers::{
+    BestWeightSelectionTurnTaker, CLIInputPlayerTurnTaker, MctsTurnTaker, MinimaxTurnTaker,
+};
+#[cfg(feature = "torch")]
+use crate::weights_calculators::CnnGameStateWeightsCalculator;
+use crate::weights_calculators::RecordValuesWeightedSumGameStateWeightsCalculator;
+#[cfg(feature = "torch")]
 use tch::{nn, Device};
 
-pub fn interactive_game(args: Vec<String>) -> Result<(), ()> {
-    let mut game = Game::TicTacToe;
+pub fn interactive_game(args: Vec<String>, game_registry: &GameRegistry) -> Result<(), ()> {
+    let mut game_name = String::new();
     let mut cli_input_player_index = 0;
     let mut draws_weight = 5.0;
     let mut losses_weight = -10.0;
     let mut wins_weight = 10.0;
     let mut visits_deficit_weight = 20.0;
+    let mut mcts_simulations: u32 = 0;
+    let mut mcts_exploration_constant: f32 = 1.5;
+    let mut minimax_depth: u32 = 0;
+    let mut minimax_time_budget_millis: u64 = 0;
 
     {
+        let game_help = format!(
+            "Game to run (either {})",
+            game_registry.describe_game_names()
+        );
         let mut arg_parser = argparse::ArgumentParser::new();
 
-        arg_parser.refer(&mut game).required().add_option(
+        arg_parser.refer(&mut game_name).required().add_option(
             &["-g", "--game"],
             argparse::Store,
-            r#"Game to run (either "checkers" or "tic-tac-toe")"#,
+            &game_help,
         );
 
         arg_parser
@@ -36,7 +56,7 @@ pub fn interactive_game(args: Vec<String>) -> Result<(), ()> {
             .add_option(
                 &["-h", "--human-player-index"],
                 argparse::Store,
-                "Player index for the human player (0 or 1)",
+                "Player index for the human player (0 for the first player)",
             );
 
         arg_parser.refer(&mut draws_weight).add_option(
@@ -63,6 +83,30 @@ pub fn interactive_game(args: Vec<String>) -> Result<(), ()> {
             "Weight of visits deficit for state decisions",
         );
 
+        arg_parser.refer(&mut mcts_simulations).add_option(
+            &["--mcts-simulations"],
+            argparse::Parse,
+            "Number of MCTS simulations per CPU move (0 picks the best weighted move without searching)",
+        );
+
+        arg_parser.refer(&mut mcts_exploration_constant).add_option(
+            &["--mcts-exploration-constant"],
+            argparse::Parse,
+            "Exploration constant used by MCTS when selecting moves to simulate",
+        );
+
+        arg_parser.refer(&mut minimax_depth).add_option(
+            &["--minimax-depth"],
+            argparse::Parse,
+            "Number of plies for the CPU to search with alpha-beta minimax (0 disables minimax)",
+        );
+
+        arg_parser.refer(&mut minimax_time_budget_millis).add_option(
+            &["--minimax-time-budget-ms"],
+            argparse::Parse,
+            "Time budget per CPU move for iterative deepening up to the minimax depth (0 searches the full depth)",
+        );
+
         match arg_parser.parse(args, &mut std::io::stdout(), &mut std::io::stderr()) {
             Ok(()) => (),
             Err(x) => {
@@ -72,193 +116,262 @@ pub fn interactive_game(args: Vec<String>) -> Result<(), ()> {
         }
     }
 
-    let sqlite_db_path = "./GamesHistory.db";
+    let minimax_search_limit = if minimax_time_budget_millis > 0 {
+        MinimaxSearchLimit::IterativeDeepening {
+            max_depth: minimax_depth,
+            time_budget: std::time::Duration::from_millis(minimax_time_budget_millis),
+        }
+    } else {
+        MinimaxSearchLimit::FixedDepth(minimax_depth)
+    };
+
+    let registered_game = match game_registry.find_registered_game(&game_name) {
+        Some(registered_game) => registered_game,
+        None => {
+            println!(
+                "Unknown game {}, expected either {}.",
+                game_name,
+                game_registry.describe_game_names()
+            );
+            return Err(());
+        }
+    };
+
+    return registered_game.run_interactive_game(&InteractiveGameOptions {
+        cli_input_player_index: cli_input_player_index,
+        mcts_exploration_constant: mcts_exploration_constant,
+        mcts_simulations: mcts_simulations,
+        minimax_depth: minimax_depth,
+        minimax_search_limit: minimax_search_limit,
+        record_values_weights: RecordValuesWeights {
+            draws_weight: draws_weight,
+            losses_weight: losses_weight,
+            visits_deficit_weight: visits_deficit_weight,
+            wins_weight: wins_weight,
+        },
+    });
+}
 
-    match game {
-        Game::Checkers => {
-            let game_name = "checkers";
-            let logs_serializer_version = 1;
+/// Plays a single game between a human player on the command line and a CPU player.
+/// The CPU player weighs game states with the value net of the game if it has one,
+/// and with the recorded game outcomes otherwise.
+pub fn run_interactive_game<
+    GameState: BasicGameState,
+    SerializedGameState: ByteArrayConvertibleSerializedGameState + Ord + Send + Sync,
+>(
+    game_definition: &dyn GameDefinition<GameState, SerializedGameState>,
+    options: &InteractiveGameOptions,
+) -> Result<(), ()> {
+    let game_name = game_definition.get_name();
+    let sqlite_db_path = "./GamesHistory.db";
+    let logs_serializer_version = 2;
 
-            let lru_cache_max_capacity: usize = 1_000_000;
-            let game_state_records_dal = SqliteGameStateRecordsDAL::new(game_name, sqlite_db_path)
-                .expect("Failed to create SqliteGameStateRecordsDAL.");
-            let game_state_records_provider = LruCacheFrontedGameStateRecordsProvider::new(
-                lru_cache_max_capacity,
-                &game_state_records_dal,
-            );
+    let number_of_players = game_definition.get_number_of_players();
+    if options.cli_input_player_index < 0 || options.cli_input_player_index >= number_of_players {
+        println!(
+            "Invalid human player index {}, {} has {} players.",
+            options.cli_input_player_index, game_name, number_of_players
+        );
+        return Err(());
+    }
+    if (number_of_players != 2
+        || game_definition
+            .get_simultaneous_game_rules_authority()
+            .is_some())
+        && (options.mcts_simulations > 0 || options.minimax_depth > 0)
+    {
+        println!("MCTS and minimax players can only play two player games in which the players take turns.");
+        return Err(());
+    }
 
-            let game_rules_authority = games::checkers::GameRulesAuthority {};
-            let game_state_serializer = games::checkers::ByteArrayGameStateSerializer {};
-            let game_state_deserializer = games::checkers::ByteArrayGameStateDeserializer {};
-            let mut torch_var_store = nn::VarStore::new(Device::cuda_if_available());
-            let torch_net = games::checkers::TorchNet::new(&torch_var_store.root());
-            torch_var_store.load("checkers-var-store.weights").unwrap();
-            let torch_net_trainer = TorchNetTrainer::new(
+    let lru_cache_max_capacity: usize = 1_000_000;
+    let (game_state_records_dal, canonicalizing_game_state_serializer) =
+        create_game_state_records_dal(game_definition, sqlite_db_path);
+    let game_state_records_provider = LruCacheFrontedGameStateRecordsProvider::new(
+        lru_cache_max_capacity,
+        &game_state_records_dal,
+    );
+
+    let game_rules_authority = game_definition.get_game_rules_authority();
+    let game_state_serializer = game_definition.get_game_state_serializer();
+    let game_state_records_serializer: &(dyn GameStateSerializer<GameState, SerializedGameState>
+          + Sync) = match &canonicalizing_game_state_serializer {
+        Some(canonicalizing_game_state_serializer) => canonicalizing_game_state_serializer,
+        None => game_state_serializer,
+    };
+
+    #[cfg(feature = "torch")]
+    let mut torch_var_store = nn::VarStore::new(Device::cuda_if_available());
+    #[cfg(feature = "torch")]
+    let var_store_file_name = format!("{}-var-store.weights", game_name);
+    #[cfg(feature = "torch")]
+    let torch_net = match game_definition.get_neural_net_definition() {
+        Some(neural_net_definition) => {
+            let torch_net = (neural_net_definition.create_value_net)(&torch_var_store.root());
+            torch_var_store.load(&var_store_file_name).unwrap();
+            Some((neural_net_definition, torch_net))
+        }
+        None => None,
+    };
+    #[cfg(feature = "torch")]
+    let torch_net_trainer = torch_net
+        .as_ref()
+        .map(|(neural_net_definition, torch_net)| {
+            return TorchNetTrainer::new(
                 torch_var_store.device(),
-                "checkers-var-store.weights",
-                &game_state_deserializer,
-                &torch_net,
+                &var_store_file_name,
+                game_definition.get_game_state_deserializer(),
+                torch_net.as_ref(),
+                ReplayBufferTrainingParameters {
+                    // Only one game is played at a time, so train a little after each one
+                    games_per_training_round: 1,
+                    learning_rate: 1e-4,
+                    minibatch_size: 64,
+                    replay_buffer_capacity: 10_000,
+                    training_steps_per_round: 8,
+                },
                 &torch_var_store,
-                &games::checkers::transform_game_state_to_tensor,
+                &neural_net_definition.transform_game_state_to_tensor,
             );
-
-            let sqlite_game_reports_processor = SqliteByteArrayLogGameReportsProcessor::new(
-                game_name,
-                logs_serializer_version,
-                10_000,
-                sqlite_db_path,
-            );
-            let game_reports_processors_vector: Vec<&dyn GameReportsProcessor<Vec<u8>, ()>> = vec![
+        });
+
+    let sqlite_game_reports_processor = SqliteByteArrayLogGameReportsProcessor::new(
+        game_name,
+        logs_serializer_version,
+        10_000,
+        sqlite_db_path,
+    );
+    let canonicalizing_game_reports_processor =
+        canonicalizing_game_state_serializer.as_ref().map(|_| {
+            CanonicalizingGameReportsProcessor::new(
                 &game_state_records_provider,
-                &sqlite_game_reports_processor,
-                &torch_net_trainer,
-            ];
-            let game_reports_processor =
-                GameReportsIterativeProcessor::new(game_reports_processors_vector);
-
-            let mut base_game_runner =
-                StandardTurnBasedGameRunner::new(&game_rules_authority, &game_state_serializer);
-            let pending_updates_managers: Vec<&dyn PendingUpdatesManager> =
-                vec![&game_state_records_provider];
-            let mut simulator = StandardSimulator::new(
-                &mut base_game_runner,
-                game_name,
-                &game_reports_processor,
-                true,
-                &pending_updates_managers,
-            );
-
-            let game_state_weights_calculator = CnnGameStateWeightsCalculator::new(
+                game_definition.get_game_state_deserializer(),
+                game_state_records_serializer,
+            )
+        });
+    let game_state_records_reports_processor: &dyn GameReportsProcessor<SerializedGameState, ()> =
+        match &canonicalizing_game_reports_processor {
+            Some(canonicalizing_game_reports_processor) => canonicalizing_game_reports_processor,
+            None => &game_state_records_provider,
+        };
+    let actions_printing_game_reports_processor = ActionsPrintingGameReportsProcessor::new();
+    let game_reports_processors_vector: Vec<&dyn GameReportsProcessor<SerializedGameState, ()>> = vec![
+        game_state_records_reports_processor,
+        &sqlite_game_reports_processor,
+        &actions_printing_game_reports_processor,
+    ];
+    // the net is trained on the games played against it, when there is one
+    #[cfg(feature = "torch")]
+    let mut game_reports_processors_vector = game_reports_processors_vector;
+    #[cfg(feature = "torch")]
+    if let Some(torch_net_trainer) = torch_net_trainer.as_ref() {
+        game_reports_processors_vector.push(torch_net_trainer);
+    }
+    let game_reports_processor = GameReportsIterativeProcessor::new(game_reports_processors_vector);
+
+    let mut base_game_runner = create_game_runner(game_definition);
+    let pending_updates_managers: Vec<&dyn PendingUpdatesManager> =
+        vec![&game_state_records_provider];
+    let mut simulator = StandardSimulator::new(
+        base_game_runner.as_mut(),
+        game_name,
+        &game_reports_processor,
+        true,
+        &pending_updates_managers,
+    );
+
+    let record_values_weights = options.record_values_weights;
+    let game_state_records_weights_calculator =
+        RecordValuesWeightedSumGameStateWeightsCalculator::new(
+            &game_state_records_provider,
+            game_state_records_serializer,
+            record_values_weights.draws_weight,
+            record_values_weights.losses_weight,
+            record_values_weights.wins_weight,
+            record_values_weights.visits_deficit_weight,
+        );
+    #[cfg(feature = "torch")]
+    let cnn_weights_calculator = torch_net
+        .as_ref()
+        .map(|(neural_net_definition, torch_net)| {
+            return CnnGameStateWeightsCalculator::new(
                 torch_var_store.device(),
-                &torch_net,
-                &games::checkers::transform_game_state_to_tensor,
-            );
-            // let game_state_weights_calculator =
-            //     RecordValuesWeightedSumGameStateWeightsCalculator::new(
-            //         &game_state_records_provider,
-            //         &game_state_serializer,
-            //         draws_weight,
-            //         losses_weight,
-            //         wins_weight,
-            //         visits_deficit_weight,
-            //     );
-
-            let cpu_player_index = (cli_input_player_index + 1) % 2;
-            let cpu_player_turn_taker = BestWeightSelectionTurnTaker::new(
-                &game_rules_authority,
-                &game_state_weights_calculator,
-                cpu_player_index,
-            );
-
-            let cli_game_state_formatter = games::checkers::CLIGameStateFormatter {};
-            let user_input_game_state_creator = games::checkers::UserInputGameStateCreator::new();
-
-            let cli_input_player_turn_taker = CLIInputPlayerTurnTaker::new(
-                &cli_game_state_formatter,
-                cli_input_player_index,
-                &user_input_game_state_creator,
-            );
-
-            let mut turn_takers: Vec<&dyn TurnTaker<games::checkers::GameStateType>> =
-                vec![&cpu_player_turn_taker];
-            turn_takers.insert(
-                cli_input_player_index as usize,
-                &cli_input_player_turn_taker,
-            );
-
-            simulator
-                .run_simulations(
-                    1,
-                    games::checkers::create_initial_game_state,
-                    &mut (|| turn_takers.clone()),
-                    -1,
-                    true,
-                )
-                .expect("Training failed.");
-        }
-        Game::TicTacToe => {
-            let game_name = "tic-tac-toe";
-            let logs_serializer_version = 1;
-
-            let lru_cache_max_capacity: usize = 1_000_000;
-            let game_state_records_dal = SqliteGameStateRecordsDAL::new(game_name, sqlite_db_path)
-                .expect("Failed to create SqliteGameStateRecordsDAL.");
-            let game_state_records_provider = LruCacheFrontedGameStateRecordsProvider::new(
-                lru_cache_max_capacity,
-                &game_state_records_dal,
+                torch_net.as_ref(),
+                &neural_net_definition.transform_game_state_to_tensor,
             );
-
-            let sqlite_game_reports_processor = SqliteByteArrayLogGameReportsProcessor::new(
-                game_name,
-                logs_serializer_version,
-                10_000,
-                sqlite_db_path,
+        });
+    #[cfg(feature = "torch")]
+    let game_state_weights_calculator: &dyn GameStateWeightsCalculator<GameState> =
+        match cnn_weights_calculator.as_ref() {
+            Some(cnn_weights_calculator) => cnn_weights_calculator,
+            None => &game_state_records_weights_calculator,
+        };
+    #[cfg(not(feature = "torch"))]
+    let game_state_weights_calculator: &dyn GameStateWeightsCalculator<GameState> =
+        &game_state_records_weights_calculator;
+
+    // Every player but the human one is a CPU player, so there's one of each kind of CPU turn taker per player index
+    let best_weight_selection_turn_takers: Vec<BestWeightSelectionTurnTaker<GameState>> = (0
+        ..number_of_players)
+        .map(|player_index| {
+            return BestWeightSelectionTurnTaker::new(
+                game_rules_authority,
+                game_state_weights_calculator,
+                player_index,
             );
-            let game_reports_processors_vector: Vec<&dyn GameReportsProcessor<Vec<u8>, ()>> =
-                vec![&game_state_records_provider, &sqlite_game_reports_processor];
-            let game_reports_processor =
-                GameReportsIterativeProcessor::new(game_reports_processors_vector);
-
-            let game_rules_authority = games::tic_tac_toe::GameRulesAuthority {};
-            let game_state_serializer = games::tic_tac_toe::ByteArrayGameStateSerializer {};
-
-            let mut base_game_runner =
-                StandardTurnBasedGameRunner::new(&game_rules_authority, &game_state_serializer);
-            let pending_updates_managers: Vec<&dyn PendingUpdatesManager> =
-                vec![&game_state_records_provider];
-            let mut simulator = StandardSimulator::new(
-                &mut base_game_runner,
-                game_name,
-                &game_reports_processor,
-                true,
-                &pending_updates_managers,
+        })
+        .collect();
+    let mcts_turn_takers: Vec<MctsTurnTaker<GameState>> = (0..number_of_players)
+        .map(|player_index| {
+            return MctsTurnTaker::new(
+                game_rules_authority,
+                game_state_weights_calculator,
+                player_index,
+                options.mcts_simulations,
+                options.mcts_exploration_constant,
+                0.0,
             );
-
-            let game_state_weights_calculator =
-                RecordValuesWeightedSumGameStateWeightsCalculator::new(
-                    &game_state_records_provider,
-                    &game_state_serializer,
-                    draws_weight,
-                    losses_weight,
-                    wins_weight,
-                    visits_deficit_weight,
-                );
-
-            let cpu_player_index = (cli_input_player_index + 1) % 2;
-            let cpu_player_turn_taker = BestWeightSelectionTurnTaker::new(
-                &game_rules_authority,
-                &game_state_weights_calculator,
-                cpu_player_index,
+        })
+        .collect();
+    let minimax_turn_takers: Vec<MinimaxTurnTaker<GameState>> = (0..number_of_players)
+        .map(|player_index| {
+            return MinimaxTurnTaker::new(
+                game_rules_authority,
+                game_state_weights_calculator,
+                player_index,
+                options.minimax_search_limit,
             );
-
-            let cli_game_state_formatter = games::tic_tac_toe::CLIGameStateFormatter {};
-            let mut user_input_game_state_creator =
-                games::tic_tac_toe::UserInputGameStateCreator {};
-
-            let cli_input_player_turn_taker = CLIInputPlayerTurnTaker::new(
-                &cli_game_state_formatter,
-                cli_input_player_index,
-                &mut user_input_game_state_creator,
-            );
-
-            let mut turn_takers: Vec<&dyn TurnTaker<games::tic_tac_toe::GameStateType>> =
-                vec![&cpu_player_turn_taker];
-            turn_takers.insert(
-                cli_input_player_index as usize,
-                &cli_input_player_turn_taker,
-            );
-
-            simulator
-                .run_simulations(
-                    1,
-                    games::tic_tac_toe::create_initial_game_state,
-                    &mut (|| turn_takers.clone()),
-                    -1,
-                    true,
-                )
-                .expect("Training failed.");
+        })
+        .collect();
+
+    let cli_input_player_turn_taker = CLIInputPlayerTurnTaker::new(
+        game_definition.get_cli_game_state_formatter(),
+        options.cli_input_player_index,
+        game_definition.get_user_input_game_state_creator(),
+    );
+
+    let mut turn_takers: Vec<&dyn TurnTaker<GameState>> = vec![];
+    for player_index in 0..number_of_players as usize {
+        if player_index as i32 == options.cli_input_player_index {
+            turn_takers.push(&cli_input_player_turn_taker);
+        } else if options.mcts_simulations > 0 {
+            turn_takers.push(&mcts_turn_takers[player_index]);
+        } else if options.minimax_depth > 0 {
+            turn_takers.push(&minimax_turn_takers[player_index]);
+        } else {
+            turn_takers.push(&best_weight_selection_turn_takers[player_index]);
         }
     }
 
+    simulator
+        .run_simulations(
+            1,
+            &|| game_definition.create_initial_game_state(),
+            &mut (|| turn_takers.clone()),
+            -1,
+            true,
+        )
+        .expect("Training failed.");
+
     return Ok(());
 }
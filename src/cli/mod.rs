@@ -1,6 +1,21 @@
+mod actions_printing_game_reports_processor;
+mod create_game_runner;
+mod create_game_state_records_dal;
 pub mod enums;
+mod evaluate_records;
+mod game_registry;
 mod interactive_game;
+mod perft;
+mod registered_game_definition;
 mod simulate_games;
+pub mod structs;
+mod tournament;
+pub mod traits;
 
+pub use evaluate_records::evaluate_records;
+pub use game_registry::GameRegistry;
 pub use interactive_game::interactive_game;
+pub use perft::perft;
+pub use registered_game_definition::RegisteredGameDefinition;
 pub use simulate_games::simulate_games;
+pub use tournament::tournament;
@@ -0,0 +1,230 @@
+use crate::analysis::{perft as count_perft_leaf_nodes, perft_divide};
+use crate::cli::structs::PerftOptions;
+use crate::cli::GameRegistry;
+use crate::traits::{BasicGameState, ByteArrayConvertibleSerializedGameState, GameDefinition};
+use std::time::Instant;
+
+pub fn perft(args: Vec<String>, game_registry: &GameRegistry) -> Result<(), ()> {
+    let mut game_name = String::new();
+    let mut depth: u32 = 5;
+    let mut hex_serialized_game_state = String::new();
+    let mut is_dividing = false;
+
+    {
+        let game_help = format!(
+            "Game to run (either {})",
+            game_registry.describe_game_names()
+        );
+        let mut arg_parser = argparse::ArgumentParser::new();
+
+        arg_parser.refer(&mut game_name).required().add_option(
+            &["-g", "--game"],
+            argparse::Store,
+            &game_help,
+        );
+
+        arg_parser.refer(&mut depth).add_option(
+            &["-d", "--depth"],
+            argparse::Parse,
+            "Number of moves to count the leaf nodes of the game tree to",
+        );
+
+        arg_parser.refer(&mut hex_serialized_game_state).add_option(
+            &["-p", "--position"],
+            argparse::Store,
+            "Game state to count from, serialized as it is in the database and written in hexadecimal (defaults to the initial game state)",
+        );
+
+        arg_parser.refer(&mut is_dividing).add_option(
+            &["--divide"],
+            argparse::StoreTrue,
+            "Also splits the count at the full depth by the available moves",
+        );
+
+        match arg_parser.parse(args, &mut std::io::stdout(), &mut std::io::stderr()) {
+            Ok(()) => (),
+            Err(x) => {
+                println!("Failed to parse arguments, please try again");
+                std::process::exit(x);
+            }
+        }
+    }
+
+    let serialized_game_state = if hex_serialized_game_state.is_empty() {
+        None
+    } else {
+        match parse_hex_bytes(&hex_serialized_game_state) {
+            Some(serialized_game_state) => Some(serialized_game_state),
+            None => {
+                println!(
+                    "Invalid position {}, expected an even number of hexadecimal digits.",
+                    hex_serialized_game_state
+                );
+                return Err(());
+            }
+        }
+    };
+
+    let registered_game = match game_registry.find_registered_game(&game_name) {
+        Some(registered_game) => registered_game,
+        None => {
+            println!(
+                "Unknown game {}, expected either {}.",
+                game_name,
+                game_registry.describe_game_names()
+            );
+            return Err(());
+        }
+    };
+
+    return registered_game.run_perft(&PerftOptions {
+        depth: depth,
+        is_dividing: is_dividing,
+        serialized_game_state: serialized_game_state,
+    });
+}
+
+/// Prints the perft count of every depth up to the given one, so that they can be checked against known counts.
+pub fn run_perft<
+    GameState: BasicGameState,
+    SerializedGameState: ByteArrayConvertibleSerializedGameState,
+>(
+    game_definition: &dyn GameDefinition<GameState, SerializedGameState>,
+    options: &PerftOptions,
+) -> Result<(), ()> {
+    if game_definition
+        .get_simultaneous_game_rules_authority()
+        .is_some()
+    {
+        println!("Perft can only count the moves of games in which the players take turns.");
+        return Err(());
+    }
+
+    let number_of_players = game_definition.get_number_of_players();
+    let (current_player_index, game_state) = match options.serialized_game_state.as_ref() {
+        Some(serialized_game_state_byte_array) => {
+            let serialized_game_state =
+                match SerializedGameState::from_byte_array(serialized_game_state_byte_array) {
+                    Some(serialized_game_state) => serialized_game_state,
+                    None => {
+                        println!(
+                            "The given position is not a serialized game state of {}.",
+                            game_definition.get_name()
+                        );
+                        return Err(());
+                    }
+                };
+            let (responsible_player_index, game_state) = game_definition
+                .get_game_state_deserializer()
+                .deserialize_game_state(&serialized_game_state);
+            // the player about to move follows the player responsible for the game state
+            (
+                (responsible_player_index + 1) % number_of_players,
+                game_state,
+            )
+        }
+        None => (0, game_definition.create_initial_game_state()),
+    };
+
+    println!(
+        "Counting from the game state below, with player {} to move:\n{}",
+        current_player_index,
+        game_definition
+            .get_cli_game_state_formatter()
+            .format_game_state_for_cli(&game_state)
+    );
+
+    let game_rules_authority = game_definition.get_game_rules_authority();
+    for depth in 1..options.depth + 1 {
+        let start_instant = Instant::now();
+        let leaf_nodes_count = count_perft_leaf_nodes(
+            game_rules_authority,
+            number_of_players,
+            current_player_index,
+            &game_state,
+            depth,
+        );
+        println!(
+            "Depth {}: {} leaf nodes. Duration: {:?}.",
+            depth,
+            leaf_nodes_count,
+            start_instant.elapsed()
+        );
+    }
+
+    if options.is_dividing {
+        print_perft_divide(
+            game_definition,
+            number_of_players,
+            current_player_index,
+            &game_state,
+            options.depth,
+        );
+    }
+
+    return Ok(());
+}
+
+fn print_perft_divide<
+    GameState: BasicGameState,
+    SerializedGameState: ByteArrayConvertibleSerializedGameState,
+>(
+    game_definition: &dyn GameDefinition<GameState, SerializedGameState>,
+    number_of_players: i32,
+    current_player_index: i32,
+    game_state: &GameState,
+    depth: u32,
+) {
+    let game_state_serializer = game_definition.get_game_state_serializer();
+    // moves are written down as actions when the game has them, and as the game states they lead to otherwise
+    let action_notations: Vec<(String, SerializedGameState)> =
+        match game_definition.get_game_action_notations_finder() {
+            Some(game_action_notations_finder) => game_action_notations_finder
+                .find_legal_action_notations(current_player_index, game_state)
+                .iter()
+                .map(|(action_notation, next_game_state)| {
+                    return (
+                        action_notation.clone(),
+                        game_state_serializer
+                            .serialize_game_state(current_player_index, next_game_state),
+                    );
+                })
+                .collect(),
+            None => vec![],
+        };
+
+    for (next_game_state, leaf_nodes_count) in perft_divide(
+        game_definition.get_game_rules_authority(),
+        number_of_players,
+        current_player_index,
+        game_state,
+        depth,
+    ) {
+        let serialized_next_game_state =
+            game_state_serializer.serialize_game_state(current_player_index, &next_game_state);
+        match action_notations
+            .iter()
+            .find(|(_, serialized_game_state)| *serialized_game_state == serialized_next_game_state)
+        {
+            Some((action_notation, _)) => println!("{}: {}", action_notation, leaf_nodes_count),
+            None => println!(
+                "{}\n{}",
+                game_definition
+                    .get_cli_game_state_formatter()
+                    .format_game_state_for_cli(&next_game_state),
+                leaf_nodes_count
+            ),
+        }
+    }
+}
+
+fn parse_hex_bytes(hex_string: &str) -> Option<Vec<u8>> {
+    if hex_string.len() % 2 != 0 || !hex_string.is_ascii() {
+        return None;
+    }
+
+    return (0..hex_string.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex_string[i..i + 2], 16).ok())
+        .collect();
+}
@@ -0,0 +1,72 @@
+use crate::cli::evaluate_records::run_evaluate_records;
+use crate::cli::interactive_game::run_interactive_game;
+use crate::cli::perft::run_perft;
+use crate::cli::simulate_games::run_simulate_games;
+use crate::cli::structs::{
+    EvaluateRecordsOptions, InteractiveGameOptions, PerftOptions, SimulateGamesConfig,
+    TournamentOptions,
+};
+use crate::cli::tournament::run_tournament;
+use crate::cli::traits::RegisteredGame;
+use crate::traits::{BasicGameState, ByteArrayConvertibleSerializedGameState, GameDefinition};
+
+/// Runs the commands for the game of the given game definition.
+pub struct RegisteredGameDefinition<
+    GameState: BasicGameState,
+    SerializedGameState: ByteArrayConvertibleSerializedGameState,
+> {
+    game_definition: Box<dyn GameDefinition<GameState, SerializedGameState>>,
+}
+
+impl<GameState: BasicGameState, SerializedGameState: ByteArrayConvertibleSerializedGameState>
+    RegisteredGameDefinition<GameState, SerializedGameState>
+{
+    pub fn new(
+        game_definition: Box<dyn GameDefinition<GameState, SerializedGameState>>,
+    ) -> RegisteredGameDefinition<GameState, SerializedGameState> {
+        return RegisteredGameDefinition {
+            game_definition: game_definition,
+        };
+    }
+}
+
+impl<
+        GameState: BasicGameState,
+        SerializedGameState: ByteArrayConvertibleSerializedGameState + Ord + Send + Sync,
+    > RegisteredGame for RegisteredGameDefinition<GameState, SerializedGameState>
+{
+    fn get_name(&self) -> &str {
+        return self.game_definition.get_name();
+    }
+
+    fn get_number_of_players(&self) -> i32 {
+        return self.game_definition.get_number_of_players();
+    }
+
+    fn has_neural_nets(&self) -> bool {
+        #[cfg(feature = "torch")]
+        return self.game_definition.get_neural_net_definition().is_some();
+        #[cfg(not(feature = "torch"))]
+        return false;
+    }
+
+    fn run_evaluate_records(&self, options: &EvaluateRecordsOptions) -> Result<(), ()> {
+        return run_evaluate_records(self.game_definition.as_ref(), options);
+    }
+
+    fn run_interactive_game(&self, options: &InteractiveGameOptions) -> Result<(), ()> {
+        return run_interactive_game(self.game_definition.as_ref(), options);
+    }
+
+    fn run_perft(&self, options: &PerftOptions) -> Result<(), ()> {
+        return run_perft(self.game_definition.as_ref(), options);
+    }
+
+    fn run_simulate_games(&self, config: &SimulateGamesConfig) -> Result<(), ()> {
+        return run_simulate_games(self.game_definition.as_ref(), config);
+    }
+
+    fn run_tournament(&self, options: &TournamentOptions) -> Result<(), ()> {
+        return run_tournament(self.game_definition.as_ref(), options);
+    }
+}
@@ -1,23 +1,96 @@
-use crate::cli::enums::Game;
-use crate::composites::GameReportsIterativeProcessor;
-use crate::game_runners::StandardTurnBasedGameRunner;
+use crate::cli::create_game_runner::create_game_runner;
+use crate::cli::create_game_state_records_dal::create_game_state_records_dal;
+use crate::cli::enums::{GameReportsProcessorKind, TurnTakerConfig, WeightsCalculatorConfig};
+use crate::cli::structs::{PersistenceConfig, PlayerConfig, SimulateGamesConfig};
+use crate::cli::GameRegistry;
+use crate::composites::{CanonicalizingGameReportsProcessor, GameReportsIterativeProcessor};
+use crate::enums::{MinimaxSearchLimit, RunGameError};
 use crate::game_state_records_providers::LruCacheFrontedGameStateRecordsProvider;
-use crate::games;
-use crate::persistence::{SqliteByteArrayLogGameReportsProcessor, SqliteGameStateRecordsDAL};
-use crate::simulating::StandardSimulator;
-use crate::training::TorchNetTrainer;
+use crate::persistence::SqliteByteArrayLogGameReportsProcessor;
+use crate::simulating::{ParallelSimulator, StandardSimulator};
+use crate::structs::GameReport;
+#[cfg(feature = "torch")]
+use crate::structs::{NeuralNetDefinition, ReplayBufferTrainingParameters};
+#[cfg(feature = "torch")]
+use crate::training::{PolicyValueTorchNetTrainer, TorchNetTrainer};
 use crate::traits::{
-    BasicGameState, BasicSerializedGameState, GameReportsProcessor, GameRunner,
-    PendingUpdatesManager, TurnTaker,
+    BasicGameState, BasicSerializedGameState, ByteArrayConvertibleSerializedGameState,
+    GameDefinition, GameReportsProcessor, GameRulesAuthority, GameRunner, GameStateRecordsFetcher,
+    GameStateSerializer, GameStateWeightsCalculator, PendingUpdatesManager, TurnTaker,
 };
-use crate::turn_takers::{BestWeightSelectionTurnTaker, WeightedRandomSelectionTurnTaker};
+#[cfg(feature = "torch")]
+use crate::traits::{PolicyValueNet, SearchPoliciesRecorder};
+use crate::turn_takers::{
+    BestWeightSelectionTurnTaker, MctsTurnTaker, MinimaxTurnTaker, WeightedRandomSelectionTurnTaker,
+};
+#[cfg(feature = "torch")]
+use crate::weights_calculators::{CnnGameStateWeightsCalculator, CnnPolicyValueCalculator};
 use crate::weights_calculators::{
-    CnnGameStateWeightsCalculator, RecordValuesWeightedSumGameStateWeightsCalculator,
+    RecordValuesWeightedSumGameStateWeightsCalculator, UniformGameStateWeightsCalculator,
 };
+#[cfg(feature = "torch")]
 use tch::{nn, Device};
 
-pub fn simulate_games<'a>(args: Vec<String>) -> Result<(), ()> {
-    let mut game = Game::TicTacToe;
+const LOGS_SERIALIZER_VERSION: i32 = 2;
+const LOGS_MAX_BATCH_SIZE: usize = 10_000;
+
+/// The weights calculator of a player.
+/// It's kept as its concrete type since MCTS players can make use of more than just the weights of a policy and value net.
+enum PlayerWeightsCalculator<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+> {
+    GameStateRecords(
+        RecordValuesWeightedSumGameStateWeightsCalculator<'a, GameState, SerializedGameState>,
+    ),
+    #[cfg(feature = "torch")]
+    PolicyValueNet(CnnPolicyValueCalculator<'a, GameState>),
+    Uniform(UniformGameStateWeightsCalculator),
+    #[cfg(feature = "torch")]
+    ValueNet(CnnGameStateWeightsCalculator<'a, GameState>),
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
+    PlayerWeightsCalculator<'a, GameState, SerializedGameState>
+{
+    fn as_game_state_weights_calculator(&self) -> &dyn GameStateWeightsCalculator<GameState> {
+        return match self {
+            PlayerWeightsCalculator::GameStateRecords(weights_calculator) => weights_calculator,
+            #[cfg(feature = "torch")]
+            PlayerWeightsCalculator::PolicyValueNet(weights_calculator) => weights_calculator,
+            PlayerWeightsCalculator::Uniform(weights_calculator) => weights_calculator,
+            #[cfg(feature = "torch")]
+            PlayerWeightsCalculator::ValueNet(weights_calculator) => weights_calculator,
+        };
+    }
+}
+
+/// Everything shared by all the games of a simulation that the players of each game are created from.
+struct SimulationComponents<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+> {
+    game_rules_authority: &'a (dyn GameRulesAuthority<GameState> + Sync),
+    game_state_records_fetcher: &'a (dyn GameStateRecordsFetcher<SerializedGameState> + Sync),
+    game_state_records_serializer:
+        &'a (dyn GameStateSerializer<GameState, SerializedGameState> + Sync),
+    #[cfg(feature = "torch")]
+    device: Device,
+    #[cfg(feature = "torch")]
+    neural_net_definition: Option<&'a NeuralNetDefinition<GameState>>,
+    #[cfg(feature = "torch")]
+    policy_value_net: Option<&'a (dyn PolicyValueNet + Sync)>,
+    #[cfg(feature = "torch")]
+    search_policies_recorder: Option<&'a (dyn SearchPoliciesRecorder<GameState> + Sync)>,
+    #[cfg(feature = "torch")]
+    value_net: Option<&'a (dyn nn::Module + Sync)>,
+}
+
+pub fn simulate_games(args: Vec<String>, game_registry: &GameRegistry) -> Result<(), ()> {
+    let mut game_name = String::new();
+    let mut config_file_name = String::new();
     let mut number_of_games: u32 = 100;
     let mut max_number_of_turns = 1000;
     let mut is_reaching_max_number_of_turns_a_draw = true;
@@ -25,14 +98,27 @@ pub fn simulate_games<'a>(args: Vec<String>) -> Result<(), ()> {
     let mut losses_weight = -10.0;
     let mut wins_weight = 10.0;
     let mut visits_deficit_weight = 20.0;
+    let mut number_of_threads: usize = 1;
+    #[cfg(feature = "torch")]
+    let mut training = ReplayBufferTrainingParameters::default();
+    let mut mcts_simulations: u32 = 0;
+    let mut mcts_exploration_constant: f32 = 1.5;
 
     {
+        let game_help = format!(
+            "Game to run (either {})",
+            game_registry.describe_game_names()
+        );
         let mut arg_parser = argparse::ArgumentParser::new();
 
-        arg_parser.refer(&mut game).required().add_option(
-            &["-g", "--game"],
+        arg_parser
+            .refer(&mut game_name)
+            .add_option(&["-g", "--game"], argparse::Store, &game_help);
+
+        arg_parser.refer(&mut config_file_name).add_option(
+            &["-c", "--config"],
             argparse::Store,
-            r#"Game to run (either "checkers" or "tic-tac-toe")"#,
+            "Config file describing the whole experiment to run, in which case the other options are ignored",
         );
 
         arg_parser.refer(&mut number_of_games).add_option(
@@ -79,6 +165,64 @@ pub fn simulate_games<'a>(args: Vec<String>) -> Result<(), ()> {
             "Weight of visits deficit for state decisions",
         );
 
+        arg_parser.refer(&mut number_of_threads).add_option(
+            &["-t", "--threads"],
+            argparse::Parse,
+            "Number of threads to simulate games on",
+        );
+
+        // the neural nets are the only ones trained
+        #[cfg(feature = "torch")]
+        {
+            arg_parser
+                .refer(&mut training.replay_buffer_capacity)
+                .add_option(
+                    &["--replay-buffer-capacity"],
+                    argparse::Parse,
+                    "Maximum number of positions kept for training the neural net",
+                );
+
+            arg_parser.refer(&mut training.minibatch_size).add_option(
+                &["--minibatch-size"],
+                argparse::Parse,
+                "Number of positions per neural net training step",
+            );
+
+            arg_parser
+                .refer(&mut training.training_steps_per_round)
+                .add_option(
+                    &["--training-steps"],
+                    argparse::Parse,
+                    "Number of neural net training steps per training round",
+                );
+
+            arg_parser
+                .refer(&mut training.games_per_training_round)
+                .add_option(
+                    &["--games-per-training-round"],
+                    argparse::Parse,
+                    "Number of games to simulate between neural net training rounds",
+                );
+
+            arg_parser.refer(&mut training.learning_rate).add_option(
+                &["--learning-rate"],
+                argparse::Parse,
+                "Learning rate of the neural net optimizer",
+            );
+        }
+
+        arg_parser.refer(&mut mcts_simulations).add_option(
+            &["--mcts-simulations"],
+            argparse::Parse,
+            "Number of MCTS simulations per move - when above 0, games are played by MCTS guided by the policy and value net of the game",
+        );
+
+        arg_parser.refer(&mut mcts_exploration_constant).add_option(
+            &["--mcts-exploration-constant"],
+            argparse::Parse,
+            "Exploration constant of the MCTS selection formula",
+        );
+
         match arg_parser.parse(args, &mut std::io::stdout(), &mut std::io::stderr()) {
             Ok(()) => (),
             Err(x) => {
@@ -88,208 +232,590 @@ pub fn simulate_games<'a>(args: Vec<String>) -> Result<(), ()> {
         }
     }
 
-    let sqlite_db_path = "./GamesHistory.db";
-
-    match game {
-        Game::Checkers => {
-            let game_name = "checkers";
-
-            let lru_cache_max_capacity: usize = 1_000_000;
-            let mut game_state_records_dal =
-                SqliteGameStateRecordsDAL::new(game_name, sqlite_db_path)
-                    .expect("Failed to create SqliteGameStateRecordsDAL.");
-            game_state_records_dal.set_is_saving_enabled(false); // disable if not playing against the neural net turn taker
-            let game_state_records_provider = LruCacheFrontedGameStateRecordsProvider::new(
-                lru_cache_max_capacity,
-                &game_state_records_dal,
-            );
-
-            let game_rules_authority = games::checkers::GameRulesAuthority {};
-            let game_state_serializer = games::checkers::ByteArrayGameStateSerializer {};
-            let game_state_deserializer = games::checkers::ByteArrayGameStateDeserializer {};
-
-            let mut torch_var_store = nn::VarStore::new(Device::cuda_if_available());
-            println!("{:?}", torch_var_store.device());
-            torch_var_store.load("checkers-var-store.weights").unwrap();
-            let torch_net = games::checkers::TorchNet::new(&torch_var_store.root());
-            let torch_net_trainer = TorchNetTrainer::new(
-                torch_var_store.device(),
-                "checkers-var-store.weights",
-                &game_state_deserializer,
-                &torch_net,
-                &torch_var_store,
-                &games::checkers::transform_game_state_to_tensor,
-            );
-
-            // let sqlite_game_reports_processor = SqliteByteArrayLogGameReportsProcessor::new(
-            //     game_name,
-            //     1, // logs serializer version
-            //     10_000,
-            //     sqlite_db_path,
-            // );
-            let game_reports_processors_vector: Vec<&dyn GameReportsProcessor<Vec<u8>, ()>> = vec![
-                // &game_state_records_provider,
-                // &sqlite_game_reports_processor,
-                &torch_net_trainer,
-            ];
-            let game_reports_processor =
-                GameReportsIterativeProcessor::new(game_reports_processors_vector);
-
-            let game_state_record_game_state_weights_calculator =
-                RecordValuesWeightedSumGameStateWeightsCalculator::new(
-                    &game_state_records_provider,
-                    &game_state_serializer,
-                    draws_weight,
-                    losses_weight,
-                    wins_weight,
-                    visits_deficit_weight,
+    let config = if !config_file_name.is_empty() {
+        load_simulate_games_config(&config_file_name)?
+    } else if game_name.is_empty() {
+        println!("Either a game or a config file is required.");
+        return Err(());
+    } else {
+        let game_state_records_weights_calculator_config =
+            WeightsCalculatorConfig::GameStateRecords {
+                draws_weight: draws_weight,
+                losses_weight: losses_weight,
+                visits_deficit_weight: visits_deficit_weight,
+                wins_weight: wins_weight,
+            };
+        let (has_neural_nets, number_of_players) =
+            match game_registry.find_registered_game(&game_name) {
+                Some(registered_game) => (
+                    registered_game.has_neural_nets(),
+                    registered_game.get_number_of_players(),
+                ),
+                None => (false, 2),
+            };
+
+        // Without a config file, the experiment is picked based on the MCTS options and on whether the game has neural nets
+        let (players, game_reports_processors, is_saving_game_state_records_enabled) =
+            if mcts_simulations > 0 {
+                let player = PlayerConfig {
+                    // Sampling moves in proportion to their visits keeps self-play games varied
+                    turn_taker: TurnTakerConfig::Mcts {
+                        exploration_constant: mcts_exploration_constant,
+                        simulations: mcts_simulations,
+                        temperature: 1.0,
+                    },
+                    weights_calculator: WeightsCalculatorConfig::PolicyValueNet,
+                };
+                (
+                    vec![player; number_of_players as usize],
+                    vec![GameReportsProcessorKind::PolicyValueNetTrainer],
+                    true,
+                )
+            } else if has_neural_nets {
+                let player = PlayerConfig {
+                    turn_taker: TurnTakerConfig::WeightedRandom,
+                    weights_calculator: WeightsCalculatorConfig::ValueNet,
+                };
+                (
+                    vec![player; number_of_players as usize],
+                    vec![GameReportsProcessorKind::ValueNetTrainer],
+                    false,
+                )
+            } else {
+                // The first player explores while all the others exploit what was learned so far
+                let mut players = vec![PlayerConfig {
+                    turn_taker: TurnTakerConfig::WeightedRandom,
+                    weights_calculator: game_state_records_weights_calculator_config.clone(),
+                }];
+                players.resize(
+                    number_of_players as usize,
+                    PlayerConfig {
+                        turn_taker: TurnTakerConfig::BestWeight,
+                        weights_calculator: game_state_records_weights_calculator_config,
+                    },
                 );
-            let torch_net_game_state_weights_calculator = CnnGameStateWeightsCalculator::new(
-                torch_var_store.device(),
-                &torch_net,
-                &games::checkers::transform_game_state_to_tensor,
+                (
+                    players,
+                    vec![
+                        GameReportsProcessorKind::GameStateRecords,
+                        GameReportsProcessorKind::GameReportsLog,
+                    ],
+                    true,
+                )
+            };
+
+        SimulateGamesConfig {
+            game: game_name,
+            game_reports_processors: game_reports_processors,
+            is_reaching_max_number_of_turns_a_draw: is_reaching_max_number_of_turns_a_draw,
+            max_number_of_turns: max_number_of_turns,
+            number_of_games: number_of_games,
+            number_of_threads: number_of_threads,
+            persistence: PersistenceConfig {
+                is_saving_game_state_records_enabled: is_saving_game_state_records_enabled,
+                ..PersistenceConfig::default()
+            },
+            players: players,
+            #[cfg(feature = "torch")]
+            training: training,
+        }
+    };
+
+    let registered_game = match game_registry.find_registered_game(&config.game) {
+        Some(registered_game) => registered_game,
+        None => {
+            println!(
+                "Unknown game {}, expected either {}.",
+                config.game,
+                game_registry.describe_game_names()
             );
+            return Err(());
+        }
+    };
 
-            let mut game_runner =
-                StandardTurnBasedGameRunner::new(&game_rules_authority, &game_state_serializer);
-
-            let turn_takers_sets = vec![
-                // vec![
-                //     BestWeightSelectionTurnTaker::new(
-                //         &game_rules_authority,
-                //         &torch_net_game_state_weights_calculator,
-                //         0,
-                //     ),
-                //     BestWeightSelectionTurnTaker::new(
-                //         &game_rules_authority,
-                //         &game_state_record_game_state_weights_calculator,
-                //         1,
-                //     ),
-                // ],
-                // vec![
-                //     BestWeightSelectionTurnTaker::new(
-                //         &game_rules_authority,
-                //         &game_state_record_game_state_weights_calculator,
-                //         0,
-                //     ),
-                //     BestWeightSelectionTurnTaker::new(
-                //         &game_rules_authority,
-                //         &torch_net_game_state_weights_calculator,
-                //         1,
-                //     ),
-                // ],
-                vec![
-                    WeightedRandomSelectionTurnTaker::new(
-                        &game_rules_authority,
-                        &torch_net_game_state_weights_calculator,
-                        0,
-                    ),
-                    WeightedRandomSelectionTurnTaker::new(
-                        &game_rules_authority,
-                        &torch_net_game_state_weights_calculator,
-                        1,
-                    ),
-                ],
-            ];
-            let mut game_number = 0;
-
-            run_simulations(
-                games::checkers::create_initial_game_state,
-                &mut (|| {
-                    game_number += 1;
-                    let indexing_parameter = game_number % turn_takers_sets.len();
-                    return vec![
-                        &turn_takers_sets[indexing_parameter][0],
-                        &turn_takers_sets[indexing_parameter][1],
-                    ];
-                }),
-                game_name,
-                &game_reports_processor,
-                &mut game_runner,
-                is_reaching_max_number_of_turns_a_draw,
-                max_number_of_turns,
-                number_of_games,
-                //&vec![&game_state_records_provider],
-                &vec![&torch_net_trainer],
-            )
-            .expect("Training failed.");
+    return registered_game.run_simulate_games(&config);
+}
+
+fn load_simulate_games_config(config_file_name: &str) -> Result<SimulateGamesConfig, ()> {
+    let config_text = match std::fs::read_to_string(config_file_name) {
+        Ok(config_text) => config_text,
+        Err(err) => {
+            println!("Failed to read config file {}: {}", config_file_name, err);
+            return Err(());
         }
-        Game::TicTacToe => {
-            let game_name = "tic-tac-toe";
-            let logs_serializer_version = 1;
-
-            let lru_cache_max_capacity: usize = 1_000_000;
-            let game_state_records_dal = SqliteGameStateRecordsDAL::new(game_name, sqlite_db_path)
-                .expect("Failed to create SqliteGameStateRecordsDAL.");
-            let game_state_records_provider = LruCacheFrontedGameStateRecordsProvider::new(
-                lru_cache_max_capacity,
-                &game_state_records_dal,
-            );
+    };
 
-            let sqlite_game_reports_processor = SqliteByteArrayLogGameReportsProcessor::new(
-                game_name,
-                logs_serializer_version,
-                10_000,
-                sqlite_db_path,
-            );
-            let game_reports_processors_vector: Vec<&dyn GameReportsProcessor<Vec<u8>, ()>> =
-                vec![&game_state_records_provider, &sqlite_game_reports_processor];
-            let game_reports_processor =
-                GameReportsIterativeProcessor::new(game_reports_processors_vector);
+    return match toml::from_str(&config_text) {
+        Ok(config) => Ok(config),
+        Err(err) => {
+            println!("Invalid config file {}: {}", config_file_name, err);
+            Err(())
+        }
+    };
+}
 
-            let game_rules_authority = games::tic_tac_toe::GameRulesAuthority {};
-            let game_state_serializer = games::tic_tac_toe::ByteArrayGameStateSerializer {};
+/// Simulates games of the given game as described by the given config,
+/// with each game's players created anew from the config.
+pub fn run_simulate_games<
+    GameState: BasicGameState,
+    SerializedGameState: ByteArrayConvertibleSerializedGameState + Ord + Send + Sync,
+>(
+    game_definition: &dyn GameDefinition<GameState, SerializedGameState>,
+    config: &SimulateGamesConfig,
+) -> Result<(), ()> {
+    let game_name = game_definition.get_name();
+
+    let number_of_players = game_definition.get_number_of_players();
+    if config.players.len() != number_of_players as usize {
+        println!(
+            "Expected a player for each of the {} players of {}, got {}.",
+            number_of_players,
+            game_name,
+            config.players.len()
+        );
+        return Err(());
+    }
 
-            let game_state_weights_calculator =
-                RecordValuesWeightedSumGameStateWeightsCalculator::new(
-                    &game_state_records_provider,
-                    &game_state_serializer,
-                    draws_weight,
-                    losses_weight,
-                    wins_weight,
-                    visits_deficit_weight,
-                );
+    if (number_of_players != 2
+        || game_definition
+            .get_simultaneous_game_rules_authority()
+            .is_some())
+        && config.players.iter().any(|player_config| {
+            return match player_config.turn_taker {
+                TurnTakerConfig::Mcts { .. } | TurnTakerConfig::Minimax { .. } => true,
+                _ => false,
+            };
+        })
+    {
+        println!("MCTS and minimax players can only play two player games in which the players take turns.");
+        return Err(());
+    }
 
-            let first_player_turn_taker = WeightedRandomSelectionTurnTaker::new(
-                &game_rules_authority,
-                &game_state_weights_calculator,
-                0,
-            );
+    let is_value_net_needed =
+        config
+            .game_reports_processors
+            .iter()
+            .any(|game_reports_processor_kind| {
+                *game_reports_processor_kind == GameReportsProcessorKind::ValueNetTrainer
+            })
+            || config.players.iter().any(|player_config| {
+                if let WeightsCalculatorConfig::ValueNet = player_config.weights_calculator {
+                    return true;
+                }
+                return false;
+            });
+    let is_policy_value_net_needed =
+        config
+            .game_reports_processors
+            .iter()
+            .any(|game_reports_processor_kind| {
+                *game_reports_processor_kind == GameReportsProcessorKind::PolicyValueNetTrainer
+            })
+            || config.players.iter().any(|player_config| {
+                if let WeightsCalculatorConfig::PolicyValueNet = player_config.weights_calculator {
+                    return true;
+                }
+                return false;
+            });
+    if is_value_net_needed || is_policy_value_net_needed {
+        #[cfg(not(feature = "torch"))]
+        {
+            println!("Neural nets are only available when built with the torch feature.");
+            return Err(());
+        }
+        #[cfg(feature = "torch")]
+        if game_definition.get_neural_net_definition().is_none() {
+            println!("There is no neural net for {}.", game_name);
+            return Err(());
+        }
+    }
 
-            let second_player_turn_taker = BestWeightSelectionTurnTaker::new(
-                &game_rules_authority,
-                &game_state_weights_calculator,
-                1,
-            );
+    let persistence = &config.persistence;
+    let game_rules_authority = game_definition.get_game_rules_authority();
+    let game_state_serializer = game_definition.get_game_state_serializer();
 
-            let mut game_runner =
-                StandardTurnBasedGameRunner::new(&game_rules_authority, &game_state_serializer);
-
-            run_simulations(
-                games::tic_tac_toe::create_initial_game_state,
-                &mut (|| vec![&first_player_turn_taker, &second_player_turn_taker]),
-                game_name,
-                &game_reports_processor,
-                &mut game_runner,
-                is_reaching_max_number_of_turns_a_draw,
-                max_number_of_turns,
-                number_of_games,
-                &vec![&game_state_records_provider],
+    let (mut game_state_records_dal, canonicalizing_game_state_serializer) =
+        create_game_state_records_dal(game_definition, &persistence.sqlite_db_path);
+    game_state_records_dal.set_is_saving_enabled(persistence.is_saving_game_state_records_enabled);
+    let game_state_records_provider = LruCacheFrontedGameStateRecordsProvider::new(
+        persistence.lru_cache_max_capacity,
+        &game_state_records_dal,
+    );
+    let game_state_records_serializer: &(dyn GameStateSerializer<GameState, SerializedGameState>
+          + Sync) = match &canonicalizing_game_state_serializer {
+        Some(canonicalizing_game_state_serializer) => canonicalizing_game_state_serializer,
+        None => game_state_serializer,
+    };
+    let canonicalizing_game_reports_processor =
+        canonicalizing_game_state_serializer.as_ref().map(|_| {
+            CanonicalizingGameReportsProcessor::new(
+                &game_state_records_provider,
+                game_definition.get_game_state_deserializer(),
+                game_state_records_serializer,
             )
-            .expect("Training failed.");
+        });
+    let sqlite_game_reports_processor = SqliteByteArrayLogGameReportsProcessor::new(
+        game_name,
+        LOGS_SERIALIZER_VERSION,
+        LOGS_MAX_BATCH_SIZE,
+        &persistence.sqlite_db_path,
+    );
+
+    #[cfg(feature = "torch")]
+    let neural_net_definition = game_definition.get_neural_net_definition();
+
+    #[cfg(feature = "torch")]
+    let value_net_weights_file_name = persistence
+        .value_net_weights_file_name
+        .clone()
+        .unwrap_or(format!("{}-var-store.weights", game_name));
+    #[cfg(feature = "torch")]
+    let mut value_net_var_store = nn::VarStore::new(Device::cuda_if_available());
+    #[cfg(feature = "torch")]
+    let value_net = if is_value_net_needed {
+        let value_net =
+            (neural_net_definition.unwrap().create_value_net)(&value_net_var_store.root());
+        if std::path::Path::new(&value_net_weights_file_name).exists() {
+            value_net_var_store
+                .load(&value_net_weights_file_name)
+                .unwrap();
         }
+        Some(value_net)
+    } else {
+        None
+    };
+    #[cfg(feature = "torch")]
+    let value_net_trainer = if config
+        .game_reports_processors
+        .contains(&GameReportsProcessorKind::ValueNetTrainer)
+    {
+        Some(TorchNetTrainer::new(
+            value_net_var_store.device(),
+            &value_net_weights_file_name,
+            game_definition.get_game_state_deserializer(),
+            value_net.as_ref().unwrap().as_ref(),
+            config.training,
+            &value_net_var_store,
+            &neural_net_definition
+                .unwrap()
+                .transform_game_state_to_tensor,
+        ))
+    } else {
+        None
+    };
+
+    #[cfg(feature = "torch")]
+    let policy_value_net_weights_file_name = persistence
+        .policy_value_net_weights_file_name
+        .clone()
+        .unwrap_or(format!("{}-policy-value-var-store.weights", game_name));
+    #[cfg(feature = "torch")]
+    let mut policy_value_net_var_store = nn::VarStore::new(Device::cuda_if_available());
+    #[cfg(feature = "torch")]
+    let policy_value_net = if is_policy_value_net_needed {
+        let policy_value_net = (neural_net_definition.unwrap().create_policy_value_net)(
+            &policy_value_net_var_store.root(),
+        );
+        if std::path::Path::new(&policy_value_net_weights_file_name).exists() {
+            policy_value_net_var_store
+                .load(&policy_value_net_weights_file_name)
+                .unwrap();
+        }
+        Some(policy_value_net)
+    } else {
+        None
+    };
+    #[cfg(feature = "torch")]
+    let policy_value_net_trainer = if config
+        .game_reports_processors
+        .contains(&GameReportsProcessorKind::PolicyValueNetTrainer)
+    {
+        let neural_net_definition = neural_net_definition.unwrap();
+        Some(PolicyValueTorchNetTrainer::new(
+            &policy_value_net_weights_file_name,
+            &neural_net_definition.find_move_policy_index,
+            game_definition.get_game_state_deserializer(),
+            game_state_serializer,
+            neural_net_definition.move_policy_size,
+            policy_value_net.as_ref().unwrap().as_ref(),
+            config.training,
+            &policy_value_net_var_store,
+            &neural_net_definition.transform_game_state_to_tensor,
+        ))
+    } else {
+        None
+    };
+
+    let mut game_reports_processors_vector: Vec<
+        &dyn GameReportsProcessor<SerializedGameState, ()>,
+    > = vec![];
+    let mut pending_updates_managers: Vec<&dyn PendingUpdatesManager> = vec![];
+    for game_reports_processor_kind in config.game_reports_processors.iter() {
+        match game_reports_processor_kind {
+            GameReportsProcessorKind::GameReportsLog => {
+                game_reports_processors_vector.push(&sqlite_game_reports_processor);
+                pending_updates_managers.push(&sqlite_game_reports_processor);
+            }
+            GameReportsProcessorKind::GameStateRecords => {
+                match &canonicalizing_game_reports_processor {
+                    Some(canonicalizing_game_reports_processor) => {
+                        game_reports_processors_vector.push(canonicalizing_game_reports_processor)
+                    }
+                    None => game_reports_processors_vector.push(&game_state_records_provider),
+                }
+                pending_updates_managers.push(&game_state_records_provider);
+            }
+            #[cfg(feature = "torch")]
+            GameReportsProcessorKind::PolicyValueNetTrainer => {
+                let policy_value_net_trainer = policy_value_net_trainer.as_ref().unwrap();
+                game_reports_processors_vector.push(policy_value_net_trainer);
+                pending_updates_managers.push(policy_value_net_trainer);
+            }
+            #[cfg(feature = "torch")]
+            GameReportsProcessorKind::ValueNetTrainer => {
+                let value_net_trainer = value_net_trainer.as_ref().unwrap();
+                game_reports_processors_vector.push(value_net_trainer);
+                pending_updates_managers.push(value_net_trainer);
+            }
+            // Already turned down above for lack of neural nets
+            #[cfg(not(feature = "torch"))]
+            _ => (),
+        }
+    }
+    let game_reports_processor = GameReportsIterativeProcessor::new(game_reports_processors_vector);
+
+    let components = SimulationComponents {
+        game_rules_authority: game_rules_authority,
+        game_state_records_fetcher: &game_state_records_provider,
+        game_state_records_serializer: game_state_records_serializer,
+        #[cfg(feature = "torch")]
+        device: value_net_var_store.device(),
+        #[cfg(feature = "torch")]
+        neural_net_definition: neural_net_definition,
+        #[cfg(feature = "torch")]
+        policy_value_net: policy_value_net
+            .as_ref()
+            .map(|policy_value_net| policy_value_net.as_ref()),
+        #[cfg(feature = "torch")]
+        search_policies_recorder: policy_value_net_trainer.as_ref().map(
+            |policy_value_net_trainer| -> &(dyn SearchPoliciesRecorder<GameState> + Sync) {
+                return policy_value_net_trainer;
+            },
+        ),
+        #[cfg(feature = "torch")]
+        value_net: value_net.as_ref().map(|value_net| value_net.as_ref()),
+    };
+
+    if config.number_of_threads > 1 {
+        let play_game = |_| {
+            // The neural nets are read by the workers while the trainers update them on the calling thread
+            let players_weights_calculators =
+                create_players_weights_calculators(config, &components);
+            let players_turn_takers =
+                create_players_turn_takers(config, &players_weights_calculators, &components);
+            let game_runner = create_game_runner(game_definition);
+
+            return game_runner.run_game(
+                game_definition.create_initial_game_state(),
+                &players_turn_takers
+                    .iter()
+                    .map(|turn_taker| turn_taker.as_ref())
+                    .collect(),
+                config.max_number_of_turns,
+                config.is_reaching_max_number_of_turns_a_draw,
+            );
+        };
+
+        run_parallel_simulations(
+            &play_game,
+            game_name,
+            &game_reports_processor,
+            config.number_of_games,
+            config.number_of_threads,
+            &pending_updates_managers,
+        )
+        .expect("Training failed.");
+
+        return Ok(());
     }
 
+    let players_weights_calculators = create_players_weights_calculators(config, &components);
+    let players_turn_takers =
+        create_players_turn_takers(config, &players_weights_calculators, &components);
+    let turn_takers: Vec<&dyn TurnTaker<GameState>> = players_turn_takers
+        .iter()
+        .map(|turn_taker| turn_taker.as_ref())
+        .collect();
+
+    let mut game_runner = create_game_runner(game_definition);
+
+    run_simulations(
+        &|| game_definition.create_initial_game_state(),
+        &mut (|| turn_takers.clone()),
+        game_name,
+        &game_reports_processor,
+        game_runner.as_mut(),
+        config.is_reaching_max_number_of_turns_a_draw,
+        config.max_number_of_turns,
+        config.number_of_games,
+        &pending_updates_managers,
+    )
+    .expect("Training failed.");
+
     return Ok(());
 }
 
+fn create_players_weights_calculators<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+>(
+    config: &SimulateGamesConfig,
+    components: &SimulationComponents<'a, GameState, SerializedGameState>,
+) -> Vec<PlayerWeightsCalculator<'a, GameState, SerializedGameState>> {
+    return config
+        .players
+        .iter()
+        .map(|player_config| match &player_config.weights_calculator {
+            WeightsCalculatorConfig::GameStateRecords {
+                draws_weight,
+                losses_weight,
+                visits_deficit_weight,
+                wins_weight,
+            } => PlayerWeightsCalculator::GameStateRecords(
+                RecordValuesWeightedSumGameStateWeightsCalculator::new(
+                    components.game_state_records_fetcher,
+                    components.game_state_records_serializer,
+                    *draws_weight,
+                    *losses_weight,
+                    *wins_weight,
+                    *visits_deficit_weight,
+                ),
+            ),
+            #[cfg(feature = "torch")]
+            WeightsCalculatorConfig::PolicyValueNet => {
+                let neural_net_definition = components.neural_net_definition.unwrap();
+                PlayerWeightsCalculator::PolicyValueNet(CnnPolicyValueCalculator::new(
+                    components.device,
+                    &neural_net_definition.find_move_policy_index,
+                    components.policy_value_net.unwrap(),
+                    &neural_net_definition.transform_game_state_to_tensor,
+                ))
+            }
+            WeightsCalculatorConfig::Uniform => {
+                PlayerWeightsCalculator::Uniform(UniformGameStateWeightsCalculator {})
+            }
+            #[cfg(feature = "torch")]
+            WeightsCalculatorConfig::ValueNet => {
+                PlayerWeightsCalculator::ValueNet(CnnGameStateWeightsCalculator::new(
+                    components.device,
+                    components.value_net.unwrap(),
+                    &components
+                        .neural_net_definition
+                        .unwrap()
+                        .transform_game_state_to_tensor,
+                ))
+            }
+            #[cfg(not(feature = "torch"))]
+            WeightsCalculatorConfig::PolicyValueNet | WeightsCalculatorConfig::ValueNet => {
+                unreachable!("Neural nets are turned down before any player is created")
+            }
+        })
+        .collect();
+}
+
+fn create_players_turn_takers<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+>(
+    config: &SimulateGamesConfig,
+    players_weights_calculators: &'a Vec<
+        PlayerWeightsCalculator<'a, GameState, SerializedGameState>,
+    >,
+    components: &SimulationComponents<'a, GameState, SerializedGameState>,
+) -> Vec<Box<dyn TurnTaker<GameState> + 'a>> {
+    return config
+        .players
+        .iter()
+        .zip(players_weights_calculators.iter())
+        .enumerate()
+        .map(
+            |(player_index, (player_config, player_weights_calculator))| -> Box<dyn TurnTaker<GameState> + 'a> {
+                let player_index = player_index as i32;
+                let game_state_weights_calculator =
+                    player_weights_calculator.as_game_state_weights_calculator();
+
+                return match &player_config.turn_taker {
+                    TurnTakerConfig::BestWeight => Box::new(BestWeightSelectionTurnTaker::new(
+                        components.game_rules_authority,
+                        game_state_weights_calculator,
+                        player_index,
+                    )),
+                    TurnTakerConfig::Mcts {
+                        exploration_constant,
+                        simulations,
+                        temperature,
+                    } => {
+                        let mcts_turn_taker = MctsTurnTaker::new(
+                            components.game_rules_authority,
+                            game_state_weights_calculator,
+                            player_index,
+                            *simulations,
+                            *exploration_constant,
+                            *temperature,
+                        );
+                        // only the neural nets set anything up on the turn taker once it is created
+                        #[cfg(feature = "torch")]
+                        let mut mcts_turn_taker = mcts_turn_taker;
+                        #[cfg(feature = "torch")]
+                        if let PlayerWeightsCalculator::PolicyValueNet(policy_value_calculator) =
+                            player_weights_calculator
+                        {
+                            mcts_turn_taker.set_policy_value_calculator(policy_value_calculator);
+                        }
+                        #[cfg(feature = "torch")]
+                        if let Some(search_policies_recorder) = components.search_policies_recorder
+                        {
+                            mcts_turn_taker.set_search_policies_recorder(search_policies_recorder);
+                        }
+                        Box::new(mcts_turn_taker)
+                    }
+                    TurnTakerConfig::Minimax {
+                        depth,
+                        time_budget_ms,
+                    } => Box::new(MinimaxTurnTaker::new(
+                        components.game_rules_authority,
+                        game_state_weights_calculator,
+                        player_index,
+                        match time_budget_ms {
+                            Some(time_budget_ms) => MinimaxSearchLimit::IterativeDeepening {
+                                max_depth: *depth,
+                                time_budget: std::time::Duration::from_millis(*time_budget_ms),
+                            },
+                            None => MinimaxSearchLimit::FixedDepth(*depth),
+                        },
+                    )),
+                    TurnTakerConfig::WeightedRandom => {
+                        Box::new(WeightedRandomSelectionTurnTaker::new(
+                            components.game_rules_authority,
+                            game_state_weights_calculator,
+                            player_index,
+                        ))
+                    }
+                };
+            },
+        )
+        .collect();
+}
+
 fn run_simulations<
     'b,
     GameState: BasicGameState,
     SerializedGameState: BasicSerializedGameState,
     ErrorType,
 >(
-    create_initial_game_state: fn() -> GameState,
+    create_initial_game_state: &dyn Fn() -> GameState,
     create_turn_takers: &mut dyn FnMut() -> Vec<&'b dyn TurnTaker<GameState>>,
     game_name: &str,
     game_reports_processor: &dyn GameReportsProcessor<SerializedGameState, ErrorType>,
@@ -315,3 +841,23 @@ fn run_simulations<
         is_reaching_max_number_of_turns_a_draw,
     );
 }
+
+fn run_parallel_simulations<SerializedGameState: BasicSerializedGameState + Send, ErrorType>(
+    play_game: &(dyn Fn(u32) -> Result<Option<GameReport<SerializedGameState>>, RunGameError>
+          + Sync),
+    game_name: &str,
+    game_reports_processor: &dyn GameReportsProcessor<SerializedGameState, ErrorType>,
+    number_of_games: u32,
+    number_of_threads: usize,
+    pending_updates_managers: &Vec<&dyn PendingUpdatesManager>,
+) -> Result<(), ErrorType> {
+    let simulator = ParallelSimulator::new(
+        game_name,
+        game_reports_processor,
+        true,
+        number_of_threads,
+        pending_updates_managers,
+    );
+
+    return simulator.run_simulations(number_of_games, play_game);
+}
@@ -0,0 +1,115 @@
+use crate::cli::enums::{
+    AgentKind, GameReportsProcessorKind, TurnTakerConfig, WeightsCalculatorConfig,
+};
+use crate::enums::MinimaxSearchLimit;
+#[cfg(feature = "torch")]
+use crate::structs::ReplayBufferTrainingParameters;
+use serde::Deserialize;
+
+#[derive(Clone, Debug)]
+pub struct EvaluateRecordsOptions {
+    pub record_values_weights: RecordValuesWeights,
+    pub sqlite_db_path: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct InteractiveGameOptions {
+    pub cli_input_player_index: i32,
+    pub mcts_exploration_constant: f32,
+    pub mcts_simulations: u32,
+    pub minimax_depth: u32,
+    pub minimax_search_limit: MinimaxSearchLimit,
+    pub record_values_weights: RecordValuesWeights,
+}
+
+/// The weights given to the recorded outcomes of game states when weighing them.
+#[derive(Clone, Copy, Debug)]
+pub struct RecordValuesWeights {
+    pub draws_weight: f32,
+    pub losses_weight: f32,
+    pub visits_deficit_weight: f32,
+    pub wins_weight: f32,
+}
+
+#[derive(Clone, Debug)]
+pub struct PerftOptions {
+    pub depth: u32,
+    pub is_dividing: bool,
+    /// Counts from the initial game state when missing.
+    pub serialized_game_state: Option<Vec<u8>>,
+}
+
+/// Where the state of an experiment is kept between runs.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PersistenceConfig {
+    /// Whether new game outcomes are saved to the database or only kept in the cache.
+    pub is_saving_game_state_records_enabled: bool,
+    pub lru_cache_max_capacity: usize,
+    /// Defaults to "<game>-policy-value-var-store.weights".
+    pub policy_value_net_weights_file_name: Option<String>,
+    pub sqlite_db_path: String,
+    /// Defaults to "<game>-var-store.weights".
+    pub value_net_weights_file_name: Option<String>,
+}
+
+impl Default for PersistenceConfig {
+    fn default() -> PersistenceConfig {
+        return PersistenceConfig {
+            is_saving_game_state_records_enabled: true,
+            lru_cache_max_capacity: 1_000_000,
+            policy_value_net_weights_file_name: None,
+            sqlite_db_path: String::from("./GamesHistory.db"),
+            value_net_weights_file_name: None,
+        };
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PlayerConfig {
+    pub turn_taker: TurnTakerConfig,
+    pub weights_calculator: WeightsCalculatorConfig,
+}
+
+/// Everything about a run of simulate-games, so that an experiment can be reproduced from a single file.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SimulateGamesConfig {
+    pub game: String,
+    pub game_reports_processors: Vec<GameReportsProcessorKind>,
+    #[serde(default = "default_is_reaching_max_number_of_turns_a_draw")]
+    pub is_reaching_max_number_of_turns_a_draw: bool,
+    #[serde(default = "default_max_number_of_turns")]
+    pub max_number_of_turns: i32,
+    pub number_of_games: u32,
+    #[serde(default = "default_number_of_threads")]
+    pub number_of_threads: usize,
+    #[serde(default)]
+    pub persistence: PersistenceConfig,
+    /// The players by the player index they play as.
+    pub players: Vec<PlayerConfig>,
+    #[cfg(feature = "torch")]
+    #[serde(default)]
+    pub training: ReplayBufferTrainingParameters,
+}
+
+fn default_is_reaching_max_number_of_turns_a_draw() -> bool {
+    return true;
+}
+
+fn default_max_number_of_turns() -> i32 {
+    return 1000;
+}
+
+fn default_number_of_threads() -> usize {
+    return 1;
+}
+
+#[derive(Clone, Debug)]
+pub struct TournamentOptions {
+    pub agents: Vec<(String, AgentKind)>,
+    pub max_number_of_turns: i32,
+    pub number_of_games_per_side: u32,
+    pub record_values_weights: RecordValuesWeights,
+}
@@ -0,0 +1,384 @@
+use crate::cli::create_game_runner::create_game_runner;
+use crate::cli::create_game_state_records_dal::create_game_state_records_dal;
+use crate::cli::enums::AgentKind;
+use crate::cli::structs::{RecordValuesWeights, TournamentOptions};
+use crate::cli::GameRegistry;
+use crate::game_state_records_providers::LruCacheFrontedGameStateRecordsProvider;
+use crate::structs::{TournamentAgent, TournamentResults};
+use crate::tournaments::{calculate_elo_ratings, RoundRobinTournament};
+use crate::traits::{
+    BasicGameState, ByteArrayConvertibleSerializedGameState, GameDefinition, GameRulesAuthority,
+    GameStateSerializer, GameStateWeightsCalculator, TurnTaker,
+};
+use crate::turn_takers::{BestWeightSelectionTurnTaker, WeightedRandomSelectionTurnTaker};
+#[cfg(feature = "torch")]
+use crate::weights_calculators::CnnGameStateWeightsCalculator;
+use crate::weights_calculators::{
+    RecordValuesWeightedSumGameStateWeightsCalculator, UniformGameStateWeightsCalculator,
+};
+#[cfg(feature = "torch")]
+use tch::{nn, Device};
+
+pub fn tournament(args: Vec<String>, game_registry: &GameRegistry) -> Result<(), ()> {
+    let mut game_name = String::new();
+    let mut agent_specifications: Vec<String> = vec![];
+    let mut number_of_games: u32 = 10;
+    let mut max_number_of_turns = 1000;
+    let mut draws_weight = 5.0;
+    let mut losses_weight = -10.0;
+    let mut wins_weight = 10.0;
+    let mut visits_deficit_weight = 20.0;
+
+    {
+        let game_help = format!(
+            "Game to run (either {})",
+            game_registry.describe_game_names()
+        );
+        let mut arg_parser = argparse::ArgumentParser::new();
+
+        arg_parser.refer(&mut game_name).required().add_option(
+            &["-g", "--game"],
+            argparse::Store,
+            &game_help,
+        );
+
+        arg_parser.refer(&mut agent_specifications).required().add_option(
+            &["-a", "--agents"],
+            argparse::List,
+            r#"Agents to enter, each as "name=kind" or just "kind", where kind is either "greedy", "weighted-random", "random" or "cnn:<weights file>""#,
+        );
+
+        arg_parser.refer(&mut number_of_games).add_option(
+            &["-n", "--numgames"],
+            argparse::Parse,
+            "Number of games each agent plays as each player against every other agent",
+        );
+
+        arg_parser.refer(&mut max_number_of_turns).add_option(
+            &["-m", "--maxturns"],
+            argparse::Parse,
+            "Maximum number of turns per game before calling it a draw",
+        );
+
+        arg_parser.refer(&mut draws_weight).add_option(
+            &["--draws-weight"],
+            argparse::Parse,
+            "Weight of draws for state decisions",
+        );
+
+        arg_parser.refer(&mut losses_weight).add_option(
+            &["--losses-weight"],
+            argparse::Parse,
+            "Weight of losses for state decisions",
+        );
+
+        arg_parser.refer(&mut wins_weight).add_option(
+            &["--wins-weight"],
+            argparse::Parse,
+            "Weight of wins for state decisions",
+        );
+
+        arg_parser.refer(&mut visits_deficit_weight).add_option(
+            &["--visits-deficit-weight"],
+            argparse::Parse,
+            "Weight of visits deficit for state decisions",
+        );
+
+        match arg_parser.parse(args, &mut std::io::stdout(), &mut std::io::stderr()) {
+            Ok(()) => (),
+            Err(x) => {
+                println!("Failed to parse arguments, please try again");
+                std::process::exit(x);
+            }
+        }
+    }
+
+    let mut agents: Vec<(String, AgentKind)> = vec![];
+    for agent_specification in agent_specifications.iter() {
+        match parse_agent_specification(agent_specification) {
+            Ok(agent) => agents.push(agent),
+            Err(()) => {
+                println!("Invalid agent specification: {}", agent_specification);
+                return Err(());
+            }
+        }
+    }
+
+    if agents.len() < 2 {
+        println!("A tournament needs at least two agents.");
+        return Err(());
+    }
+
+    let registered_game = match game_registry.find_registered_game(&game_name) {
+        Some(registered_game) => registered_game,
+        None => {
+            println!(
+                "Unknown game {}, expected either {}.",
+                game_name,
+                game_registry.describe_game_names()
+            );
+            return Err(());
+        }
+    };
+
+    return registered_game.run_tournament(&TournamentOptions {
+        agents: agents,
+        max_number_of_turns: max_number_of_turns,
+        number_of_games_per_side: number_of_games,
+        record_values_weights: RecordValuesWeights {
+            draws_weight: draws_weight,
+            losses_weight: losses_weight,
+            visits_deficit_weight: visits_deficit_weight,
+            wins_weight: wins_weight,
+        },
+    });
+}
+
+/// Runs a round-robin tournament between the given agents and prints its results.
+/// Neural net agents can only enter the tournament for games with a value net.
+pub fn run_tournament<
+    GameState: BasicGameState,
+    SerializedGameState: ByteArrayConvertibleSerializedGameState + Ord + Send + Sync,
+>(
+    game_definition: &dyn GameDefinition<GameState, SerializedGameState>,
+    options: &TournamentOptions,
+) -> Result<(), ()> {
+    let game_name = game_definition.get_name();
+    let sqlite_db_path = "./GamesHistory.db";
+
+    // Every game is played between a pair of agents
+    if game_definition.get_number_of_players() != 2 {
+        println!(
+            "Tournaments are only held for two player games, {} has {} players.",
+            game_name,
+            game_definition.get_number_of_players()
+        );
+        return Err(());
+    }
+
+    let lru_cache_max_capacity: usize = 1_000_000;
+    let (game_state_records_dal, canonicalizing_game_state_serializer) =
+        create_game_state_records_dal(game_definition, sqlite_db_path);
+    let game_state_records_provider = LruCacheFrontedGameStateRecordsProvider::new(
+        lru_cache_max_capacity,
+        &game_state_records_dal,
+    );
+
+    let game_rules_authority = game_definition.get_game_rules_authority();
+    let game_state_serializer = game_definition.get_game_state_serializer();
+    let game_state_records_serializer: &(dyn GameStateSerializer<GameState, SerializedGameState>
+          + Sync) = match &canonicalizing_game_state_serializer {
+        Some(canonicalizing_game_state_serializer) => canonicalizing_game_state_serializer,
+        None => game_state_serializer,
+    };
+
+    let record_values_weights = options.record_values_weights;
+    let game_state_records_weights_calculator =
+        RecordValuesWeightedSumGameStateWeightsCalculator::new(
+            &game_state_records_provider,
+            game_state_records_serializer,
+            record_values_weights.draws_weight,
+            record_values_weights.losses_weight,
+            record_values_weights.wins_weight,
+            record_values_weights.visits_deficit_weight,
+        );
+    let uniform_weights_calculator = UniformGameStateWeightsCalculator {};
+
+    // Every neural net agent gets its own net, loaded from its own weights file
+    #[cfg(feature = "torch")]
+    let mut torch_nets = vec![];
+    #[cfg(feature = "torch")]
+    for (agent_name, agent_kind) in options.agents.iter() {
+        let weights_file_name = match agent_kind {
+            AgentKind::Cnn(weights_file_name) => weights_file_name,
+            _ => {
+                torch_nets.push(None);
+                continue;
+            }
+        };
+
+        let neural_net_definition = match game_definition.get_neural_net_definition() {
+            Some(neural_net_definition) => neural_net_definition,
+            None => {
+                println!(
+                    "There is no neural net for {}, so {} cannot enter the tournament.",
+                    game_name, agent_name
+                );
+                return Err(());
+            }
+        };
+
+        let mut torch_var_store = nn::VarStore::new(Device::cuda_if_available());
+        let torch_net = (neural_net_definition.create_value_net)(&torch_var_store.root());
+        torch_var_store
+            .load(weights_file_name)
+            .expect("Failed to load the neural net weights.");
+        torch_nets.push(Some((neural_net_definition, torch_var_store, torch_net)));
+    }
+    #[cfg(feature = "torch")]
+    let cnn_weights_calculators: Vec<Option<CnnGameStateWeightsCalculator<GameState>>> = torch_nets
+        .iter()
+        .map(|torch_net_option| {
+            return torch_net_option.as_ref().map(
+                |(neural_net_definition, torch_var_store, torch_net)| {
+                    CnnGameStateWeightsCalculator::new(
+                        torch_var_store.device(),
+                        torch_net.as_ref(),
+                        &neural_net_definition.transform_game_state_to_tensor,
+                    )
+                },
+            );
+        })
+        .collect();
+
+    let mut agents_turn_takers = vec![];
+    for agent_index in 0..options.agents.len() {
+        let agent_kind = &options.agents[agent_index].1;
+        let game_state_weights_calculator: &dyn GameStateWeightsCalculator<GameState> =
+            match agent_kind {
+                #[cfg(feature = "torch")]
+                AgentKind::Cnn(_) => cnn_weights_calculators[agent_index].as_ref().unwrap(),
+                AgentKind::Random => &uniform_weights_calculator,
+                _ => &game_state_records_weights_calculator,
+            };
+
+        agents_turn_takers.push(create_agent_turn_takers(
+            agent_kind,
+            game_rules_authority,
+            game_state_weights_calculator,
+        ));
+    }
+
+    let tournament_agents: Vec<TournamentAgent<GameState>> = options
+        .agents
+        .iter()
+        .zip(agents_turn_takers.iter())
+        .map(|((agent_name, _), turn_takers)| TournamentAgent {
+            name: agent_name.clone(),
+            turn_takers: turn_takers
+                .iter()
+                .map(|turn_taker| turn_taker.as_ref())
+                .collect(),
+        })
+        .collect();
+
+    let game_runner = create_game_runner(game_definition);
+    let tournament = RoundRobinTournament::new(game_runner.as_ref(), true);
+    let tournament_results = tournament.run_tournament(
+        &tournament_agents,
+        &|| game_definition.create_initial_game_state(),
+        options.number_of_games_per_side,
+        options.max_number_of_turns,
+        true,
+    );
+
+    print_tournament_results(&tournament_results);
+    return Ok(());
+}
+
+/// Parses an agent specification of the form "name=kind", or just "kind" in which case the kind doubles as the name.
+fn parse_agent_specification(agent_specification: &str) -> Result<(String, AgentKind), ()> {
+    let (agent_name, agent_kind_string) = match agent_specification.find('=') {
+        Some(separator_index) => (
+            &agent_specification[..separator_index],
+            &agent_specification[separator_index + 1..],
+        ),
+        None => (agent_specification, agent_specification),
+    };
+
+    let agent_kind: AgentKind = agent_kind_string.parse()?;
+    return Ok((String::from(agent_name), agent_kind));
+}
+
+/// Creates the turn takers of an agent, one for each player index.
+fn create_agent_turn_takers<'a, GameState: BasicGameState>(
+    agent_kind: &AgentKind,
+    game_rules_authority: &'a dyn GameRulesAuthority<GameState>,
+    game_state_weights_calculator: &'a dyn GameStateWeightsCalculator<GameState>,
+) -> Vec<Box<dyn TurnTaker<GameState> + 'a>> {
+    return (0..2)
+        .map(|player_index| -> Box<dyn TurnTaker<GameState> + 'a> {
+            return match agent_kind {
+                AgentKind::Random | AgentKind::WeightedRandom => {
+                    Box::new(WeightedRandomSelectionTurnTaker::new(
+                        game_rules_authority,
+                        game_state_weights_calculator,
+                        player_index,
+                    ))
+                }
+                _ => Box::new(BestWeightSelectionTurnTaker::new(
+                    game_rules_authority,
+                    game_state_weights_calculator,
+                    player_index,
+                )),
+            };
+        })
+        .collect();
+}
+
+fn print_tournament_results(tournament_results: &TournamentResults) {
+    let agents_count = tournament_results.agent_names.len();
+    let column_width = tournament_results
+        .agent_names
+        .iter()
+        .map(|agent_name| agent_name.len())
+        .max()
+        .unwrap_or(0)
+        .max(11)
+        + 2;
+
+    println!();
+    println!("Wins/draws/losses of each row agent against each column agent:");
+
+    let mut header = format!("{:width$}", "", width = column_width);
+    for agent_name in tournament_results.agent_names.iter() {
+        header.push_str(&format!("{:>width$}", agent_name, width = column_width));
+    }
+    println!("{}", header);
+
+    for i in 0..agents_count {
+        let mut row = format!(
+            "{:width$}",
+            tournament_results.agent_names[i],
+            width = column_width
+        );
+        for j in 0..agents_count {
+            let cell = if i == j {
+                String::from("-")
+            } else {
+                let matchup_record = &tournament_results.matchup_records[i][j];
+                format!(
+                    "{}/{}/{}",
+                    matchup_record.wins_count,
+                    matchup_record.draws_count,
+                    matchup_record.losses_count
+                )
+            };
+            row.push_str(&format!("{:>width$}", cell, width = column_width));
+        }
+        println!("{}", row);
+    }
+
+    if tournament_results.inconclusive_games_count > 0 {
+        println!(
+            "Number of games that were inconclusive: {}.",
+            tournament_results.inconclusive_games_count
+        );
+    }
+
+    let elo_ratings = calculate_elo_ratings(&tournament_results.matchup_records);
+    let mut ranked_agent_indices: Vec<usize> = (0..agents_count).collect();
+    ranked_agent_indices.sort_by(|a, b| elo_ratings[*b].total_cmp(&elo_ratings[*a]));
+
+    println!();
+    println!("Elo ratings:");
+    for (rank, agent_index) in ranked_agent_indices.iter().enumerate() {
+        println!(
+            "{}. {:width$} {:.0}",
+            rank + 1,
+            tournament_results.agent_names[*agent_index],
+            elo_ratings[*agent_index],
+            width = column_width
+        );
+    }
+}
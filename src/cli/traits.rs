@@ -0,0 +1,25 @@
+use crate::cli::structs::{
+    EvaluateRecordsOptions, InteractiveGameOptions, PerftOptions, SimulateGamesConfig,
+    TournamentOptions,
+};
+
+/// A game as seen by the commands, with its game state type hidden away
+/// so that games of all types can be kept side by side in the game registry.
+pub trait RegisteredGame {
+    fn get_name(&self) -> &str;
+
+    fn get_number_of_players(&self) -> i32;
+
+    /// Whether the game can be played by neural nets.
+    fn has_neural_nets(&self) -> bool;
+
+    fn run_evaluate_records(&self, options: &EvaluateRecordsOptions) -> Result<(), ()>;
+
+    fn run_interactive_game(&self, options: &InteractiveGameOptions) -> Result<(), ()>;
+
+    fn run_perft(&self, options: &PerftOptions) -> Result<(), ()>;
+
+    fn run_simulate_games(&self, config: &SimulateGamesConfig) -> Result<(), ()>;
+
+    fn run_tournament(&self, options: &TournamentOptions) -> Result<(), ()>;
+}
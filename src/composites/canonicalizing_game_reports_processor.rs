@@ -0,0 +1,70 @@
+use crate::structs::GameReport;
+use crate::traits::{
+    BasicGameState, BasicSerializedGameState, GameReportsProcessor, GameStateDeserializer,
+    GameStateSerializer,
+};
+
+/// Hands game reports over to a game reports processor with their game states serialized again by the given serializer,
+/// e.g. so that the game state records of game states equivalent by symmetry are kept together.
+/// The game report itself is left untouched for the other processors.
+pub struct CanonicalizingGameReportsProcessor<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+    ErrorType,
+> {
+    game_reports_processor: &'a dyn GameReportsProcessor<SerializedGameState, ErrorType>,
+    game_state_deserializer: &'a dyn GameStateDeserializer<GameState, SerializedGameState>,
+    game_state_serializer: &'a dyn GameStateSerializer<GameState, SerializedGameState>,
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState, ErrorType>
+    CanonicalizingGameReportsProcessor<'a, GameState, SerializedGameState, ErrorType>
+{
+    pub fn new(
+        game_reports_processor: &'a dyn GameReportsProcessor<SerializedGameState, ErrorType>,
+        game_state_deserializer: &'a dyn GameStateDeserializer<GameState, SerializedGameState>,
+        game_state_serializer: &'a dyn GameStateSerializer<GameState, SerializedGameState>,
+    ) -> CanonicalizingGameReportsProcessor<'a, GameState, SerializedGameState, ErrorType> {
+        return CanonicalizingGameReportsProcessor {
+            game_reports_processor: game_reports_processor,
+            game_state_deserializer: game_state_deserializer,
+            game_state_serializer: game_state_serializer,
+        };
+    }
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState, ErrorType>
+    GameReportsProcessor<SerializedGameState, ErrorType>
+    for CanonicalizingGameReportsProcessor<'a, GameState, SerializedGameState, ErrorType>
+{
+    fn process_game_report(
+        &self,
+        game_report: &mut GameReport<SerializedGameState>,
+    ) -> Result<(), ErrorType> {
+        let mut canonical_game_report = game_report.clone();
+        for game_state_update in canonical_game_report.game_state_updates.iter_mut() {
+            let (_, game_state) = self
+                .game_state_deserializer
+                .deserialize_game_state(&game_state_update.new_serialized_game_state);
+            game_state_update.new_serialized_game_state = self
+                .game_state_serializer
+                .serialize_game_state(game_state_update.responsible_player_index, &game_state);
+        }
+        // the move of each player in a round is the game state update they are responsible for
+        for joint_action in canonical_game_report.joint_actions.iter_mut() {
+            for (player_index, serialized_game_state) in joint_action.iter_mut().enumerate() {
+                let (_, game_state) = self
+                    .game_state_deserializer
+                    .deserialize_game_state(serialized_game_state);
+                *serialized_game_state = self
+                    .game_state_serializer
+                    .serialize_game_state(player_index as i32, &game_state);
+            }
+        }
+
+        return self
+            .game_reports_processor
+            .process_game_report(&mut canonical_game_report);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::traits::{
+    BasicGameState, BasicSerializedGameState, GameStateSerializer, GameStateSymmetriesFinder,
+};
+
+/// Serializes a game state as the smallest of the serialized game states of its symmetry class,
+/// so that all the game states equivalent by the symmetries of the game share the same serialized game state.
+/// Game states are still weighed one by one through it, so weights come back in the order of the game states they were asked for.
+pub struct CanonicalizingGameStateSerializer<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState + Ord,
+> {
+    game_state_serializer: &'a (dyn GameStateSerializer<GameState, SerializedGameState> + Sync),
+    game_state_symmetries_finder: &'a (dyn GameStateSymmetriesFinder<GameState> + Sync),
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState + Ord>
+    CanonicalizingGameStateSerializer<'a, GameState, SerializedGameState>
+{
+    pub fn new(
+        game_state_serializer: &'a (dyn GameStateSerializer<GameState, SerializedGameState> + Sync),
+        game_state_symmetries_finder: &'a (dyn GameStateSymmetriesFinder<GameState> + Sync),
+    ) -> CanonicalizingGameStateSerializer<'a, GameState, SerializedGameState> {
+        return CanonicalizingGameStateSerializer {
+            game_state_serializer: game_state_serializer,
+            game_state_symmetries_finder: game_state_symmetries_finder,
+        };
+    }
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState + Ord>
+    GameStateSerializer<GameState, SerializedGameState>
+    for CanonicalizingGameStateSerializer<'a, GameState, SerializedGameState>
+{
+    fn serialize_game_state(
+        &self,
+        responsible_player_index: i32,
+        game_state: &GameState,
+    ) -> SerializedGameState {
+        let serialized_game_state = self
+            .game_state_serializer
+            .serialize_game_state(responsible_player_index, game_state);
+
+        return self
+            .game_state_symmetries_finder
+            .find_symmetric_game_states(responsible_player_index, game_state)
+            .iter()
+            .map(
+                |(symmetric_responsible_player_index, symmetric_game_state)| {
+                    return self.game_state_serializer.serialize_game_state(
+                        *symmetric_responsible_player_index,
+                        symmetric_game_state,
+                    );
+                },
+            )
+            .fold(serialized_game_state, std::cmp::min);
+    }
+}
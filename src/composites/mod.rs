@@ -1,2 +1,8 @@
+mod canonicalizing_game_reports_processor;
+pub use canonicalizing_game_reports_processor::CanonicalizingGameReportsProcessor;
+
+mod canonicalizing_game_state_serializer;
+pub use canonicalizing_game_state_serializer::CanonicalizingGameStateSerializer;
+
 mod game_reports_iterative_processor;
 pub use game_reports_iterative_processor::GameReportsIterativeProcessor;
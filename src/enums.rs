@@ -9,4 +9,17 @@ pub enum RunGameError {
     #[allow(dead_code)]
     Unknown,
     UnableToDecideNextState(i32),
+    UnableToSampleChanceOutcome,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum MinimaxSearchLimit {
+    /// Searches exactly the given number of plies ahead.
+    FixedDepth(u32),
+    /// Searches one ply deeper at a time until either the max depth is reached
+    /// or the time budget runs out, keeping the result of the deepest completed search.
+    IterativeDeepening {
+        max_depth: u32,
+        time_budget: std::time::Duration,
+    },
 }
@@ -1,3 +1,5 @@
+mod simultaneous_game_runner;
 mod standard_turn_based_game_runner;
 
+pub use simultaneous_game_runner::SimultaneousGameRunner;
 pub use standard_turn_based_game_runner::StandardTurnBasedGameRunner;
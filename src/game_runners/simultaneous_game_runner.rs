@@ -0,0 +1,316 @@
+use crate::enums::RunGameError;
+use crate::internal::utility_functions;
+use crate::structs::{GameReport, GameStateUpdate};
+use crate::traits::{
+    BasicGameState, BasicSerializedGameState, GameRunner, GameStateSerializer,
+    SimultaneousGameRulesAuthority, TurnTaker,
+};
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+
+/// Runs games in rounds in which all players move at once.
+/// Every player decides from their own observation of the game state at the start of the round,
+/// and the move of each player is recorded as an update they are responsible for, in player index order,
+/// followed by the update of the resolved game state that no player is responsible for.
+/// A round counts as a single turn towards the max number of turns.
+pub struct SimultaneousGameRunner<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+> {
+    game_rules_authority: &'a dyn SimultaneousGameRulesAuthority<GameState>,
+    game_state_serializer: &'a dyn GameStateSerializer<GameState, SerializedGameState>,
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
+    SimultaneousGameRunner<'a, GameState, SerializedGameState>
+{
+    pub fn new(
+        game_rules_authority: &'a dyn SimultaneousGameRulesAuthority<GameState>,
+        game_state_serializer: &'a dyn GameStateSerializer<GameState, SerializedGameState>,
+    ) -> SimultaneousGameRunner<'a, GameState, SerializedGameState> {
+        return SimultaneousGameRunner {
+            game_rules_authority: game_rules_authority,
+            game_state_serializer: game_state_serializer,
+        };
+    }
+
+    fn create_game_state_update(
+        &self,
+        responsible_player_index: i32,
+        game_state: &GameState,
+    ) -> GameStateUpdate<SerializedGameState> {
+        return GameStateUpdate {
+            new_serialized_game_state: self.game_state_serializer.serialize_game_state(
+                responsible_player_index,
+                &self
+                    .game_rules_authority
+                    .observe_game_state(responsible_player_index, game_state),
+            ),
+            responsible_player_index: responsible_player_index,
+        };
+    }
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
+    GameRunner<GameState, SerializedGameState>
+    for SimultaneousGameRunner<'a, GameState, SerializedGameState>
+{
+    fn run_game(
+        &self,
+        initial_game_state: GameState,
+        turn_takers: &Vec<&dyn TurnTaker<GameState>>,
+        max_number_of_turns: i32,
+        is_reaching_max_number_of_turns_a_draw: bool,
+    ) -> Result<Option<GameReport<SerializedGameState>>, RunGameError> {
+        let mut game_state_updates: Vec<GameStateUpdate<SerializedGameState>> =
+            vec![self.create_game_state_update(-1, &initial_game_state)];
+        let mut joint_actions: Vec<Vec<SerializedGameState>> = vec![];
+
+        let number_of_players = turn_takers.len() as i32;
+
+        let mut game_states_history: Vec<GameState> = vec![initial_game_state.clone()];
+        let mut current_game_state = initial_game_state;
+        let mut rounds_counter = 0;
+
+        while max_number_of_turns == -1 || rounds_counter < max_number_of_turns {
+            // All the players are about to move, so the first one stands in for the next player
+            match self
+                .game_rules_authority
+                .analyze_game_history_for_terminality(&game_states_history, 0)
+            {
+                None => (),
+                Some(winning_player_index) => {
+                    let player_ranks = self.game_rules_authority.rank_players(
+                        &current_game_state,
+                        winning_player_index,
+                        number_of_players,
+                    );
+                    return Ok(Some(GameReport {
+                        actions: None,
+                        game_state_updates: game_state_updates,
+                        joint_actions: joint_actions,
+                        number_of_players: number_of_players,
+                        player_ranks: player_ranks,
+                        winning_player_index: winning_player_index,
+                    }));
+                }
+            }
+
+            rounds_counter += 1;
+
+            let chance_outcomes = self
+                .game_rules_authority
+                .find_chance_outcomes(0, &current_game_state);
+            if !chance_outcomes.is_empty() {
+                let chance_outcome_probabilities: Vec<f32> = chance_outcomes
+                    .iter()
+                    .map(|(_, probability)| *probability)
+                    .collect();
+                let chance_outcome_index = match WeightedIndex::new(&chance_outcome_probabilities) {
+                    Ok(dist) => dist.sample(&mut rand::thread_rng()),
+                    Err(_) => return Err(RunGameError::UnableToSampleChanceOutcome),
+                };
+                current_game_state = chance_outcomes[chance_outcome_index].0.clone();
+                game_state_updates.push(self.create_game_state_update(-1, &current_game_state));
+                game_states_history.push(current_game_state.clone());
+            }
+
+            let mut players_next_game_states: Vec<GameState> = vec![];
+            let mut joint_action: Vec<SerializedGameState> = vec![];
+            for player_index in 0..number_of_players {
+                let observed_game_state = self
+                    .game_rules_authority
+                    .observe_game_state(player_index, &current_game_state);
+                let player_next_game_state = match turn_takers[player_index as usize]
+                    .decide_next_game_state(&observed_game_state)
+                {
+                    Ok(new_observed_game_state) => {
+                        match utility_functions::find_next_game_state_from_observation(
+                            self.game_rules_authority,
+                            self.game_state_serializer,
+                            player_index,
+                            &current_game_state,
+                            &observed_game_state,
+                            &new_observed_game_state,
+                        ) {
+                            Some(player_next_game_state) => player_next_game_state,
+                            None => {
+                                return Err(RunGameError::UnableToDecideNextState(player_index))
+                            }
+                        }
+                    }
+                    Err(_) => return Err(RunGameError::UnableToDecideNextState(player_index)),
+                };
+
+                let game_state_update =
+                    self.create_game_state_update(player_index, &player_next_game_state);
+                joint_action.push(game_state_update.new_serialized_game_state.clone());
+                game_state_updates.push(game_state_update);
+                players_next_game_states.push(player_next_game_state);
+            }
+            joint_actions.push(joint_action);
+
+            current_game_state = self
+                .game_rules_authority
+                .resolve_joint_game_states(&current_game_state, &players_next_game_states);
+            game_state_updates.push(self.create_game_state_update(-1, &current_game_state));
+            game_states_history.push(current_game_state.clone());
+        }
+
+        if max_number_of_turns > -1
+            && rounds_counter == max_number_of_turns
+            && is_reaching_max_number_of_turns_a_draw
+        {
+            return Ok(Some(GameReport {
+                actions: None,
+                game_state_updates: game_state_updates,
+                joint_actions: joint_actions,
+                number_of_players: number_of_players,
+                player_ranks: vec![0; number_of_players as usize],
+                winning_player_index: -1,
+            }));
+        }
+
+        return Ok(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::goofspiel::{
+        ByteArrayGameStateDeserializer, ByteArrayGameStateSerializer,
+        GameRulesAuthority as GoofspielGameRulesAuthority, GameStateType as GoofspielGameState,
+    };
+    use crate::traits::GameStateDeserializer;
+    use crate::turn_takers::WeightedRandomSelectionTurnTaker;
+    use crate::weights_calculators::UniformGameStateWeightsCalculator;
+
+    #[test]
+    fn test_resolving_goofspiel_rounds() {
+        let game_rules_authority = GoofspielGameRulesAuthority {};
+        let game_state_serializer = ByteArrayGameStateSerializer {};
+        let game_state_deserializer = ByteArrayGameStateDeserializer {};
+        let weights_calculator = UniformGameStateWeightsCalculator {};
+        let game_runner =
+            SimultaneousGameRunner::new(&game_rules_authority, &game_state_serializer);
+        let first_turn_taker =
+            WeightedRandomSelectionTurnTaker::new(&game_rules_authority, &weights_calculator, 0);
+        let second_turn_taker =
+            WeightedRandomSelectionTurnTaker::new(&game_rules_authority, &weights_calculator, 1);
+        let turn_takers: Vec<&dyn TurnTaker<GoofspielGameState>> =
+            vec![&first_turn_taker, &second_turn_taker];
+
+        // The first player bids a 3 against a 1 for the last prize card, a 2
+        let game_state = GoofspielGameState {
+            player_hands: [0b100, 0b001],
+            prize_deck: 0b010,
+            prize_card: 0,
+            bids: [0, 0],
+            scores: [0, 0],
+        };
+        let game_report = game_runner
+            .run_game(game_state, &turn_takers, -1, true)
+            .unwrap()
+            .unwrap();
+
+        // the prize card is revealed, then each player bids in player index order before the round is resolved
+        let responsible_player_indices: Vec<i32> = game_report
+            .game_state_updates
+            .iter()
+            .map(|game_state_update| game_state_update.responsible_player_index)
+            .collect();
+        assert_eq!(responsible_player_indices, vec![-1, -1, 0, 1, -1]);
+        let (_, second_player_bid_game_state) = game_state_deserializer
+            .deserialize_game_state(&game_report.game_state_updates[3].new_serialized_game_state);
+        assert_eq!(second_player_bid_game_state.bids, [0, 1]);
+        let (_, resolved_game_state) = game_state_deserializer
+            .deserialize_game_state(&game_report.game_state_updates[4].new_serialized_game_state);
+        assert_eq!(
+            resolved_game_state,
+            GoofspielGameState {
+                player_hands: [0, 0],
+                prize_deck: 0,
+                prize_card: 0,
+                bids: [0, 0],
+                scores: [2, 0],
+            }
+        );
+        assert_eq!(game_report.winning_player_index, 0);
+
+        // Equal bids discard the prize card, leaving the game drawn
+        let game_state = GoofspielGameState {
+            player_hands: [0b010, 0b010],
+            prize_deck: 0b100,
+            prize_card: 0,
+            bids: [0, 0],
+            scores: [0, 0],
+        };
+        let game_report = game_runner
+            .run_game(game_state, &turn_takers, -1, true)
+            .unwrap()
+            .unwrap();
+        let (_, resolved_game_state) = game_state_deserializer.deserialize_game_state(
+            &game_report
+                .game_state_updates
+                .last()
+                .unwrap()
+                .new_serialized_game_state,
+        );
+        assert_eq!(resolved_game_state.scores, [0, 0]);
+        assert_eq!(game_report.winning_player_index, -1);
+        assert_eq!(game_report.player_ranks, vec![0, 0]);
+    }
+
+    #[test]
+    fn test_recording_joint_actions() {
+        let game_rules_authority = GoofspielGameRulesAuthority {};
+        let game_state_serializer = ByteArrayGameStateSerializer {};
+        let game_state_deserializer = ByteArrayGameStateDeserializer {};
+        let weights_calculator = UniformGameStateWeightsCalculator {};
+        let game_runner =
+            SimultaneousGameRunner::new(&game_rules_authority, &game_state_serializer);
+        let first_turn_taker =
+            WeightedRandomSelectionTurnTaker::new(&game_rules_authority, &weights_calculator, 0);
+        let second_turn_taker =
+            WeightedRandomSelectionTurnTaker::new(&game_rules_authority, &weights_calculator, 1);
+        let turn_takers: Vec<&dyn TurnTaker<GoofspielGameState>> =
+            vec![&first_turn_taker, &second_turn_taker];
+
+        let game_state = GoofspielGameState {
+            player_hands: [0b100, 0b001],
+            prize_deck: 0b010,
+            prize_card: 0,
+            bids: [0, 0],
+            scores: [0, 0],
+        };
+        let game_report = game_runner
+            .run_game(game_state, &turn_takers, -1, true)
+            .unwrap()
+            .unwrap();
+
+        // A single round was played, in which each player moved from the game state the prize card was revealed in
+        assert_eq!(game_report.joint_actions.len(), 1);
+        let players_bids: Vec<[u8; 2]> = game_report.joint_actions[0]
+            .iter()
+            .map(|serialized_game_state| {
+                let (_, game_state) =
+                    game_state_deserializer.deserialize_game_state(serialized_game_state);
+                return game_state.bids;
+            })
+            .collect();
+        assert_eq!(players_bids, vec![[3, 0], [0, 1]]);
+        assert_eq!(
+            game_report.joint_actions[0],
+            vec![
+                game_report.game_state_updates[2]
+                    .new_serialized_game_state
+                    .clone(),
+                game_report.game_state_updates[3]
+                    .new_serialized_game_state
+                    .clone(),
+            ]
+        );
+    }
+}
@@ -1,15 +1,19 @@
 use crate::enums::RunGameError;
+use crate::internal::utility_functions;
 use crate::structs::{GameReport, GameStateUpdate};
 use crate::traits::{
-    BasicGameState, BasicSerializedGameState, GameRulesAuthority, GameRunner, GameStateSerializer,
-    TurnTaker,
+    BasicGameState, BasicSerializedGameState, GameActionNotationsFinder, GameRulesAuthority,
+    GameRunner, GameStateSerializer, TurnTaker,
 };
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
 
 pub struct StandardTurnBasedGameRunner<
     'a,
     GameState: BasicGameState,
     SerializedGameState: BasicSerializedGameState,
 > {
+    game_action_notations_finder: Option<&'a dyn GameActionNotationsFinder<GameState>>,
     game_rules_authority: &'a dyn GameRulesAuthority<GameState>,
     game_state_serializer: &'a dyn GameStateSerializer<GameState, SerializedGameState>,
 }
@@ -22,10 +26,47 @@ impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameStat
         game_state_serializer: &'a dyn GameStateSerializer<GameState, SerializedGameState>,
     ) -> StandardTurnBasedGameRunner<'a, GameState, SerializedGameState> {
         return StandardTurnBasedGameRunner {
+            game_action_notations_finder: None,
             game_rules_authority: game_rules_authority,
             game_state_serializer: game_state_serializer,
         };
     }
+
+    /// Creates a game runner that also records the notation and the policy tensor index of the action behind every move in its game reports.
+    pub fn new_with_action_notations(
+        game_rules_authority: &'a dyn GameRulesAuthority<GameState>,
+        game_state_serializer: &'a dyn GameStateSerializer<GameState, SerializedGameState>,
+        game_action_notations_finder: &'a dyn GameActionNotationsFinder<GameState>,
+    ) -> StandardTurnBasedGameRunner<'a, GameState, SerializedGameState> {
+        return StandardTurnBasedGameRunner {
+            game_action_notations_finder: Some(game_action_notations_finder),
+            game_rules_authority: game_rules_authority,
+            game_state_serializer: game_state_serializer,
+        };
+    }
+
+    /// Finds the notation and the policy tensor index of the action that leads the given player from the current game state to the new one.
+    fn find_action(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GameState,
+        new_game_state: &GameState,
+    ) -> Option<(String, usize)> {
+        let game_action_notations_finder = self.game_action_notations_finder?;
+        let serialized_new_game_state = self
+            .game_state_serializer
+            .serialize_game_state(current_player_index, new_game_state);
+        return game_action_notations_finder
+            .find_legal_action_notations_and_indices(current_player_index, current_game_state)
+            .into_iter()
+            .find(|(_, _, available_next_game_state)| {
+                return self
+                    .game_state_serializer
+                    .serialize_game_state(current_player_index, available_next_game_state)
+                    == serialized_new_game_state;
+            })
+            .map(|(action_notation, action_index, _)| (action_notation, action_index));
+    }
 }
 
 impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
@@ -47,9 +88,14 @@ impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameStat
                 responsible_player_index: -1,
             }];
 
+        let mut actions: Option<Vec<Option<(String, usize)>>> =
+            self.game_action_notations_finder.map(|_| vec![None]);
+
         let number_of_players = turn_takers.len() as i32;
 
         let mut current_player_index: i32 = -1;
+        // Holds the game state of every update so that the rules can judge the game by its whole history
+        let mut game_states_history: Vec<GameState> = vec![initial_game_state.clone()];
         let mut current_game_state = initial_game_state;
         let mut turns_counter = 0;
 
@@ -59,14 +105,22 @@ impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameStat
             // Check if the current state is terminal given the next player index before starting their turn
             match self
                 .game_rules_authority
-                .analyze_game_state_for_terminality(&current_game_state, next_player_index)
+                .analyze_game_history_for_terminality(&game_states_history, next_player_index)
             {
                 None => (),
                 Some(winning_player_index) => {
                     // A terminal index was reached - just return, we're done here.
+                    let player_ranks = self.game_rules_authority.rank_players(
+                        &current_game_state,
+                        winning_player_index,
+                        number_of_players,
+                    );
                     return Ok(Some(GameReport {
+                        actions: actions,
                         game_state_updates: game_state_updates,
+                        joint_actions: vec![],
                         number_of_players: number_of_players,
+                        player_ranks: player_ranks,
                         winning_player_index: winning_player_index,
                     }));
                 }
@@ -76,23 +130,109 @@ impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameStat
 
             turns_counter += 1;
 
+            let previous_player_index = current_player_index;
             current_player_index = next_player_index;
             let current_turn_taker = turn_takers[current_player_index as usize];
 
-            match current_turn_taker.decide_next_game_state(&current_game_state) {
-                Ok(new_game_state) => {
-                    let new_serialized_game_state = self
-                        .game_state_serializer
-                        .serialize_game_state(current_player_index, &new_game_state);
-                    game_state_updates.push(GameStateUpdate {
-                        new_serialized_game_state: new_serialized_game_state,
-                        responsible_player_index: current_player_index,
-                    });
-
-                    current_game_state = new_game_state;
+            let chance_outcomes = self
+                .game_rules_authority
+                .find_chance_outcomes(current_player_index, &current_game_state);
+            if !chance_outcomes.is_empty() {
+                let chance_outcome_probabilities: Vec<f32> = chance_outcomes
+                    .iter()
+                    .map(|(_, probability)| *probability)
+                    .collect();
+                let chance_outcome_index = match WeightedIndex::new(&chance_outcome_probabilities) {
+                    Ok(dist) => dist.sample(&mut rand::thread_rng()),
+                    Err(_) => return Err(RunGameError::UnableToSampleChanceOutcome),
+                };
+                current_game_state = chance_outcomes[chance_outcome_index].0.clone();
+
+                // No player decides the outcome of a chance event, so it is recorded as part of the last player's turn
+                // so that the player about to move still follows the responsible player of the latest update
+                let new_serialized_game_state = self.game_state_serializer.serialize_game_state(
+                    previous_player_index,
+                    &self
+                        .game_rules_authority
+                        .observe_game_state(previous_player_index, &current_game_state),
+                );
+                game_state_updates.push(GameStateUpdate {
+                    new_serialized_game_state: new_serialized_game_state,
+                    responsible_player_index: previous_player_index,
+                });
+                if let Some(actions) = actions.as_mut() {
+                    actions.push(None);
+                }
+                game_states_history.push(current_game_state.clone());
+            }
+
+            if self
+                .game_rules_authority
+                .is_passing_forced(current_player_index, &current_game_state)
+            {
+                // There's nothing for the turn taker to decide, the turn goes by with the game state left as it is
+                let new_serialized_game_state = self.game_state_serializer.serialize_game_state(
+                    current_player_index,
+                    &self
+                        .game_rules_authority
+                        .observe_game_state(current_player_index, &current_game_state),
+                );
+                game_state_updates.push(GameStateUpdate {
+                    new_serialized_game_state: new_serialized_game_state,
+                    responsible_player_index: current_player_index,
+                });
+                if let Some(actions) = actions.as_mut() {
+                    actions.push(None);
+                }
+                game_states_history.push(current_game_state.clone());
+
+                continue;
+            }
+
+            let observed_game_state = self
+                .game_rules_authority
+                .observe_game_state(current_player_index, &current_game_state);
+            let new_game_state = match current_turn_taker
+                .decide_next_game_state(&observed_game_state)
+            {
+                Ok(new_observed_game_state) => {
+                    match utility_functions::find_next_game_state_from_observation(
+                        self.game_rules_authority,
+                        self.game_state_serializer,
+                        current_player_index,
+                        &current_game_state,
+                        &observed_game_state,
+                        &new_observed_game_state,
+                    ) {
+                        Some(new_game_state) => new_game_state,
+                        None => {
+                            return Err(RunGameError::UnableToDecideNextState(current_player_index))
+                        }
+                    }
                 }
                 Err(_) => return Err(RunGameError::UnableToDecideNextState(current_player_index)),
+            };
+
+            let new_serialized_game_state = self.game_state_serializer.serialize_game_state(
+                current_player_index,
+                &self
+                    .game_rules_authority
+                    .observe_game_state(current_player_index, &new_game_state),
+            );
+            game_state_updates.push(GameStateUpdate {
+                new_serialized_game_state: new_serialized_game_state,
+                responsible_player_index: current_player_index,
+            });
+            if let Some(actions) = actions.as_mut() {
+                actions.push(self.find_action(
+                    current_player_index,
+                    &current_game_state,
+                    &new_game_state,
+                ));
             }
+
+            game_states_history.push(new_game_state.clone());
+            current_game_state = new_game_state;
         }
 
         if max_number_of_turns > -1
@@ -100,8 +240,11 @@ impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameStat
             && is_reaching_max_number_of_turns_a_draw
         {
             return Ok(Some(GameReport {
+                actions: actions,
                 game_state_updates: game_state_updates,
+                joint_actions: vec![],
                 number_of_players: number_of_players,
+                player_ranks: vec![0; number_of_players as usize],
                 winning_player_index: -1,
             }));
         }
@@ -109,3 +252,102 @@ impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameStat
         return Ok(None);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::simplified_backgammon::{
+        ByteArrayGameStateDeserializer, ByteArrayGameStateSerializer,
+        GameRulesAuthority as SimplifiedBackgammonGameRulesAuthority,
+        GameStateType as SimplifiedBackgammonGameState,
+    };
+    use crate::games::tic_tac_toe::{
+        FixedWidthGameStateSerializer, GameRulesAuthority as TicTacToeGameRulesAuthority,
+        GameStateType as TicTacToeGameState,
+    };
+    use crate::traits::GameStateDeserializer;
+    use crate::turn_takers::WeightedRandomSelectionTurnTaker;
+    use crate::weights_calculators::UniformGameStateWeightsCalculator;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_sampling_chance_outcomes() {
+        let game_rules_authority = SimplifiedBackgammonGameRulesAuthority {};
+        let game_state_serializer = ByteArrayGameStateSerializer {};
+        let game_state_deserializer = ByteArrayGameStateDeserializer {};
+        let weights_calculator = UniformGameStateWeightsCalculator {};
+        let game_runner =
+            StandardTurnBasedGameRunner::new(&game_rules_authority, &game_state_serializer);
+        let first_turn_taker =
+            WeightedRandomSelectionTurnTaker::new(&game_rules_authority, &weights_calculator, 0);
+        let second_turn_taker =
+            WeightedRandomSelectionTurnTaker::new(&game_rules_authority, &weights_calculator, 1);
+        let turn_takers: Vec<&dyn TurnTaker<SimplifiedBackgammonGameState>> =
+            vec![&first_turn_taker, &second_turn_taker];
+
+        // Each player has a single checker left in their home board, 3 points away from bearing off for the first player
+        // and 1 point away for the second player, who bears off with any roll if the first player rolls less than 3
+        let mut points = [0; 12];
+        points[9] = 1;
+        points[0] = -1;
+        let game_state = SimplifiedBackgammonGameState {
+            points: points,
+            bar_counts: [0, 0],
+            borne_off_counts: [4, 4],
+            die_roll: 0,
+        };
+
+        let mut seen_die_rolls: HashSet<u8> = HashSet::new();
+        for _ in 0..300 {
+            let game_report = game_runner
+                .run_game(game_state, &turn_takers, -1, true)
+                .unwrap()
+                .unwrap();
+
+            // the roll of the die comes right after the initial game state, before the first player moves
+            let (_, rolled_game_state) = game_state_deserializer.deserialize_game_state(
+                &game_report.game_state_updates[1].new_serialized_game_state,
+            );
+            let die_roll = rolled_game_state.die_roll;
+            assert!(die_roll >= 1 && die_roll <= 6);
+            assert_eq!(
+                game_report.winning_player_index,
+                if die_roll >= 3 { 0 } else { 1 }
+            );
+            seen_die_rolls.insert(die_roll);
+        }
+
+        assert_eq!(seen_die_rolls.len(), 6);
+    }
+
+    #[test]
+    fn test_recording_actions() {
+        let game_rules_authority = TicTacToeGameRulesAuthority {};
+        let game_state_serializer = FixedWidthGameStateSerializer {};
+        let weights_calculator = UniformGameStateWeightsCalculator {};
+        let game_runner = StandardTurnBasedGameRunner::new_with_action_notations(
+            &game_rules_authority,
+            &game_state_serializer,
+            &game_rules_authority,
+        );
+        let first_turn_taker =
+            WeightedRandomSelectionTurnTaker::new(&game_rules_authority, &weights_calculator, 0);
+        let second_turn_taker =
+            WeightedRandomSelectionTurnTaker::new(&game_rules_authority, &weights_calculator, 1);
+        let turn_takers: Vec<&dyn TurnTaker<TicTacToeGameState>> =
+            vec![&first_turn_taker, &second_turn_taker];
+
+        // the first player wins by filling the only empty space, in the bottom right corner
+        let game_state = vec![vec![1, 2, 1], vec![2, 2, 1], vec![1, 1, 0]];
+        let game_report = game_runner
+            .run_game(game_state, &turn_takers, -1, true)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(game_report.winning_player_index, 0);
+        assert_eq!(
+            game_report.actions,
+            Some(vec![None, Some((String::from("2,2"), 8))])
+        );
+    }
+}
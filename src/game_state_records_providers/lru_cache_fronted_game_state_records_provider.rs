@@ -1,3 +1,4 @@
+use crate::internal::utility_functions;
 use crate::structs::{
     GameReport, GameStateRecord, GameStateUpdate, IncrementPersistedGameStateRecordValuesTask,
 };
@@ -6,11 +7,10 @@ use crate::traits::{
     PendingUpdatesManager,
 };
 use lru::LruCache;
-use std::cell::{RefCell, RefMut};
 use std::cmp;
 use std::collections::HashSet;
+use std::sync::{Mutex, MutexGuard};
 use std::thread;
-use std::time;
 
 const CAPACITY_CLEARANCE_DIVISOR: usize = 5;
 
@@ -18,9 +18,9 @@ pub struct LruCacheFrontedGameStateRecordsProvider<
     'a,
     SerializedGameState: BasicSerializedGameState,
 > {
-    lru_cache_ref_cell: RefCell<LruCache<SerializedGameState, (GameStateRecord, GameStateRecord)>>,
+    lru_cache_mutex: Mutex<LruCache<SerializedGameState, (GameStateRecord, GameStateRecord)>>,
     max_capacity: usize,
-    game_state_records_dal: &'a dyn GameStateRecordsDAL<SerializedGameState>,
+    game_state_records_dal: &'a (dyn GameStateRecordsDAL<SerializedGameState> + Sync),
 }
 
 impl<'a, SerializedGameState: BasicSerializedGameState>
@@ -28,10 +28,10 @@ impl<'a, SerializedGameState: BasicSerializedGameState>
 {
     pub fn new(
         max_capacity: usize,
-        game_state_records_dal: &'a dyn GameStateRecordsDAL<SerializedGameState>,
+        game_state_records_dal: &'a (dyn GameStateRecordsDAL<SerializedGameState> + Sync),
     ) -> LruCacheFrontedGameStateRecordsProvider<'a, SerializedGameState> {
         return LruCacheFrontedGameStateRecordsProvider {
-            lru_cache_ref_cell: RefCell::new(LruCache::unbounded()),
+            lru_cache_mutex: Mutex::new(LruCache::unbounded()),
             max_capacity: max_capacity,
             game_state_records_dal: game_state_records_dal,
         };
@@ -39,14 +39,13 @@ impl<'a, SerializedGameState: BasicSerializedGameState>
 
     fn safe_get_lru_cache_mut(
         &self,
-    ) -> RefMut<LruCache<SerializedGameState, (GameStateRecord, GameStateRecord)>> {
-        loop {
-            match self.lru_cache_ref_cell.try_borrow_mut() {
-                Ok(value) => return value,
-                Err(_) => {
-                    println!("Failed to get lru cache mutable reference!?");
-                    thread::sleep(time::Duration::from_millis(10));
-                }
+    ) -> MutexGuard<'_, LruCache<SerializedGameState, (GameStateRecord, GameStateRecord)>> {
+        match self.lru_cache_mutex.lock() {
+            Ok(value) => return value,
+            Err(poisoned_error) => {
+                // A panic elsewhere cannot leave the cache itself in an invalid state, so keep going
+                println!("Recovering lru cache lock poisoned by a panicked thread.");
+                return poisoned_error.into_inner();
             }
         }
     }
@@ -158,8 +157,6 @@ impl<'a, SerializedGameState: BasicSerializedGameState + Send + Send + Sync>
         &self,
         game_report: &mut GameReport<SerializedGameState>,
     ) -> Result<(), ()> {
-        let did_draw = game_report.winning_player_index == -1;
-
         let mut pending_updates_count_to_commit = 0;
 
         {
@@ -175,8 +172,10 @@ impl<'a, SerializedGameState: BasicSerializedGameState + Send + Send + Sync>
 
                 already_updated_game_state_updates.insert(game_state_update.clone());
 
-                let did_win =
-                    game_report.winning_player_index == game_state_update.responsible_player_index;
+                let player_game_state_record = utility_functions::find_player_game_state_record(
+                    &game_report.player_ranks,
+                    game_state_update.responsible_player_index,
+                );
                 let mut is_in_cache = false;
                 let new_cache_value: (GameStateRecord, GameStateRecord);
                 match lru_cache.get(&game_state_update.new_serialized_game_state) {
@@ -186,11 +185,11 @@ impl<'a, SerializedGameState: BasicSerializedGameState + Send + Send + Sync>
                             original_game_state_record.clone(),
                             GameStateRecord::new(
                                 pending_updates_game_state_record.draws_count
-                                    + if did_draw { 1 } else { 0 },
+                                    + player_game_state_record.draws_count,
                                 pending_updates_game_state_record.losses_count
-                                    + if !did_draw && !did_win { 1 } else { 0 },
+                                    + player_game_state_record.losses_count,
                                 pending_updates_game_state_record.wins_count
-                                    + if did_win { 1 } else { 0 },
+                                    + player_game_state_record.wins_count,
                             ),
                         );
                     }
@@ -199,14 +198,7 @@ impl<'a, SerializedGameState: BasicSerializedGameState + Send + Send + Sync>
                         .get_game_state_record(&game_state_update.new_serialized_game_state)
                     {
                         Some(game_state_record) => {
-                            new_cache_value = (
-                                game_state_record.clone(),
-                                GameStateRecord::new(
-                                    if did_draw { 1 } else { 0 },
-                                    if !did_draw && !did_win { 1 } else { 0 },
-                                    if did_win { 1 } else { 0 },
-                                ),
-                            );
+                            new_cache_value = (game_state_record.clone(), player_game_state_record);
                         }
                         None => {
                             new_cache_value =
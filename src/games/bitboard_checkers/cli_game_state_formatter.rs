@@ -0,0 +1,24 @@
+use crate::games::bitboard_checkers::GameStateType as BitboardCheckersGameState;
+use crate::games::checkers::CLIGameStateFormatter as CheckersCLIGameStateFormatter;
+use crate::traits::CLIGameStateFormatter as TCLIGameStateFormatter;
+
+/// Shows the board the same way checkers does.
+pub struct CLIGameStateFormatter {
+    checkers_cli_game_state_formatter: CheckersCLIGameStateFormatter,
+}
+
+impl CLIGameStateFormatter {
+    pub fn new() -> CLIGameStateFormatter {
+        return CLIGameStateFormatter {
+            checkers_cli_game_state_formatter: CheckersCLIGameStateFormatter {},
+        };
+    }
+}
+
+impl TCLIGameStateFormatter<BitboardCheckersGameState> for CLIGameStateFormatter {
+    fn format_game_state_for_cli(&self, game_state: &BitboardCheckersGameState) -> String {
+        return self
+            .checkers_cli_game_state_formatter
+            .format_game_state_for_cli(&game_state.to_checkers_game_state());
+    }
+}
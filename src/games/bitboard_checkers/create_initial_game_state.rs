@@ -0,0 +1,12 @@
+use crate::games::bitboard_checkers::GameStateType as BitboardCheckersGameState;
+
+pub fn create_initial_game_state() -> BitboardCheckersGameState {
+    return BitboardCheckersGameState {
+        // the first three rows
+        first_player_single_pieces: 0x0000_0FFF,
+        first_player_double_pieces: 0,
+        // the last three rows
+        second_player_single_pieces: 0xFFF0_0000,
+        second_player_double_pieces: 0,
+    };
+}
@@ -0,0 +1,34 @@
+use crate::games::bitboard_checkers::internal::*;
+use crate::games::bitboard_checkers::GameStateType as BitboardCheckersGameState;
+
+/// Size of the fixed move encoding, the same as the one of checkers.
+pub const MOVE_POLICY_SIZE: usize = PLAYABLE_SPACES_COUNT * PLAYABLE_SPACES_COUNT;
+
+/// Finds the index within the fixed move encoding of the move that leads from the current game state to the next one.
+/// A move is identified by the playable space it starts from and the playable space it ends on,
+/// so it can't be told apart when a multi-jump ends on the space it started from.
+pub fn find_move_policy_index(
+    current_player_index: i32,
+    current_game_state: &BitboardCheckersGameState,
+    next_game_state: &BitboardCheckersGameState,
+) -> Option<usize> {
+    let get_own_pieces = |game_state: &BitboardCheckersGameState| -> u32 {
+        if current_player_index == 0 {
+            return game_state.first_player_single_pieces | game_state.first_player_double_pieces;
+        }
+        return game_state.second_player_single_pieces | game_state.second_player_double_pieces;
+    };
+    let current_own_pieces = get_own_pieces(current_game_state);
+    let next_own_pieces = get_own_pieces(next_game_state);
+
+    let origin_space_bit = current_own_pieces & !next_own_pieces;
+    let destination_space_bit = next_own_pieces & !current_own_pieces;
+    if origin_space_bit == 0 || destination_space_bit == 0 {
+        return None;
+    }
+
+    return Some(
+        origin_space_bit.trailing_zeros() as usize * PLAYABLE_SPACES_COUNT
+            + destination_space_bit.trailing_zeros() as usize,
+    );
+}
@@ -0,0 +1,143 @@
+use crate::games::bitboard_checkers::GameStateType as BitboardCheckersGameState;
+use crate::structs::FixedWidthSerializedGameState;
+use crate::traits::{GameStateDeserializer, GameStateSerializer};
+
+const FIRST_PLAYER_PIECES_OFFSET: u32 = 0;
+const SECOND_PLAYER_PIECES_OFFSET: u32 = 32;
+const DOUBLE_PIECES_OFFSET: u32 = 64;
+const RESPONSIBLE_PLAYER_OFFSET: u32 = 96;
+
+const NO_RESPONSIBLE_PLAYER_BITS: u128 = 0b11;
+
+/// Serializes game states to the very same value the checkers serializer does, so records of both can be told apart only by game name.
+/// The serialized game state is made of the bitboards of the pieces of the first player, the pieces of the second player
+/// and the double pieces, followed by two bits for the player who last moved (11 for no player responsible).
+pub struct FixedWidthGameStateSerializer {}
+
+impl GameStateSerializer<BitboardCheckersGameState, FixedWidthSerializedGameState>
+    for FixedWidthGameStateSerializer
+{
+    fn serialize_game_state(
+        &self,
+        responsible_player_index: i32,
+        game_state: &BitboardCheckersGameState,
+    ) -> FixedWidthSerializedGameState {
+        let first_player_pieces =
+            game_state.first_player_single_pieces | game_state.first_player_double_pieces;
+        let second_player_pieces =
+            game_state.second_player_single_pieces | game_state.second_player_double_pieces;
+        let double_pieces =
+            game_state.first_player_double_pieces | game_state.second_player_double_pieces;
+        let responsible_player_bits = if responsible_player_index == -1 {
+            NO_RESPONSIBLE_PLAYER_BITS
+        } else {
+            responsible_player_index as u128
+        };
+
+        return FixedWidthSerializedGameState::new(
+            ((first_player_pieces as u128) << FIRST_PLAYER_PIECES_OFFSET)
+                | ((second_player_pieces as u128) << SECOND_PLAYER_PIECES_OFFSET)
+                | ((double_pieces as u128) << DOUBLE_PIECES_OFFSET)
+                | (responsible_player_bits << RESPONSIBLE_PLAYER_OFFSET),
+        );
+    }
+}
+
+pub struct FixedWidthGameStateDeserializer {}
+
+impl GameStateDeserializer<BitboardCheckersGameState, FixedWidthSerializedGameState>
+    for FixedWidthGameStateDeserializer
+{
+    fn deserialize_game_state(
+        &self,
+        serialized_game_state: &FixedWidthSerializedGameState,
+    ) -> (i32, BitboardCheckersGameState) {
+        let bits = serialized_game_state.bits;
+        let first_player_pieces = (bits >> FIRST_PLAYER_PIECES_OFFSET) as u32;
+        let second_player_pieces = (bits >> SECOND_PLAYER_PIECES_OFFSET) as u32;
+        let double_pieces = (bits >> DOUBLE_PIECES_OFFSET) as u32;
+
+        let responsible_player_bits = (bits >> RESPONSIBLE_PLAYER_OFFSET) & 0b11;
+        let responsible_player_index = if responsible_player_bits == NO_RESPONSIBLE_PLAYER_BITS {
+            -1
+        } else {
+            responsible_player_bits as i32
+        };
+
+        return (
+            responsible_player_index,
+            BitboardCheckersGameState {
+                first_player_single_pieces: first_player_pieces & !double_pieces,
+                first_player_double_pieces: first_player_pieces & double_pieces,
+                second_player_single_pieces: second_player_pieces & !double_pieces,
+                second_player_double_pieces: second_player_pieces & double_pieces,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::bitboard_checkers::create_initial_game_state;
+    use crate::games::checkers::FixedWidthGameStateSerializer as CheckersFixedWidthGameStateSerializer;
+
+    fn create_test_game_states() -> Vec<(i32, BitboardCheckersGameState)> {
+        return vec![
+            (-1, create_initial_game_state()),
+            (
+                0,
+                BitboardCheckersGameState {
+                    first_player_single_pieces: 0x0000_0FFE,
+                    first_player_double_pieces: 0x4000_0000,
+                    second_player_single_pieces: 0xBFF0_0000,
+                    second_player_double_pieces: 0x0000_0001,
+                },
+            ),
+            (
+                1,
+                BitboardCheckersGameState {
+                    first_player_single_pieces: 0x0001_2000,
+                    first_player_double_pieces: 0x8000_0000,
+                    second_player_single_pieces: 0,
+                    second_player_double_pieces: 0x0000_0010,
+                },
+            ),
+        ];
+    }
+
+    #[test]
+    fn test_for_invertibility() {
+        let serializer = FixedWidthGameStateSerializer {};
+        let deserializer = FixedWidthGameStateDeserializer {};
+
+        for (responsible_player_index, game_state) in create_test_game_states().iter() {
+            let (deserialized_responsible_player_index, deserialized_game_state) = deserializer
+                .deserialize_game_state(
+                    &serializer.serialize_game_state(*responsible_player_index, game_state),
+                );
+
+            assert_eq!(
+                *responsible_player_index,
+                deserialized_responsible_player_index
+            );
+            assert_eq!(*game_state, deserialized_game_state);
+        }
+    }
+
+    #[test]
+    fn test_serializing_like_checkers() {
+        let serializer = FixedWidthGameStateSerializer {};
+        let checkers_serializer = CheckersFixedWidthGameStateSerializer {};
+
+        for (responsible_player_index, game_state) in create_test_game_states().iter() {
+            assert_eq!(
+                serializer.serialize_game_state(*responsible_player_index, game_state),
+                checkers_serializer.serialize_game_state(
+                    *responsible_player_index,
+                    &game_state.to_checkers_game_state()
+                )
+            );
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::games::bitboard_checkers::{
+    create_initial_game_state, CLIGameStateFormatter, FixedWidthGameStateDeserializer,
+    FixedWidthGameStateSerializer, GameRulesAuthority, GameStateType as BitboardCheckersGameState,
+    UserInputGameStateCreator,
+};
+#[cfg(feature = "torch")]
+use crate::games::bitboard_checkers::{
+    find_move_policy_index, transform_game_state_to_tensor, MOVE_POLICY_SIZE,
+};
+#[cfg(feature = "torch")]
+use crate::games::checkers::{PolicyValueTorchNet, TorchNet};
+use crate::structs::FixedWidthSerializedGameState;
+#[cfg(feature = "torch")]
+use crate::structs::NeuralNetDefinition;
+#[cfg(feature = "torch")]
+use crate::traits::PolicyValueNet;
+use crate::traits::{
+    CLIGameStateFormatter as TCLIGameStateFormatter, GameDefinition as TGameDefinition,
+    GameRulesAuthority as TGameRulesAuthority, GameStateDeserializer, GameStateSerializer,
+    UserInputGameStateCreator as TUserInputGameStateCreator,
+};
+#[cfg(feature = "torch")]
+use tch::nn;
+
+/// American checkers, played on bitboards.
+/// Its game states serialize and transform to tensors the same way the ones of checkers do.
+pub struct GameDefinition {
+    cli_game_state_formatter: CLIGameStateFormatter,
+    game_rules_authority: GameRulesAuthority,
+    game_state_deserializer: FixedWidthGameStateDeserializer,
+    game_state_serializer: FixedWidthGameStateSerializer,
+    #[cfg(feature = "torch")]
+    neural_net_definition: NeuralNetDefinition<BitboardCheckersGameState>,
+    user_input_game_state_creator: UserInputGameStateCreator,
+}
+
+impl GameDefinition {
+    pub fn new() -> GameDefinition {
+        return GameDefinition {
+            cli_game_state_formatter: CLIGameStateFormatter::new(),
+            game_rules_authority: GameRulesAuthority {},
+            game_state_deserializer: FixedWidthGameStateDeserializer {},
+            game_state_serializer: FixedWidthGameStateSerializer {},
+            #[cfg(feature = "torch")]
+            neural_net_definition: NeuralNetDefinition {
+                create_policy_value_net: create_policy_value_net,
+                create_value_net: create_value_net,
+                find_move_policy_index: find_move_policy_index,
+                move_policy_size: MOVE_POLICY_SIZE,
+                transform_game_state_to_tensor: transform_game_state_to_tensor,
+            },
+            user_input_game_state_creator: UserInputGameStateCreator::new(),
+        };
+    }
+}
+
+impl TGameDefinition<BitboardCheckersGameState, FixedWidthSerializedGameState> for GameDefinition {
+    fn get_name(&self) -> &str {
+        return "bitboard-checkers";
+    }
+
+    fn create_initial_game_state(&self) -> BitboardCheckersGameState {
+        return create_initial_game_state();
+    }
+
+    fn get_cli_game_state_formatter(
+        &self,
+    ) -> &dyn TCLIGameStateFormatter<BitboardCheckersGameState> {
+        return &self.cli_game_state_formatter;
+    }
+
+    fn get_game_rules_authority(
+        &self,
+    ) -> &(dyn TGameRulesAuthority<BitboardCheckersGameState> + Sync) {
+        return &self.game_rules_authority;
+    }
+
+    fn get_game_state_deserializer(
+        &self,
+    ) -> &(dyn GameStateDeserializer<BitboardCheckersGameState, FixedWidthSerializedGameState> + Sync)
+    {
+        return &self.game_state_deserializer;
+    }
+
+    fn get_game_state_serializer(
+        &self,
+    ) -> &(dyn GameStateSerializer<BitboardCheckersGameState, FixedWidthSerializedGameState> + Sync)
+    {
+        return &self.game_state_serializer;
+    }
+
+    fn get_user_input_game_state_creator(
+        &self,
+    ) -> &dyn TUserInputGameStateCreator<BitboardCheckersGameState, String> {
+        return &self.user_input_game_state_creator;
+    }
+
+    #[cfg(feature = "torch")]
+    fn get_neural_net_definition(&self) -> Option<&NeuralNetDefinition<BitboardCheckersGameState>> {
+        return Some(&self.neural_net_definition);
+    }
+}
+
+#[cfg(feature = "torch")]
+fn create_policy_value_net(vs: &nn::Path) -> Box<dyn PolicyValueNet + Sync> {
+    return Box::new(PolicyValueTorchNet::new(vs));
+}
+
+#[cfg(feature = "torch")]
+fn create_value_net(vs: &nn::Path) -> Box<dyn nn::Module + Sync> {
+    return Box::new(TorchNet::new(vs));
+}
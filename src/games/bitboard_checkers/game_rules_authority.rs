@@ -0,0 +1,129 @@
+use crate::games::bitboard_checkers::internal::*;
+use crate::games::bitboard_checkers::GameStateType as BitboardCheckersGameState;
+use crate::internal::utility_functions;
+use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+/// Plays by the American checkers rules, the same way the checkers game rules authority does.
+/// Besides losing by running out of moves, games are drawn by threefold repetition
+/// or once both players made 40 moves in a row without a capture or a move of a single piece.
+pub struct GameRulesAuthority {}
+
+impl TGameRulesAuthority<BitboardCheckersGameState> for GameRulesAuthority {
+    fn analyze_game_state_for_terminality(
+        &self,
+        game_state: &BitboardCheckersGameState,
+        next_player_index: i32,
+    ) -> Option<i32> {
+        if has_any_available_move(next_player_index, game_state) {
+            return None;
+        }
+
+        // the next player can't move for their coming turn, so the other player has won
+        let other_player_index = (next_player_index + 1) % 2;
+        return Some(other_player_index);
+    }
+
+    fn analyze_game_history_for_terminality(
+        &self,
+        game_states_history: &Vec<BitboardCheckersGameState>,
+        next_player_index: i32,
+    ) -> Option<i32> {
+        let current_game_state = &game_states_history[game_states_history.len() - 1];
+        if let Some(winning_player_index) =
+            self.analyze_game_state_for_terminality(current_game_state, next_player_index)
+        {
+            return Some(winning_player_index);
+        }
+
+        if utility_functions::is_game_history_drawn(
+            game_states_history,
+            is_progress_move,
+            NO_PROGRESS_MOVES_LIMIT,
+            REPETITIONS_FOR_DRAW,
+        ) {
+            return Some(-1);
+        }
+
+        return None;
+    }
+
+    fn find_available_next_game_states(
+        &self,
+        current_player_index: i32,
+        current_game_state: &BitboardCheckersGameState,
+    ) -> Vec<BitboardCheckersGameState> {
+        return find_available_next_game_states(current_player_index, current_game_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::perft;
+    use crate::games::bitboard_checkers::create_initial_game_state;
+    use crate::games::checkers::{CheckersRules, GameRulesAuthority as CheckersGameRulesAuthority};
+
+    #[test]
+    fn test_perft_from_initial_game_state() {
+        let game_rules_authority = GameRulesAuthority {};
+        let initial_game_state = create_initial_game_state();
+
+        // Published perft counts of American checkers
+        let expected_leaf_nodes_counts: Vec<u64> = vec![7, 49, 302, 1469, 7361, 36768, 179740];
+
+        for (i, expected_leaf_nodes_count) in expected_leaf_nodes_counts.iter().enumerate() {
+            assert_eq!(
+                perft(
+                    &game_rules_authority,
+                    2,
+                    0,
+                    &initial_game_state,
+                    i as u32 + 1
+                ),
+                *expected_leaf_nodes_count
+            );
+        }
+    }
+
+    #[test]
+    fn test_same_next_game_states_as_checkers() {
+        let game_rules_authority = GameRulesAuthority {};
+        let checkers_game_rules_authority =
+            CheckersGameRulesAuthority::new(CheckersRules::american());
+
+        // Follows a deterministic line of play long enough to see kings, multi-jumps and the end of the game
+        let mut current_player_index = 0;
+        let mut game_state = create_initial_game_state();
+        for ply in 0..200 {
+            let mut next_game_states: Vec<BitboardCheckersGameState> = game_rules_authority
+                .find_available_next_game_states(current_player_index, &game_state);
+            let mut expected_next_game_states: Vec<BitboardCheckersGameState> =
+                checkers_game_rules_authority
+                    .find_available_next_game_states(
+                        current_player_index,
+                        &game_state.to_checkers_game_state(),
+                    )
+                    .iter()
+                    .map(BitboardCheckersGameState::from_checkers_game_state)
+                    .collect();
+
+            let sort_key = |game_state: &BitboardCheckersGameState| {
+                return (
+                    game_state.first_player_single_pieces,
+                    game_state.first_player_double_pieces,
+                    game_state.second_player_single_pieces,
+                    game_state.second_player_double_pieces,
+                );
+            };
+            next_game_states.sort_by_key(sort_key);
+            expected_next_game_states.sort_by_key(sort_key);
+            assert_eq!(next_game_states, expected_next_game_states);
+
+            if next_game_states.is_empty() {
+                break;
+            }
+            game_state = next_game_states[(ply * 7) % next_game_states.len()];
+            current_player_index = (current_player_index + 1) % 2;
+        }
+    }
+}
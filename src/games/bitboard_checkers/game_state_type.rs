@@ -0,0 +1,84 @@
+use crate::games::bitboard_checkers::internal::*;
+use crate::games::checkers::GameStateType as CheckersGameState;
+use crate::traits::BasicGameState;
+
+/// A checkers board as one bitboard for each type of piece.
+/// Bit i of a bitboard stands for the playable space i, the playable spaces being numbered from 0 to 31 row by row.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GameStateType {
+    pub first_player_single_pieces: u32,
+    pub first_player_double_pieces: u32,
+    pub second_player_single_pieces: u32,
+    pub second_player_double_pieces: u32,
+}
+
+impl BasicGameState for GameStateType {}
+
+impl GameStateType {
+    pub fn from_checkers_game_state(checkers_game_state: &CheckersGameState) -> GameStateType {
+        let mut game_state = GameStateType {
+            first_player_single_pieces: 0,
+            first_player_double_pieces: 0,
+            second_player_single_pieces: 0,
+            second_player_double_pieces: 0,
+        };
+
+        for playable_space_index in 0..PLAYABLE_SPACES_COUNT {
+            let (row, col) = get_playable_space_coor(playable_space_index);
+            let space_bit = 1 << playable_space_index;
+            match checkers_game_state[row][col] {
+                FIRST_PLAYER_SINGLE_PIECE_VALUE => {
+                    game_state.first_player_single_pieces |= space_bit
+                }
+                FIRST_PLAYER_DOUBLE_PIECE_VALUE => {
+                    game_state.first_player_double_pieces |= space_bit
+                }
+                SECOND_PLAYER_SINGLE_PIECE_VALUE => {
+                    game_state.second_player_single_pieces |= space_bit
+                }
+                SECOND_PLAYER_DOUBLE_PIECE_VALUE => {
+                    game_state.second_player_double_pieces |= space_bit
+                }
+                _ => (),
+            }
+        }
+
+        return game_state;
+    }
+
+    pub fn to_checkers_game_state(&self) -> CheckersGameState {
+        let mut checkers_game_state = [[EMPTY_SPACE_VALUE; 8]; 8];
+
+        for playable_space_index in 0..PLAYABLE_SPACES_COUNT {
+            let (row, col) = get_playable_space_coor(playable_space_index);
+            checkers_game_state[row][col] = self.get_space_value(playable_space_index);
+        }
+
+        return checkers_game_state;
+    }
+
+    /// The value the playable space has in the checkers game state representation.
+    pub fn get_space_value(&self, playable_space_index: usize) -> u8 {
+        let space_bit = 1 << playable_space_index;
+        if self.first_player_single_pieces & space_bit != 0 {
+            return FIRST_PLAYER_SINGLE_PIECE_VALUE;
+        }
+        if self.first_player_double_pieces & space_bit != 0 {
+            return FIRST_PLAYER_DOUBLE_PIECE_VALUE;
+        }
+        if self.second_player_single_pieces & space_bit != 0 {
+            return SECOND_PLAYER_SINGLE_PIECE_VALUE;
+        }
+        if self.second_player_double_pieces & space_bit != 0 {
+            return SECOND_PLAYER_DOUBLE_PIECE_VALUE;
+        }
+        return EMPTY_SPACE_VALUE;
+    }
+
+    pub fn get_occupied_spaces(&self) -> u32 {
+        return self.first_player_single_pieces
+            | self.first_player_double_pieces
+            | self.second_player_single_pieces
+            | self.second_player_double_pieces;
+    }
+}
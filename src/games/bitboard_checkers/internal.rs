@@ -0,0 +1,352 @@
+use crate::games::bitboard_checkers::GameStateType as BitboardCheckersGameState;
+
+pub const PLAYABLE_SPACES_COUNT: usize = 32;
+
+// The space values of the checkers game state representation
+pub const EMPTY_SPACE_VALUE: u8 = 0;
+pub const FIRST_PLAYER_SINGLE_PIECE_VALUE: u8 = 1;
+pub const FIRST_PLAYER_DOUBLE_PIECE_VALUE: u8 = 11;
+pub const SECOND_PLAYER_SINGLE_PIECE_VALUE: u8 = 2;
+pub const SECOND_PLAYER_DOUBLE_PIECE_VALUE: u8 = 22;
+
+/// The number of moves in a row, counting the moves of both players, without a capture or a move of a single piece
+/// after which the game is drawn - 40 moves for each player.
+pub const NO_PROGRESS_MOVES_LIMIT: usize = 80;
+/// The number of times the same position has to come up with the same player to move for the game to be drawn.
+pub const REPETITIONS_FOR_DRAW: usize = 3;
+
+// Each row has 4 playable spaces. Those of even rows are in the odd columns, those of odd rows are in the even columns.
+const EVEN_ROWS_MASK: u32 = 0x0F0F_0F0F;
+const ODD_ROWS_MASK: u32 = 0xF0F0_F0F0;
+/// The playable spaces in column 7, which are all in even rows.
+const RIGHT_EDGE_MASK: u32 = 0x0808_0808;
+/// The playable spaces in column 0, which are all in odd rows.
+const LEFT_EDGE_MASK: u32 = 0x1010_1010;
+const FIRST_ROW_MASK: u32 = 0x0000_000F;
+const LAST_ROW_MASK: u32 = 0xF000_0000;
+
+/// Down moves towards the last row, which is the way the first player's single pieces go.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Direction {
+    DownLeft,
+    DownRight,
+    UpLeft,
+    UpRight,
+}
+
+const DOWN_DIRECTIONS: [Direction; 2] = [Direction::DownLeft, Direction::DownRight];
+const UP_DIRECTIONS: [Direction; 2] = [Direction::UpLeft, Direction::UpRight];
+const ALL_DIRECTIONS: [Direction; 4] = [
+    Direction::DownLeft,
+    Direction::DownRight,
+    Direction::UpLeft,
+    Direction::UpRight,
+];
+
+/// Moves every set bit to the next playable space in the given direction, dropping the bits that would leave the board.
+/// Going down a row adds 4 to the index of a space, plus or minus one depending on the parity of the row and the direction.
+pub fn shift_in_direction(bits: u32, direction: Direction) -> u32 {
+    return match direction {
+        Direction::DownLeft => {
+            ((bits & EVEN_ROWS_MASK) << 4) | ((bits & ODD_ROWS_MASK & !LEFT_EDGE_MASK) << 3)
+        }
+        Direction::DownRight => {
+            ((bits & EVEN_ROWS_MASK & !RIGHT_EDGE_MASK) << 5) | ((bits & ODD_ROWS_MASK) << 4)
+        }
+        Direction::UpLeft => {
+            ((bits & EVEN_ROWS_MASK) >> 4) | ((bits & ODD_ROWS_MASK & !LEFT_EDGE_MASK) >> 5)
+        }
+        Direction::UpRight => {
+            ((bits & EVEN_ROWS_MASK & !RIGHT_EDGE_MASK) >> 3) | ((bits & ODD_ROWS_MASK) >> 4)
+        }
+    };
+}
+
+/// The bitboards of a game state as seen by the given player.
+pub struct PlayerBitboards {
+    pub own_single_pieces: u32,
+    pub own_double_pieces: u32,
+    pub opponent_pieces: u32,
+    pub empty_spaces: u32,
+    pub forward_directions: [Direction; 2],
+    pub double_row_mask: u32,
+}
+
+pub fn get_player_bitboards(
+    current_player_index: i32,
+    game_state: &BitboardCheckersGameState,
+) -> PlayerBitboards {
+    let empty_spaces = !game_state.get_occupied_spaces();
+
+    if current_player_index == 0 {
+        return PlayerBitboards {
+            own_single_pieces: game_state.first_player_single_pieces,
+            own_double_pieces: game_state.first_player_double_pieces,
+            opponent_pieces: game_state.second_player_single_pieces
+                | game_state.second_player_double_pieces,
+            empty_spaces: empty_spaces,
+            forward_directions: DOWN_DIRECTIONS,
+            double_row_mask: LAST_ROW_MASK,
+        };
+    }
+
+    return PlayerBitboards {
+        own_single_pieces: game_state.second_player_single_pieces,
+        own_double_pieces: game_state.second_player_double_pieces,
+        opponent_pieces: game_state.first_player_single_pieces
+            | game_state.first_player_double_pieces,
+        empty_spaces: empty_spaces,
+        forward_directions: UP_DIRECTIONS,
+        double_row_mask: FIRST_ROW_MASK,
+    };
+}
+
+/// Tells if the given player has any move at all, looking at all of their pieces at once.
+pub fn has_any_available_move(
+    current_player_index: i32,
+    game_state: &BitboardCheckersGameState,
+) -> bool {
+    let player_bitboards = get_player_bitboards(current_player_index, game_state);
+
+    for direction in ALL_DIRECTIONS.iter() {
+        let moving_pieces = if player_bitboards.forward_directions.contains(direction) {
+            player_bitboards.own_single_pieces | player_bitboards.own_double_pieces
+        } else {
+            player_bitboards.own_double_pieces
+        };
+
+        let next_spaces = shift_in_direction(moving_pieces, *direction);
+        if next_spaces & player_bitboards.empty_spaces != 0 {
+            return true;
+        }
+
+        let jumped_spaces =
+            shift_in_direction(next_spaces & player_bitboards.opponent_pieces, *direction);
+        if jumped_spaces & player_bitboards.empty_spaces != 0 {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+/// Finds the game states the given player's moves lead to.
+/// Captures are mandatory, so simple moves are only found when no capture is available.
+pub fn find_available_next_game_states(
+    current_player_index: i32,
+    game_state: &BitboardCheckersGameState,
+) -> Vec<BitboardCheckersGameState> {
+    let player_bitboards = get_player_bitboards(current_player_index, game_state);
+    let mut available_next_game_states: Vec<BitboardCheckersGameState> = vec![];
+
+    for (pieces, is_double_piece) in [
+        (player_bitboards.own_single_pieces, false),
+        (player_bitboards.own_double_pieces, true),
+    ]
+    .iter()
+    {
+        for piece_bit in iterate_bits(*pieces) {
+            fill_vector_with_capture_game_states_continuing_jump(
+                current_player_index,
+                game_state,
+                &player_bitboards,
+                piece_bit,
+                piece_bit,
+                *is_double_piece,
+                0,
+                &mut available_next_game_states,
+            );
+        }
+    }
+    if !available_next_game_states.is_empty() {
+        return available_next_game_states;
+    }
+
+    for (pieces, is_double_piece) in [
+        (player_bitboards.own_single_pieces, false),
+        (player_bitboards.own_double_pieces, true),
+    ]
+    .iter()
+    {
+        let directions: &[Direction] = if *is_double_piece {
+            &ALL_DIRECTIONS
+        } else {
+            &player_bitboards.forward_directions
+        };
+
+        for piece_bit in iterate_bits(*pieces) {
+            for direction in directions.iter() {
+                let move_to_bit =
+                    shift_in_direction(piece_bit, *direction) & player_bitboards.empty_spaces;
+                if move_to_bit != 0 {
+                    available_next_game_states.push(perform_move(
+                        current_player_index,
+                        game_state,
+                        &player_bitboards,
+                        piece_bit,
+                        move_to_bit,
+                        *is_double_piece,
+                        0,
+                    ));
+                }
+            }
+        }
+    }
+
+    return available_next_game_states;
+}
+
+/// Finds the game states the capture of a piece leads to once the piece has nothing left to capture.
+/// Captured pieces are only taken off the board once the move is over, so they can't be jumped over a second time.
+/// A single piece reaching the far row is doubled, which ends its move.
+fn fill_vector_with_capture_game_states_continuing_jump(
+    current_player_index: i32,
+    game_state: &BitboardCheckersGameState,
+    player_bitboards: &PlayerBitboards,
+    start_bit: u32,
+    piece_bit: u32,
+    is_double_piece: bool,
+    captured_pieces: u32,
+    available_next_game_states: &mut Vec<BitboardCheckersGameState>,
+) {
+    let directions: &[Direction] = if is_double_piece {
+        &ALL_DIRECTIONS
+    } else {
+        &player_bitboards.forward_directions
+    };
+    // the piece leaves its space as soon as it moves, so that space is free to land on
+    let landing_spaces = player_bitboards.empty_spaces | start_bit;
+
+    let mut has_jumped = false;
+    for direction in directions.iter() {
+        let captured_piece_bit = shift_in_direction(piece_bit, *direction)
+            & player_bitboards.opponent_pieces
+            & !captured_pieces;
+        let landing_bit = shift_in_direction(captured_piece_bit, *direction) & landing_spaces;
+        if landing_bit == 0 {
+            continue;
+        }
+
+        // a capture is possible!
+        has_jumped = true;
+        if !is_double_piece && landing_bit & player_bitboards.double_row_mask != 0 {
+            push_if_new(
+                perform_move(
+                    current_player_index,
+                    game_state,
+                    player_bitboards,
+                    start_bit,
+                    landing_bit,
+                    true,
+                    captured_pieces | captured_piece_bit,
+                ),
+                available_next_game_states,
+            );
+            continue;
+        }
+
+        fill_vector_with_capture_game_states_continuing_jump(
+            current_player_index,
+            game_state,
+            player_bitboards,
+            start_bit,
+            landing_bit,
+            is_double_piece,
+            captured_pieces | captured_piece_bit,
+            available_next_game_states,
+        );
+    }
+
+    if !has_jumped && captured_pieces != 0 {
+        push_if_new(
+            perform_move(
+                current_player_index,
+                game_state,
+                player_bitboards,
+                start_bit,
+                piece_bit,
+                is_double_piece,
+                captured_pieces,
+            ),
+            available_next_game_states,
+        );
+    }
+}
+
+/// Different capture paths can end up in the same game state, e.g. a double piece going around the same pieces either way.
+fn push_if_new(
+    game_state: BitboardCheckersGameState,
+    available_next_game_states: &mut Vec<BitboardCheckersGameState>,
+) {
+    if !available_next_game_states.contains(&game_state) {
+        available_next_game_states.push(game_state);
+    }
+}
+
+/// Moves the piece from one space to another, taking the captured pieces off the board.
+/// The piece ends up doubled if it was doubled already or if it reaches the far row.
+fn perform_move(
+    current_player_index: i32,
+    game_state: &BitboardCheckersGameState,
+    player_bitboards: &PlayerBitboards,
+    start_bit: u32,
+    end_bit: u32,
+    is_double_piece: bool,
+    captured_pieces: u32,
+) -> BitboardCheckersGameState {
+    let is_ending_double = is_double_piece || end_bit & player_bitboards.double_row_mask != 0;
+    let own_single_pieces = (player_bitboards.own_single_pieces & !start_bit)
+        | if is_ending_double { 0 } else { end_bit };
+    let own_double_pieces = (player_bitboards.own_double_pieces & !start_bit)
+        | if is_ending_double { end_bit } else { 0 };
+
+    if current_player_index == 0 {
+        return BitboardCheckersGameState {
+            first_player_single_pieces: own_single_pieces,
+            first_player_double_pieces: own_double_pieces,
+            second_player_single_pieces: game_state.second_player_single_pieces & !captured_pieces,
+            second_player_double_pieces: game_state.second_player_double_pieces & !captured_pieces,
+        };
+    }
+
+    return BitboardCheckersGameState {
+        first_player_single_pieces: game_state.first_player_single_pieces & !captured_pieces,
+        first_player_double_pieces: game_state.first_player_double_pieces & !captured_pieces,
+        second_player_single_pieces: own_single_pieces,
+        second_player_double_pieces: own_double_pieces,
+    };
+}
+
+/// A move makes progress when it captures a piece or moves a single piece, as neither can ever be undone.
+pub fn is_progress_move(
+    previous_game_state: &BitboardCheckersGameState,
+    next_game_state: &BitboardCheckersGameState,
+) -> bool {
+    return previous_game_state.first_player_single_pieces
+        != next_game_state.first_player_single_pieces
+        || previous_game_state.second_player_single_pieces
+            != next_game_state.second_player_single_pieces
+        || previous_game_state.get_occupied_spaces().count_ones()
+            != next_game_state.get_occupied_spaces().count_ones();
+}
+
+/// Iterates over the set bits of the given bits, lowest first, each as a bitboard of its own.
+pub fn iterate_bits(mut bits: u32) -> impl Iterator<Item = u32> {
+    return std::iter::from_fn(move || {
+        if bits == 0 {
+            return None;
+        }
+
+        let lowest_bit = bits & bits.wrapping_neg();
+        bits &= bits - 1;
+        return Some(lowest_bit);
+    });
+}
+
+/// The (row, col) coordinate of a playable space.
+pub fn get_playable_space_coor(playable_space_index: usize) -> (usize, usize) {
+    let row = playable_space_index / 4;
+    // the playable spaces of even rows are the odd columns and vice versa
+    let col = (playable_space_index % 4) * 2 + (1 - row % 2);
+    return (row, col);
+}
@@ -0,0 +1,34 @@
+mod cli_game_state_formatter;
+pub use cli_game_state_formatter::CLIGameStateFormatter;
+
+mod create_initial_game_state;
+pub use create_initial_game_state::create_initial_game_state;
+
+#[cfg(feature = "torch")]
+mod find_move_policy_index;
+#[cfg(feature = "torch")]
+pub use find_move_policy_index::{find_move_policy_index, MOVE_POLICY_SIZE};
+
+mod fixed_width_game_state_serialization;
+pub use fixed_width_game_state_serialization::{
+    FixedWidthGameStateDeserializer, FixedWidthGameStateSerializer,
+};
+
+mod game_definition;
+pub use game_definition::GameDefinition;
+
+mod game_state_type;
+pub use game_state_type::GameStateType;
+
+mod game_rules_authority;
+pub use game_rules_authority::GameRulesAuthority;
+
+mod internal;
+
+#[cfg(feature = "torch")]
+mod transform_game_state_to_tensor;
+#[cfg(feature = "torch")]
+pub use transform_game_state_to_tensor::transform_game_state_to_tensor;
+
+mod user_input_game_state_creator;
+pub use user_input_game_state_creator::UserInputGameStateCreator;
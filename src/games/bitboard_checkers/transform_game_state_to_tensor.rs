@@ -0,0 +1,14 @@
+use crate::games::bitboard_checkers::GameStateType as BitboardCheckersGameState;
+use crate::games::checkers::transform_game_state_to_tensor as transform_checkers_game_state_to_tensor;
+use tch::Tensor;
+
+/// Gives the same tensor as the checkers game state, so nets can be trained on either.
+pub fn transform_game_state_to_tensor(
+    responsible_player_index: i32,
+    game_state: &BitboardCheckersGameState,
+) -> Tensor {
+    return transform_checkers_game_state_to_tensor(
+        responsible_player_index,
+        &game_state.to_checkers_game_state(),
+    );
+}
@@ -0,0 +1,40 @@
+use crate::games::bitboard_checkers::GameStateType as BitboardCheckersGameState;
+use crate::games::checkers::{
+    CheckersRules, UserInputGameStateCreator as CheckersUserInputGameStateCreator,
+};
+use crate::traits::UserInputGameStateCreator as TUserInputGameStateCreator;
+
+/// Reads moves the same way checkers does, by the American checkers rules.
+pub struct UserInputGameStateCreator {
+    checkers_user_input_game_state_creator: CheckersUserInputGameStateCreator<8>,
+}
+
+impl UserInputGameStateCreator {
+    pub fn new() -> UserInputGameStateCreator {
+        return UserInputGameStateCreator {
+            checkers_user_input_game_state_creator: CheckersUserInputGameStateCreator::new(
+                CheckersRules::american(),
+            ),
+        };
+    }
+}
+
+impl TUserInputGameStateCreator<BitboardCheckersGameState, String> for UserInputGameStateCreator {
+    fn create_new_game_state_from_user_input(
+        &self,
+        current_player_index: i32,
+        current_game_state: &BitboardCheckersGameState,
+        user_input: String,
+    ) -> Result<BitboardCheckersGameState, String> {
+        let new_checkers_game_state = self
+            .checkers_user_input_game_state_creator
+            .create_new_game_state_from_user_input(
+                current_player_index,
+                &current_game_state.to_checkers_game_state(),
+                user_input,
+            )?;
+        return Ok(BitboardCheckersGameState::from_checkers_game_state(
+            &new_checkers_game_state,
+        ));
+    }
+}
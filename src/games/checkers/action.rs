@@ -0,0 +1,6 @@
+/// A move of a single piece, given by the spaces it visits in order as (row, col) coordinates,
+/// starting with the space it moves from. A simple move visits two spaces, while a capture visits one more space for every jump.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Action {
+    pub path: Vec<(usize, usize)>,
+}
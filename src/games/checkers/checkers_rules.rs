@@ -0,0 +1,102 @@
+/// The rules that set the checkers variants apart from each other.
+/// Captures are mandatory in every variant, whatever the size of the board it is played on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CheckersRules {
+    /// Kings move and capture along a whole diagonal rather than a single space at a time,
+    /// landing on any empty space past the piece they capture.
+    pub are_kings_flying: bool,
+    /// Single pieces capture backwards as well as forwards, though they still only move forwards.
+    pub can_single_pieces_capture_backwards: bool,
+    pub can_single_pieces_capture_kings: bool,
+    /// Among the captures the other rules allow, only the ones that take the most kings may be played.
+    pub is_capturing_most_kings_preferred: bool,
+    /// Among the captures the other rules allow, only the ones made with a king may be played when there is any.
+    /// Applied before the preference for capturing the most kings.
+    pub is_capturing_with_king_preferred: bool,
+    /// Only the captures that take the most pieces may be played.
+    pub is_maximum_capture_mandatory: bool,
+    /// A single piece reaching the far row in the middle of a capture is crowned right away and carries on capturing as a king.
+    /// Otherwise it carries on capturing as a single piece and is only crowned if its move ends on the far row.
+    pub is_promotion_mid_capture_continuing: bool,
+    /// The number of moves in a row, counting the moves of both players, without a capture or a move of a single piece
+    /// after which the game is drawn.
+    pub no_progress_moves_limit: usize,
+}
+
+impl CheckersRules {
+    /// American checkers, also known as English draughts.
+    pub fn american() -> CheckersRules {
+        return CheckersRules {
+            are_kings_flying: false,
+            can_single_pieces_capture_backwards: false,
+            can_single_pieces_capture_kings: true,
+            is_capturing_most_kings_preferred: false,
+            is_capturing_with_king_preferred: false,
+            is_maximum_capture_mandatory: false,
+            is_promotion_mid_capture_continuing: false,
+            no_progress_moves_limit: 80,
+        };
+    }
+
+    pub fn brazilian() -> CheckersRules {
+        return CheckersRules {
+            are_kings_flying: true,
+            can_single_pieces_capture_backwards: true,
+            can_single_pieces_capture_kings: true,
+            is_capturing_most_kings_preferred: false,
+            is_capturing_with_king_preferred: false,
+            is_maximum_capture_mandatory: true,
+            is_promotion_mid_capture_continuing: false,
+            no_progress_moves_limit: 80,
+        };
+    }
+
+    /// International draughts, played by the Brazilian rules on a 10x10 board, with 25 moves for each player without progress making a draw.
+    pub fn international() -> CheckersRules {
+        return CheckersRules {
+            no_progress_moves_limit: 50,
+            ..CheckersRules::brazilian()
+        };
+    }
+
+    /// Italian checkers, without the last preference between captures of the same length,
+    /// which is capturing kings first.
+    pub fn italian() -> CheckersRules {
+        return CheckersRules {
+            are_kings_flying: false,
+            can_single_pieces_capture_backwards: false,
+            can_single_pieces_capture_kings: false,
+            is_capturing_most_kings_preferred: true,
+            is_capturing_with_king_preferred: true,
+            is_maximum_capture_mandatory: true,
+            is_promotion_mid_capture_continuing: false,
+            no_progress_moves_limit: 80,
+        };
+    }
+
+    pub fn pool() -> CheckersRules {
+        return CheckersRules {
+            are_kings_flying: true,
+            can_single_pieces_capture_backwards: true,
+            can_single_pieces_capture_kings: true,
+            is_capturing_most_kings_preferred: false,
+            is_capturing_with_king_preferred: false,
+            is_maximum_capture_mandatory: false,
+            is_promotion_mid_capture_continuing: false,
+            no_progress_moves_limit: 80,
+        };
+    }
+
+    pub fn russian() -> CheckersRules {
+        return CheckersRules {
+            are_kings_flying: true,
+            can_single_pieces_capture_backwards: true,
+            can_single_pieces_capture_kings: true,
+            is_capturing_most_kings_preferred: false,
+            is_capturing_with_king_preferred: false,
+            is_maximum_capture_mandatory: false,
+            is_promotion_mid_capture_continuing: true,
+            no_progress_moves_limit: 80,
+        };
+    }
+}
@@ -1,25 +1,31 @@
 use crate::games::checkers::internal::*;
-use crate::games::checkers::GameStateType as CheckersGameState;
-use crate::traits::CLIGameStateFormatter as TCLIGameStateFormatter;
+use crate::traits::{BasicGameState, CLIGameStateFormatter as TCLIGameStateFormatter};
 
 pub struct CLIGameStateFormatter {}
 
-impl TCLIGameStateFormatter<CheckersGameState> for CLIGameStateFormatter {
-    fn format_game_state_for_cli(&self, game_state: &CheckersGameState) -> String {
+impl<const BOARD_SIZE: usize> TCLIGameStateFormatter<Board<BOARD_SIZE>> for CLIGameStateFormatter
+where
+    Board<BOARD_SIZE>: BasicGameState,
+{
+    fn format_game_state_for_cli(&self, game_state: &Board<BOARD_SIZE>) -> String {
         let mut string_pieces: Vec<String> = vec![];
 
-        string_pieces.push(String::from(" |0|1|2|3|4|5|6|7|\n"));
+        string_pieces.push(String::from(" "));
+        for col in 0..BOARD_SIZE {
+            string_pieces.push(format!("|{}", col));
+        }
+        string_pieces.push(String::from("|\n"));
 
-        for row in 0..MAX_ROW + 1 {
+        for row in 0..BOARD_SIZE {
             string_pieces.push(format!("{}", row));
-            for col in 0..MAX_COL + 1 {
+            for col in 0..BOARD_SIZE {
                 if (row + col) % 2 == 0 {
                     // not a usable space
                     string_pieces.push(String::from("|■"));
                 } else {
                     string_pieces.push(format!(
                         "|{}",
-                        convert_space_value_to_cli_string(game_state[row as usize][col as usize])
+                        convert_space_value_to_cli_string(game_state[row][col])
                     ));
                 }
             }
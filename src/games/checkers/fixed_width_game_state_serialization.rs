@@ -0,0 +1,165 @@
+use crate::games::checkers::internal::*;
+use crate::games::checkers::GameStateType as CheckersGameState;
+use crate::structs::FixedWidthSerializedGameState;
+use crate::traits::{GameStateDeserializer, GameStateSerializer};
+
+// Each part of the serialized game state is a bitboard of the 32 playable spaces, numbered row by row
+const FIRST_PLAYER_PIECES_OFFSET: u32 = 0;
+const SECOND_PLAYER_PIECES_OFFSET: u32 = 32;
+const DOUBLE_PIECES_OFFSET: u32 = 64;
+const RESPONSIBLE_PLAYER_OFFSET: u32 = 96;
+
+const NO_RESPONSIBLE_PLAYER_BITS: u128 = 0b11;
+
+/// Packs a game state into 98 bits:
+/// - the first 32 bits tell which playable spaces hold a piece of the first player
+/// - the next 32 bits tell which playable spaces hold a piece of the second player
+/// - the next 32 bits tell which playable spaces hold a double piece, whoever it belongs to
+/// - the last two bits hold the player who last moved (11 for no player responsible)
+pub struct FixedWidthGameStateSerializer {}
+
+impl GameStateSerializer<CheckersGameState, FixedWidthSerializedGameState>
+    for FixedWidthGameStateSerializer
+{
+    fn serialize_game_state(
+        &self,
+        responsible_player_index: i32,
+        game_state: &CheckersGameState,
+    ) -> FixedWidthSerializedGameState {
+        let mut bits: u128 = 0;
+
+        for playable_space_index in 0..PLAYABLE_SPACES_COUNT {
+            let (row, col) = get_playable_space_coor(game_state.len(), playable_space_index);
+            let space_bits: u128 = match game_state[row][col] {
+                FIRST_PLAYER_SINGLE_PIECE_VALUE => 1 << FIRST_PLAYER_PIECES_OFFSET,
+                FIRST_PLAYER_DOUBLE_PIECE_VALUE => {
+                    (1 << FIRST_PLAYER_PIECES_OFFSET) | (1 << DOUBLE_PIECES_OFFSET)
+                }
+                SECOND_PLAYER_SINGLE_PIECE_VALUE => 1 << SECOND_PLAYER_PIECES_OFFSET,
+                SECOND_PLAYER_DOUBLE_PIECE_VALUE => {
+                    (1 << SECOND_PLAYER_PIECES_OFFSET) | (1 << DOUBLE_PIECES_OFFSET)
+                }
+                _ => 0,
+            };
+            bits |= space_bits << playable_space_index;
+        }
+
+        let responsible_player_bits = if responsible_player_index == -1 {
+            NO_RESPONSIBLE_PLAYER_BITS
+        } else {
+            responsible_player_index as u128
+        };
+        bits |= responsible_player_bits << RESPONSIBLE_PLAYER_OFFSET;
+
+        return FixedWidthSerializedGameState::new(bits);
+    }
+}
+
+pub struct FixedWidthGameStateDeserializer {}
+
+impl GameStateDeserializer<CheckersGameState, FixedWidthSerializedGameState>
+    for FixedWidthGameStateDeserializer
+{
+    fn deserialize_game_state(
+        &self,
+        serialized_game_state: &FixedWidthSerializedGameState,
+    ) -> (i32, CheckersGameState) {
+        let bits = serialized_game_state.bits;
+        let mut game_state = [[EMPTY_SPACE_VALUE; 8]; 8];
+
+        for playable_space_index in 0..PLAYABLE_SPACES_COUNT {
+            let is_set = |offset: u32| -> bool {
+                return (bits >> (offset as usize + playable_space_index)) & 1 == 1;
+            };
+
+            let (row, col) = get_playable_space_coor(game_state.len(), playable_space_index);
+            game_state[row][col] = match (
+                is_set(FIRST_PLAYER_PIECES_OFFSET),
+                is_set(SECOND_PLAYER_PIECES_OFFSET),
+                is_set(DOUBLE_PIECES_OFFSET),
+            ) {
+                (true, _, false) => FIRST_PLAYER_SINGLE_PIECE_VALUE,
+                (true, _, true) => FIRST_PLAYER_DOUBLE_PIECE_VALUE,
+                (false, true, false) => SECOND_PLAYER_SINGLE_PIECE_VALUE,
+                (false, true, true) => SECOND_PLAYER_DOUBLE_PIECE_VALUE,
+                (false, false, _) => EMPTY_SPACE_VALUE,
+            };
+        }
+
+        let responsible_player_bits = (bits >> RESPONSIBLE_PLAYER_OFFSET) & 0b11;
+        let responsible_player_index = if responsible_player_bits == NO_RESPONSIBLE_PLAYER_BITS {
+            -1
+        } else {
+            responsible_player_bits as i32
+        };
+
+        return (responsible_player_index, game_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn test_for_invertibility() {
+        let serializer = FixedWidthGameStateSerializer {};
+        let deserializer = FixedWidthGameStateDeserializer {};
+
+        let test_game_states: Vec<(i32, CheckersGameState)> = vec![
+            (
+                -1,
+                [
+                    [0, 1, 0, 1, 0, 1, 0, 1],
+                    [1, 0, 1, 0, 1, 0, 1, 0],
+                    [0, 1, 0, 1, 0, 1, 0, 1],
+                    [0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0],
+                    [2, 0, 2, 0, 2, 0, 2, 0],
+                    [0, 2, 0, 2, 0, 2, 0, 2],
+                    [2, 0, 2, 0, 2, 0, 2, 0],
+                ],
+            ),
+            (
+                0,
+                [
+                    [0, 22, 0, 1, 0, 1, 0, 1],
+                    [1, 0, 1, 0, 1, 0, 1, 0],
+                    [0, 1, 0, 1, 0, 1, 0, 1],
+                    [0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0],
+                    [2, 0, 2, 0, 2, 0, 2, 0],
+                    [0, 2, 0, 2, 0, 2, 0, 2],
+                    [2, 0, 2, 0, 2, 0, 11, 0],
+                ],
+            ),
+            (
+                1,
+                [
+                    [0, 22, 0, 1, 0, 1, 0, 1],
+                    [1, 0, 1, 0, 1, 0, 1, 0],
+                    [0, 1, 0, 1, 0, 1, 0, 1],
+                    [0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0],
+                    [2, 0, 2, 0, 2, 0, 2, 0],
+                    [0, 2, 0, 2, 0, 2, 0, 2],
+                    [2, 0, 2, 0, 2, 0, 11, 0],
+                ],
+            ),
+        ];
+
+        for (responsible_player_index, game_state) in test_game_states.iter() {
+            let (deserialized_responsible_player_index, deserialized_game_state) = deserializer
+                .deserialize_game_state(
+                    &serializer.serialize_game_state(*responsible_player_index, game_state),
+                );
+
+            assert_eq!(
+                *responsible_player_index,
+                deserialized_responsible_player_index
+            );
+            assert_eq!(*game_state, deserialized_game_state);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::games::checkers::{
+    create_initial_game_state, CLIGameStateFormatter, CheckersRules,
+    FixedWidthGameStateDeserializer, FixedWidthGameStateSerializer, GameRulesAuthority,
+    GameStateSymmetriesFinder, GameStateType as CheckersGameState, UserInputGameStateCreator,
+};
+#[cfg(feature = "torch")]
+use crate::games::checkers::{
+    find_move_policy_index, transform_game_state_to_tensor, PolicyValueTorchNet, TorchNet,
+    MOVE_POLICY_SIZE,
+};
+use crate::structs::FixedWidthSerializedGameState;
+#[cfg(feature = "torch")]
+use crate::structs::NeuralNetDefinition;
+#[cfg(feature = "torch")]
+use crate::traits::PolicyValueNet;
+use crate::traits::{
+    CLIGameStateFormatter as TCLIGameStateFormatter, GameActionNotationsFinder,
+    GameDefinition as TGameDefinition, GameRulesAuthority as TGameRulesAuthority,
+    GameStateDeserializer, GameStateSerializer,
+    GameStateSymmetriesFinder as TGameStateSymmetriesFinder,
+    UserInputGameStateCreator as TUserInputGameStateCreator,
+};
+#[cfg(feature = "torch")]
+use tch::nn;
+
+pub struct GameDefinition {
+    cli_game_state_formatter: CLIGameStateFormatter,
+    game_rules_authority: GameRulesAuthority<8>,
+    game_state_deserializer: FixedWidthGameStateDeserializer,
+    game_state_serializer: FixedWidthGameStateSerializer,
+    game_state_symmetries_finder: GameStateSymmetriesFinder,
+    name: &'static str,
+    #[cfg(feature = "torch")]
+    neural_net_definition: NeuralNetDefinition<CheckersGameState>,
+    user_input_game_state_creator: UserInputGameStateCreator<8>,
+}
+
+impl GameDefinition {
+    /// American checkers.
+    pub fn new() -> GameDefinition {
+        return GameDefinition::new_with_rules("checkers", CheckersRules::american());
+    }
+
+    /// A checkers variant going by the given name, played on the same board as American checkers.
+    pub fn new_with_rules(name: &'static str, rules: CheckersRules) -> GameDefinition {
+        return GameDefinition {
+            cli_game_state_formatter: CLIGameStateFormatter {},
+            game_rules_authority: GameRulesAuthority::new(rules),
+            game_state_deserializer: FixedWidthGameStateDeserializer {},
+            game_state_serializer: FixedWidthGameStateSerializer {},
+            game_state_symmetries_finder: GameStateSymmetriesFinder {},
+            name: name,
+            #[cfg(feature = "torch")]
+            neural_net_definition: NeuralNetDefinition {
+                create_policy_value_net: create_policy_value_net,
+                create_value_net: create_value_net,
+                find_move_policy_index: find_move_policy_index,
+                move_policy_size: MOVE_POLICY_SIZE,
+                transform_game_state_to_tensor: transform_game_state_to_tensor,
+            },
+            user_input_game_state_creator: UserInputGameStateCreator::new(rules),
+        };
+    }
+}
+
+impl TGameDefinition<CheckersGameState, FixedWidthSerializedGameState> for GameDefinition {
+    fn get_name(&self) -> &str {
+        return self.name;
+    }
+
+    fn get_game_state_records_version(&self) -> i32 {
+        // Game states used to be serialized to a byte for every piece rather than to a fixed width
+        return 2;
+    }
+
+    fn create_initial_game_state(&self) -> CheckersGameState {
+        return create_initial_game_state();
+    }
+
+    fn get_cli_game_state_formatter(&self) -> &dyn TCLIGameStateFormatter<CheckersGameState> {
+        return &self.cli_game_state_formatter;
+    }
+
+    fn get_game_rules_authority(&self) -> &(dyn TGameRulesAuthority<CheckersGameState> + Sync) {
+        return &self.game_rules_authority;
+    }
+
+    fn get_game_action_notations_finder(
+        &self,
+    ) -> Option<&(dyn GameActionNotationsFinder<CheckersGameState> + Sync)> {
+        return Some(&self.game_rules_authority);
+    }
+
+    fn get_game_state_deserializer(
+        &self,
+    ) -> &(dyn GameStateDeserializer<CheckersGameState, FixedWidthSerializedGameState> + Sync) {
+        return &self.game_state_deserializer;
+    }
+
+    fn get_game_state_serializer(
+        &self,
+    ) -> &(dyn GameStateSerializer<CheckersGameState, FixedWidthSerializedGameState> + Sync) {
+        return &self.game_state_serializer;
+    }
+
+    fn get_game_state_symmetries_finder(
+        &self,
+    ) -> Option<&(dyn TGameStateSymmetriesFinder<CheckersGameState> + Sync)> {
+        return Some(&self.game_state_symmetries_finder);
+    }
+
+    fn get_user_input_game_state_creator(
+        &self,
+    ) -> &dyn TUserInputGameStateCreator<CheckersGameState, String> {
+        return &self.user_input_game_state_creator;
+    }
+
+    #[cfg(feature = "torch")]
+    fn get_neural_net_definition(&self) -> Option<&NeuralNetDefinition<CheckersGameState>> {
+        return Some(&self.neural_net_definition);
+    }
+}
+
+#[cfg(feature = "torch")]
+fn create_policy_value_net(vs: &nn::Path) -> Box<dyn PolicyValueNet + Sync> {
+    return Box::new(PolicyValueTorchNet::new(vs));
+}
+
+#[cfg(feature = "torch")]
+fn create_value_net(vs: &nn::Path) -> Box<dyn nn::Module + Sync> {
+    return Box::new(TorchNet::new(vs));
+}
@@ -1,63 +1,36 @@
 use crate::games::checkers::internal::*;
-use crate::games::checkers::GameStateType as CheckersGameState;
-use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+use crate::games::checkers::{Action, CheckersRules};
+use crate::internal::utility_functions;
+use crate::traits::{
+    BasicGameState, GameActionsAuthority as TGameActionsAuthority,
+    GameRulesAuthority as TGameRulesAuthority,
+};
 
-const MAX_NUMBER_OF_MOVES_TO_FIND: i32 = 10_000;
+/// Plays by the given checkers rules on a board of the given size.
+/// Besides losing by running out of moves, games are drawn by threefold repetition
+/// or once the no-progress moves limit of the rules is reached.
+pub struct GameRulesAuthority<const BOARD_SIZE: usize> {
+    rules: CheckersRules,
+}
 
-pub struct GameRulesAuthority {}
+impl<const BOARD_SIZE: usize> GameRulesAuthority<BOARD_SIZE> {
+    pub fn new(rules: CheckersRules) -> GameRulesAuthority<BOARD_SIZE> {
+        return GameRulesAuthority { rules: rules };
+    }
+}
 
-impl TGameRulesAuthority<CheckersGameState> for GameRulesAuthority {
+impl<const BOARD_SIZE: usize> TGameRulesAuthority<Board<BOARD_SIZE>>
+    for GameRulesAuthority<BOARD_SIZE>
+where
+    Board<BOARD_SIZE>: BasicGameState,
+{
     fn analyze_game_state_for_terminality(
         &self,
-        game_state: &CheckersGameState,
+        game_state: &Board<BOARD_SIZE>,
         next_player_index: i32,
     ) -> Option<i32> {
-        let move_search_params = get_player_specific_move_search_parameters(next_player_index);
-        let mut owned_pieces_info: Vec<((usize, usize), &[(i8, i8)])> = vec![];
-        fill_vector_with_current_player_owned_pieces_info_for_move_search(
-            game_state,
-            &move_search_params,
-            &mut owned_pieces_info,
-        );
-
-        let mut available_move_states: Vec<CheckersGameState> = vec![];
-
-        // see if any simple moves exist for any pieces first since that's cheaper
-        for (current_coor, available_directions) in owned_pieces_info.iter() {
-            fill_vector_with_available_simple_move_states_for_piece(
-                game_state,
-                current_coor,
-                available_directions,
-                move_search_params.single_piece_value,
-                move_search_params.double_piece_value,
-                move_search_params.double_row,
-                &mut available_move_states,
-                1,
-            );
-            if !available_move_states.is_empty() {
-                // at least one move exists so this player hasn't lost yet
-                return None;
-            }
-        }
-
-        // no simple moves were found so let's see if we have
-        // any capture moves (which are more expensive to find) are available
-        for (current_coor, available_directions) in owned_pieces_info.iter() {
-            fill_vector_with_available_capture_move_states_for_piece(
-                next_player_index,
-                game_state,
-                current_coor,
-                available_directions,
-                move_search_params.single_piece_value,
-                move_search_params.double_piece_value,
-                move_search_params.double_row,
-                &mut available_move_states,
-                1,
-            );
-            if !available_move_states.is_empty() {
-                // at least one move exists so this player hasn't lost yet
-                return None;
-            }
+        if has_any_available_move(next_player_index, game_state, &self.rules) {
+            return None;
         }
 
         // no available moves have been found
@@ -68,56 +41,612 @@ impl TGameRulesAuthority<CheckersGameState> for GameRulesAuthority {
         return Some(other_player_index);
     }
 
+    fn analyze_game_history_for_terminality(
+        &self,
+        game_states_history: &Vec<Board<BOARD_SIZE>>,
+        next_player_index: i32,
+    ) -> Option<i32> {
+        let current_game_state = &game_states_history[game_states_history.len() - 1];
+        if let Some(winning_player_index) =
+            self.analyze_game_state_for_terminality(current_game_state, next_player_index)
+        {
+            return Some(winning_player_index);
+        }
+
+        if utility_functions::is_game_history_drawn(
+            game_states_history,
+            is_progress_move,
+            self.rules.no_progress_moves_limit,
+            REPETITIONS_FOR_DRAW,
+        ) {
+            return Some(-1);
+        }
+
+        return None;
+    }
+
     fn find_available_next_game_states(
         &self,
         current_player_index: i32,
-        current_game_state: &CheckersGameState,
-    ) -> Vec<CheckersGameState> {
-        let mut available_next_states: Vec<CheckersGameState> = vec![];
+        current_game_state: &Board<BOARD_SIZE>,
+    ) -> Vec<Board<BOARD_SIZE>> {
+        let mut available_next_states: Vec<Board<BOARD_SIZE>> = vec![];
 
-        let move_search_params = get_player_specific_move_search_parameters(current_player_index);
-        let mut owned_pieces_info: Vec<((usize, usize), &[(i8, i8)])> = vec![];
-        fill_vector_with_current_player_owned_pieces_info_for_move_search(
+        for path in find_available_move_paths(current_player_index, current_game_state, &self.rules)
+        {
+            let next_state =
+                perform_move(current_player_index, current_game_state, &self.rules, &path);
+            // flying kings can capture the same pieces by different paths, which end up in the same game state
+            if !available_next_states.contains(&next_state) {
+                available_next_states.push(next_state);
+            }
+        }
+
+        return available_next_states;
+    }
+}
+
+impl<const BOARD_SIZE: usize> TGameActionsAuthority<Board<BOARD_SIZE>>
+    for GameRulesAuthority<BOARD_SIZE>
+where
+    Board<BOARD_SIZE>: BasicGameState,
+{
+    type Action = Action;
+
+    fn find_legal_actions(
+        &self,
+        current_player_index: i32,
+        current_game_state: &Board<BOARD_SIZE>,
+    ) -> Vec<Action> {
+        return find_available_move_paths(current_player_index, current_game_state, &self.rules)
+            .into_iter()
+            .map(|path| Action { path: path })
+            .collect();
+    }
+
+    fn apply_action(
+        &self,
+        current_player_index: i32,
+        current_game_state: &Board<BOARD_SIZE>,
+        action: &Action,
+    ) -> Board<BOARD_SIZE> {
+        return perform_move(
+            current_player_index,
             current_game_state,
-            &move_search_params,
-            &mut owned_pieces_info,
-        );
-
-        // find any available capture moves
-        for (current_coor, available_directions) in owned_pieces_info.iter() {
-            fill_vector_with_available_capture_move_states_for_piece(
-                current_player_index,
-                current_game_state,
-                current_coor,
-                available_directions,
-                move_search_params.single_piece_value,
-                move_search_params.double_piece_value,
-                move_search_params.double_row,
-                &mut available_next_states,
-                MAX_NUMBER_OF_MOVES_TO_FIND,
+            &self.rules,
+            &action.path,
+        );
+    }
+
+    fn format_action(&self, action: &Action) -> String {
+        let coordinate_pieces: Vec<String> = action
+            .path
+            .iter()
+            .map(|coor| format!("{},{}", coor.0, coor.1))
+            .collect();
+        return coordinate_pieces.join(";");
+    }
+
+    fn get_number_of_action_indices(&self) -> usize {
+        let playable_spaces_count = BOARD_SIZE * BOARD_SIZE / 2;
+        return playable_spaces_count * playable_spaces_count;
+    }
+
+    /// Actions are identified by the playable space they start from and the playable space they end on,
+    /// the same way moves are encoded for the policy value net.
+    fn find_action_index(&self, _current_player_index: i32, action: &Action) -> usize {
+        let playable_spaces_count = BOARD_SIZE * BOARD_SIZE / 2;
+        let start_coor = action.path[0];
+        let end_coor = action.path[action.path.len() - 1];
+        return get_playable_space_index(BOARD_SIZE, start_coor.0, start_coor.1)
+            * playable_spaces_count
+            + get_playable_space_index(BOARD_SIZE, end_coor.0, end_coor.1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::perft;
+    use crate::games::checkers::{create_initial_game_state, GameStateType as CheckersGameState};
+    use crate::games::draughts;
+
+    fn create_game_state(pieces: &[((usize, usize), u8)]) -> CheckersGameState {
+        let mut game_state = [[EMPTY_SPACE_VALUE; 8]; 8];
+        for (coor, piece_value) in pieces.iter() {
+            game_state[coor.0][coor.1] = *piece_value;
+        }
+        return game_state;
+    }
+
+    fn find_sorted_legal_paths(
+        rules: CheckersRules,
+        current_player_index: i32,
+        game_state: &CheckersGameState,
+    ) -> Vec<Vec<(usize, usize)>> {
+        let game_rules_authority = GameRulesAuthority::new(rules);
+        let mut legal_paths: Vec<Vec<(usize, usize)>> = game_rules_authority
+            .find_legal_actions(current_player_index, game_state)
+            .into_iter()
+            .map(|action| action.path)
+            .collect();
+        legal_paths.sort();
+        return legal_paths;
+    }
+
+    #[test]
+    fn test_perft_from_initial_game_state() {
+        let game_rules_authority = GameRulesAuthority::new(CheckersRules::american());
+        let initial_game_state = create_initial_game_state();
+
+        // Published perft counts of American checkers
+        let expected_leaf_nodes_counts: Vec<u64> = vec![7, 49, 302, 1469, 7361, 36768, 179740];
+
+        for (i, expected_leaf_nodes_count) in expected_leaf_nodes_counts.iter().enumerate() {
+            assert_eq!(
+                perft(
+                    &game_rules_authority,
+                    2,
+                    0,
+                    &initial_game_state,
+                    i as u32 + 1
+                ),
+                *expected_leaf_nodes_count
             );
         }
-        if !available_next_states.is_empty() {
-            // There are capture available, which means no simple moves are allowed
-            // Just return and don't waste time finding those moves
-            return available_next_states;
+    }
+
+    #[test]
+    fn test_perft_from_initial_draughts_game_state() {
+        let game_rules_authority = GameRulesAuthority::new(CheckersRules::international());
+        let initial_game_state = draughts::create_initial_game_state();
+
+        // Published perft counts of international draughts
+        let expected_leaf_nodes_counts: Vec<u64> = vec![9, 81, 658, 4265];
+
+        for (i, expected_leaf_nodes_count) in expected_leaf_nodes_counts.iter().enumerate() {
+            assert_eq!(
+                perft(
+                    &game_rules_authority,
+                    2,
+                    0,
+                    &initial_game_state,
+                    i as u32 + 1
+                ),
+                *expected_leaf_nodes_count
+            );
+        }
+    }
+
+    #[test]
+    fn test_action_indices() {
+        let game_rules_authority = GameRulesAuthority::new(CheckersRules::american());
+        let initial_game_state = create_initial_game_state();
+        assert_eq!(game_rules_authority.get_number_of_action_indices(), 32 * 32);
+
+        let mut action_indices: Vec<usize> = game_rules_authority
+            .find_legal_actions(0, &initial_game_state)
+            .iter()
+            .map(|action| game_rules_authority.find_action_index(0, action))
+            .collect();
+        action_indices.sort();
+        action_indices.dedup();
+        assert_eq!(action_indices.len(), 7);
+        assert!(action_indices
+            .iter()
+            .all(|action_index| *action_index < 32 * 32));
+
+        // From the 9th playable space to the 13th, both counted from 0
+        let action = Action {
+            path: vec![(2, 1), (3, 0)],
+        };
+        assert_eq!(
+            game_rules_authority.find_action_index(0, &action),
+            8 * 32 + 12
+        );
+
+        let draughts_game_rules_authority: GameRulesAuthority<10> =
+            GameRulesAuthority::new(CheckersRules::international());
+        assert_eq!(
+            draughts_game_rules_authority.get_number_of_action_indices(),
+            50 * 50
+        );
+    }
+
+    #[test]
+    fn test_multi_jump_branches() {
+        let game_state = create_game_state(&[
+            ((2, 1), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((3, 2), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((5, 2), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((5, 4), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::american(), 0, &game_state),
+            vec![vec![(2, 1), (4, 3), (6, 1)], vec![(2, 1), (4, 3), (6, 5)],]
+        );
+    }
+
+    #[test]
+    fn test_king_captures_each_piece_once_when_going_around() {
+        let game_state = create_game_state(&[
+            ((2, 3), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((3, 2), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((3, 4), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((5, 2), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((5, 4), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        // the king ends up on the space it started from, which it left as soon as it moved
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::american(), 0, &game_state),
+            vec![
+                vec![(2, 3), (4, 1), (6, 3), (4, 5), (2, 3)],
+                vec![(2, 3), (4, 5), (6, 3), (4, 1), (2, 3)],
+            ]
+        );
+
+        // both ways around capture the same pieces
+        let game_rules_authority = GameRulesAuthority::new(CheckersRules::american());
+        assert_eq!(
+            game_rules_authority.find_available_next_game_states(0, &game_state),
+            vec![create_game_state(&[(
+                (2, 3),
+                FIRST_PLAYER_DOUBLE_PIECE_VALUE
+            )])]
+        );
+    }
+
+    #[test]
+    fn test_promotion_ends_capture() {
+        let game_state = create_game_state(&[
+            ((5, 2), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((6, 3), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((6, 5), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        let game_rules_authority = GameRulesAuthority::new(CheckersRules::american());
+        assert_eq!(
+            game_rules_authority.find_available_next_game_states(0, &game_state),
+            vec![create_game_state(&[
+                ((6, 5), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+                ((7, 4), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_second_player_multi_jump_promotes() {
+        let game_state = create_game_state(&[
+            ((1, 2), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((1, 4), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((3, 2), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((4, 1), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::american(), 1, &game_state),
+            vec![vec![(4, 1), (2, 3), (0, 1)], vec![(4, 1), (2, 3), (0, 5)],]
+        );
+
+        let game_rules_authority = GameRulesAuthority::new(CheckersRules::american());
+        for next_game_state in game_rules_authority
+            .find_available_next_game_states(1, &game_state)
+            .iter()
+        {
+            assert!(next_game_state[0].contains(&SECOND_PLAYER_DOUBLE_PIECE_VALUE));
         }
+    }
+
+    #[test]
+    fn test_single_pieces_do_not_capture_backwards() {
+        let game_state = create_game_state(&[
+            ((3, 2), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((3, 4), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((4, 3), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::american(), 0, &game_state),
+            vec![vec![(4, 3), (5, 2)], vec![(4, 3), (5, 4)]]
+        );
+    }
+
+    #[test]
+    fn test_capture_is_mandatory() {
+        let game_state = create_game_state(&[
+            ((2, 1), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((2, 5), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((3, 2), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::american(), 0, &game_state),
+            vec![vec![(2, 1), (4, 3)]]
+        );
+    }
 
-        // no capture moves are available
-        // so let's see what simple moves exist
-        for (current_coor, available_directions) in owned_pieces_info.iter() {
-            fill_vector_with_available_simple_move_states_for_piece(
-                current_game_state,
-                current_coor,
-                available_directions,
-                move_search_params.single_piece_value,
-                move_search_params.double_piece_value,
-                move_search_params.double_row,
-                &mut available_next_states,
-                MAX_NUMBER_OF_MOVES_TO_FIND,
+    #[test]
+    fn test_flying_king_captures_from_afar() {
+        let game_state = create_game_state(&[
+            ((4, 3), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((1, 6), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((6, 1), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        // Flying back along the diagonal would capture the other piece too,
+        // if the piece just captured didn't stay in the way until the move is over
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::russian(), 0, &game_state),
+            vec![vec![(4, 3), (0, 7)], vec![(4, 3), (7, 0)]]
+        );
+
+        // kings that don't fly have nothing next to them to capture
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::american(), 0, &game_state),
+            vec![
+                vec![(4, 3), (3, 2)],
+                vec![(4, 3), (3, 4)],
+                vec![(4, 3), (5, 2)],
+                vec![(4, 3), (5, 4)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_maximum_capture_is_mandatory() {
+        let game_state = create_game_state(&[
+            ((0, 7), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((1, 6), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((2, 1), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((3, 2), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((5, 4), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::brazilian(), 0, &game_state),
+            vec![vec![(2, 1), (4, 3), (6, 5)]]
+        );
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::pool(), 0, &game_state),
+            vec![vec![(0, 7), (2, 5)], vec![(2, 1), (4, 3), (6, 5)]]
+        );
+    }
+
+    #[test]
+    fn test_promotion_mid_capture_continues_as_flying_king() {
+        let game_state = create_game_state(&[
+            ((5, 2), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((6, 3), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((5, 6), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        // crowned on the far row, the piece flies on to capture a piece it isn't next to
+        let game_rules_authority = GameRulesAuthority::new(CheckersRules::russian());
+        assert_eq!(
+            game_rules_authority.find_available_next_game_states(0, &game_state),
+            vec![create_game_state(&[(
+                (4, 7),
+                FIRST_PLAYER_DOUBLE_PIECE_VALUE
+            )])]
+        );
+
+        let game_rules_authority = GameRulesAuthority::new(CheckersRules::pool());
+        assert_eq!(
+            game_rules_authority.find_available_next_game_states(0, &game_state),
+            vec![create_game_state(&[
+                ((5, 6), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+                ((7, 4), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_passing_through_far_row_mid_capture_does_not_promote() {
+        let game_state = create_game_state(&[
+            ((5, 0), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((6, 1), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((6, 3), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        // the single piece captures backwards off the far row and stays a single piece
+        for rules in [CheckersRules::brazilian(), CheckersRules::pool()].iter() {
+            let game_rules_authority = GameRulesAuthority::new(*rules);
+            assert_eq!(
+                game_rules_authority.find_available_next_game_states(0, &game_state),
+                vec![create_game_state(&[(
+                    (5, 4),
+                    FIRST_PLAYER_SINGLE_PIECE_VALUE
+                )])]
             );
         }
+    }
 
-        return available_next_states;
+    #[test]
+    fn test_single_pieces_do_not_capture_kings() {
+        let game_state = create_game_state(&[
+            ((2, 1), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((3, 2), SECOND_PLAYER_DOUBLE_PIECE_VALUE),
+        ]);
+
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::italian(), 0, &game_state),
+            vec![vec![(2, 1), (3, 0)]]
+        );
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::american(), 0, &game_state),
+            vec![vec![(2, 1), (4, 3)]]
+        );
+    }
+
+    #[test]
+    fn test_capturing_with_king_is_preferred() {
+        let game_state = create_game_state(&[
+            ((2, 1), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((2, 5), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((3, 2), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+            ((3, 6), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::italian(), 0, &game_state),
+            vec![vec![(2, 5), (4, 7)]]
+        );
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::american(), 0, &game_state),
+            vec![vec![(2, 1), (4, 3)], vec![(2, 5), (4, 7)]]
+        );
+    }
+
+    #[test]
+    fn test_capturing_most_kings_is_preferred() {
+        let game_state = create_game_state(&[
+            ((2, 3), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((3, 2), SECOND_PLAYER_DOUBLE_PIECE_VALUE),
+            ((3, 4), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::italian(), 0, &game_state),
+            vec![vec![(2, 3), (4, 1)]]
+        );
+        assert_eq!(
+            find_sorted_legal_paths(CheckersRules::american(), 0, &game_state),
+            vec![vec![(2, 3), (4, 1)], vec![(2, 3), (4, 5)]]
+        );
+    }
+
+    #[test]
+    fn test_threefold_repetition_draw() {
+        let game_rules_authority = GameRulesAuthority::new(CheckersRules::american());
+
+        // Both kings shuffle back and forth, bringing the starting position back every 4 moves
+        let first_game_state = create_game_state(&[
+            ((0, 1), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((7, 6), SECOND_PLAYER_DOUBLE_PIECE_VALUE),
+        ]);
+        let shuffled_game_states = [
+            create_game_state(&[
+                ((1, 2), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+                ((7, 6), SECOND_PLAYER_DOUBLE_PIECE_VALUE),
+            ]),
+            create_game_state(&[
+                ((1, 2), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+                ((6, 5), SECOND_PLAYER_DOUBLE_PIECE_VALUE),
+            ]),
+            create_game_state(&[
+                ((0, 1), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+                ((6, 5), SECOND_PLAYER_DOUBLE_PIECE_VALUE),
+            ]),
+            first_game_state,
+        ];
+
+        let mut game_states_history = vec![first_game_state];
+        for _ in 0..2 {
+            game_states_history.extend_from_slice(&shuffled_game_states);
+            // the first player is to move again, but the position came up only twice so far
+            if game_states_history.len() == 5 {
+                assert_eq!(
+                    game_rules_authority
+                        .analyze_game_history_for_terminality(&game_states_history, 0),
+                    None
+                );
+            }
+        }
+
+        assert_eq!(
+            game_rules_authority.analyze_game_history_for_terminality(&game_states_history, 0),
+            Some(-1)
+        );
+    }
+
+    #[test]
+    fn test_no_progress_moves_limit_draw() {
+        let rules = CheckersRules::american();
+        let game_rules_authority = GameRulesAuthority::new(rules);
+
+        // The kings hop around the board rather than making legal moves, which is all the same to the draw rules,
+        // so that no game state ever comes up twice
+        let playable_spaces: Vec<(usize, usize)> = (0..8)
+            .flat_map(|row| (0..8).map(move |col| (row, col)))
+            .filter(|(row, col)| (row + col) % 2 == 1)
+            .collect();
+        let game_states_history: Vec<CheckersGameState> = (0..rules.no_progress_moves_limit + 1)
+            .map(|move_index| {
+                return create_game_state(&[
+                    (
+                        playable_spaces[move_index % 26],
+                        FIRST_PLAYER_DOUBLE_PIECE_VALUE,
+                    ),
+                    (
+                        playable_spaces[26 + move_index / 26],
+                        SECOND_PLAYER_DOUBLE_PIECE_VALUE,
+                    ),
+                ]);
+            })
+            .collect();
+
+        assert_eq!(
+            game_rules_authority.analyze_game_history_for_terminality(
+                &game_states_history[..rules.no_progress_moves_limit].to_vec(),
+                1
+            ),
+            None
+        );
+        assert_eq!(
+            game_rules_authority.analyze_game_history_for_terminality(&game_states_history, 0),
+            Some(-1)
+        );
+
+        // A single piece moving along the way starts the count over
+        let mut game_states_history = game_states_history;
+        for (history_index, game_state) in game_states_history.iter_mut().enumerate() {
+            let single_piece_col = if history_index < 10 { 6 } else { 4 };
+            game_state[7][single_piece_col] = SECOND_PLAYER_SINGLE_PIECE_VALUE;
+        }
+        assert_eq!(
+            game_rules_authority.analyze_game_history_for_terminality(&game_states_history, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_progress_moves() {
+        let king_game_state = create_game_state(&[
+            ((3, 2), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((6, 1), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((4, 3), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+
+        let king_move_game_state = create_game_state(&[
+            ((2, 1), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((6, 1), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((4, 3), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+        assert!(!is_progress_move(&king_game_state, &king_move_game_state));
+
+        let capture_game_state = create_game_state(&[
+            ((5, 4), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((6, 1), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+        assert!(is_progress_move(&king_game_state, &capture_game_state));
+
+        let single_piece_move_game_state = create_game_state(&[
+            ((3, 2), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((6, 1), FIRST_PLAYER_SINGLE_PIECE_VALUE),
+            ((5, 2), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+        assert!(is_progress_move(
+            &king_game_state,
+            &single_piece_move_game_state
+        ));
+
+        let promotion_game_state = create_game_state(&[
+            ((3, 2), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((7, 0), FIRST_PLAYER_DOUBLE_PIECE_VALUE),
+            ((4, 3), SECOND_PLAYER_SINGLE_PIECE_VALUE),
+        ]);
+        assert!(is_progress_move(&king_game_state, &promotion_game_state));
     }
 }
@@ -0,0 +1,77 @@
+use crate::games::checkers::internal::*;
+use crate::games::checkers::GameStateType as CheckersGameState;
+use crate::traits::GameStateSymmetriesFinder as TGameStateSymmetriesFinder;
+
+/// The board looks the same to both players once it is turned around and the colors of the pieces are swapped,
+/// so a game state is equivalent to the one the other player would have gotten to by the mirrored moves.
+pub struct GameStateSymmetriesFinder {}
+
+impl TGameStateSymmetriesFinder<CheckersGameState> for GameStateSymmetriesFinder {
+    fn find_symmetric_game_states(
+        &self,
+        responsible_player_index: i32,
+        game_state: &CheckersGameState,
+    ) -> Vec<(i32, CheckersGameState)> {
+        let mut flipped_game_state = [[EMPTY_SPACE_VALUE; 8]; 8];
+        for row in 0..8 {
+            for col in 0..8 {
+                flipped_game_state[7 - row][7 - col] = match game_state[row][col] {
+                    FIRST_PLAYER_SINGLE_PIECE_VALUE => SECOND_PLAYER_SINGLE_PIECE_VALUE,
+                    FIRST_PLAYER_DOUBLE_PIECE_VALUE => SECOND_PLAYER_DOUBLE_PIECE_VALUE,
+                    SECOND_PLAYER_SINGLE_PIECE_VALUE => FIRST_PLAYER_SINGLE_PIECE_VALUE,
+                    SECOND_PLAYER_DOUBLE_PIECE_VALUE => FIRST_PLAYER_DOUBLE_PIECE_VALUE,
+                    space_value => space_value,
+                };
+            }
+        }
+
+        // no player is responsible for the initial game state, whichever way the board is turned
+        let flipped_responsible_player_index = if responsible_player_index == -1 {
+            -1
+        } else {
+            1 - responsible_player_index
+        };
+
+        return vec![
+            (responsible_player_index, *game_state),
+            (flipped_responsible_player_index, flipped_game_state),
+        ];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composites::CanonicalizingGameStateSerializer;
+    use crate::games::checkers::{create_initial_game_state, FixedWidthGameStateSerializer};
+    use crate::traits::GameStateSerializer;
+
+    #[test]
+    fn test_color_flip_shares_serialized_game_state() {
+        let serializer = CanonicalizingGameStateSerializer::new(
+            &FixedWidthGameStateSerializer {},
+            &GameStateSymmetriesFinder {},
+        );
+
+        let mut game_state = create_initial_game_state();
+        game_state[2][1] = EMPTY_SPACE_VALUE;
+        game_state[3][0] = FIRST_PLAYER_SINGLE_PIECE_VALUE;
+        // the same position, as seen with the colors and the sides of the board swapped
+        let mut flipped_game_state = create_initial_game_state();
+        flipped_game_state[5][6] = EMPTY_SPACE_VALUE;
+        flipped_game_state[4][7] = SECOND_PLAYER_SINGLE_PIECE_VALUE;
+
+        assert_eq!(
+            serializer.serialize_game_state(0, &game_state),
+            serializer.serialize_game_state(1, &flipped_game_state)
+        );
+        assert_ne!(
+            serializer.serialize_game_state(0, &game_state),
+            serializer.serialize_game_state(0, &flipped_game_state)
+        );
+        assert_eq!(
+            serializer.serialize_game_state(-1, &create_initial_game_state()),
+            FixedWidthGameStateSerializer {}.serialize_game_state(-1, &create_initial_game_state())
+        );
+    }
+}
@@ -1,8 +1,7 @@
-use crate::games::checkers::GameStateType as CheckersGameState;
-use std::collections::HashSet;
+use crate::games::checkers::CheckersRules;
 
-pub const MAX_ROW: i8 = 7;
-pub const MAX_COL: i8 = 7;
+/// The spaces of a square board by row and column, the checkers board being 8 spaces wide and the draughts one 10.
+pub type Board<const BOARD_SIZE: usize> = [[u8; BOARD_SIZE]; BOARD_SIZE];
 
 pub const EMPTY_SPACE_VALUE: u8 = 0;
 pub const FIRST_PLAYER_SINGLE_PIECE_VALUE: u8 = 1;
@@ -10,6 +9,11 @@ pub const FIRST_PLAYER_DOUBLE_PIECE_VALUE: u8 = 11;
 pub const SECOND_PLAYER_SINGLE_PIECE_VALUE: u8 = 2;
 pub const SECOND_PLAYER_DOUBLE_PIECE_VALUE: u8 = 22;
 
+pub const PLAYABLE_SPACES_COUNT: usize = 32;
+
+/// The number of times the same position has to come up with the same player to move for the game to be drawn.
+pub const REPETITIONS_FOR_DRAW: usize = 3;
+
 pub struct MoveSearchParameters {
     pub forward_row_direction: i8,
     pub single_piece_value: u8,
@@ -19,8 +23,11 @@ pub struct MoveSearchParameters {
     pub double_row: usize,
 }
 
-pub fn fill_vector_with_current_player_owned_pieces_info_for_move_search<'l>(
-    current_game_state: &CheckersGameState,
+pub fn fill_vector_with_current_player_owned_pieces_info_for_move_search<
+    'l,
+    const BOARD_SIZE: usize,
+>(
+    current_game_state: &Board<BOARD_SIZE>,
     move_search_params: &'l MoveSearchParameters,
     owned_pieces_info: &mut Vec<((usize, usize), &'l [(i8, i8)])>,
 ) {
@@ -42,165 +49,396 @@ pub fn fill_vector_with_current_player_owned_pieces_info_for_move_search<'l>(
     }
 }
 
-pub fn fill_vector_with_available_simple_move_states_for_piece(
-    current_game_state: &CheckersGameState,
-    start_coor: &(usize, usize),
-    available_directions: &[(i8, i8)],
-    single_piece_value: u8,
-    double_piece_value: u8,
-    double_row: usize,
-    available_simple_move_states: &mut Vec<CheckersGameState>,
-    max_number_of_moves_to_find: i32,
-) {
-    let current_game_state_space_value = current_game_state[start_coor.0][start_coor.1];
+/// Finds the paths of the moves the given player is allowed to make, each path being the spaces the moving piece visits,
+/// starting with the space it moves from.
+/// Captures are mandatory, so simple moves are only found when no capture is available.
+pub fn find_available_move_paths<const BOARD_SIZE: usize>(
+    current_player_index: i32,
+    current_game_state: &Board<BOARD_SIZE>,
+    rules: &CheckersRules,
+) -> Vec<Vec<(usize, usize)>> {
+    let capture_paths =
+        find_available_capture_paths(current_player_index, current_game_state, rules);
+    if !capture_paths.is_empty() {
+        return capture_paths;
+    }
 
-    for direction in available_directions.iter() {
-        let simple_move_coor = (
-            start_coor.0 as i8 + direction.0,
-            start_coor.1 as i8 + direction.1,
+    let move_search_params =
+        get_player_specific_move_search_parameters(current_player_index, BOARD_SIZE);
+    let mut owned_pieces_info: Vec<((usize, usize), &[(i8, i8)])> = vec![];
+    fill_vector_with_current_player_owned_pieces_info_for_move_search(
+        current_game_state,
+        &move_search_params,
+        &mut owned_pieces_info,
+    );
+
+    let mut simple_move_paths: Vec<Vec<(usize, usize)>> = vec![];
+    for (current_coor, available_directions) in owned_pieces_info.iter() {
+        let is_flying = rules.are_kings_flying
+            && current_game_state[current_coor.0][current_coor.1]
+                == move_search_params.double_piece_value;
+        fill_vector_with_available_simple_move_paths_for_piece(
+            current_game_state,
+            current_coor,
+            available_directions,
+            is_flying,
+            &mut simple_move_paths,
         );
+    }
 
-        if !is_valid_board_coordinate(simple_move_coor.0, simple_move_coor.1) {
-            continue;
-        }
+    return simple_move_paths;
+}
 
-        let simple_move_space_value =
-            current_game_state[simple_move_coor.0 as usize][simple_move_coor.1 as usize];
-        if simple_move_space_value == 0 {
-            let mut simple_move_state = current_game_state.clone();
-            simple_move_state[start_coor.0][start_coor.1] = EMPTY_SPACE_VALUE;
+/// Finds the paths of the complete captures the given player is allowed to make,
+/// leaving out the shorter ones when the maximum capture is mandatory.
+pub fn find_available_capture_paths<const BOARD_SIZE: usize>(
+    current_player_index: i32,
+    current_game_state: &Board<BOARD_SIZE>,
+    rules: &CheckersRules,
+) -> Vec<Vec<(usize, usize)>> {
+    let move_search_params =
+        get_player_specific_move_search_parameters(current_player_index, BOARD_SIZE);
+    let mut owned_pieces_info: Vec<((usize, usize), &[(i8, i8)])> = vec![];
+    fill_vector_with_current_player_owned_pieces_info_for_move_search(
+        current_game_state,
+        &move_search_params,
+        &mut owned_pieces_info,
+    );
+
+    let mut capture_paths: Vec<Vec<(usize, usize)>> = vec![];
+    for (current_coor, _) in owned_pieces_info.iter() {
+        fill_vector_with_available_capture_paths_for_piece(
+            current_game_state,
+            current_coor,
+            &move_search_params,
+            rules,
+            &mut capture_paths,
+        );
+    }
 
-            if current_game_state_space_value == single_piece_value
-                && simple_move_coor.0 as usize == double_row
-            {
-                // doublin' that piece!
-                simple_move_state[simple_move_coor.0 as usize][simple_move_coor.1 as usize] =
-                    double_piece_value;
-            } else {
-                simple_move_state[simple_move_coor.0 as usize][simple_move_coor.1 as usize] =
-                    current_game_state_space_value;
-            }
+    if rules.is_maximum_capture_mandatory {
+        // every step of a capture path captures exactly one piece
+        let max_capture_path_len = capture_paths
+            .iter()
+            .map(|path| path.len())
+            .max()
+            .unwrap_or(0);
+        capture_paths.retain(|path| path.len() == max_capture_path_len);
+    }
 
-            available_simple_move_states.push(simple_move_state);
+    if rules.is_capturing_with_king_preferred
+        && capture_paths.iter().any(|path| {
+            return current_game_state[path[0].0][path[0].1]
+                == move_search_params.double_piece_value;
+        })
+    {
+        capture_paths.retain(|path| {
+            return current_game_state[path[0].0][path[0].1]
+                == move_search_params.double_piece_value;
+        });
+    }
+
+    if rules.is_capturing_most_kings_preferred {
+        let max_captured_kings_count = capture_paths
+            .iter()
+            .map(|path| count_captured_kings(current_game_state, path))
+            .max()
+            .unwrap_or(0);
+        capture_paths.retain(|path| {
+            count_captured_kings(current_game_state, path) == max_captured_kings_count
+        });
+    }
 
-            if available_simple_move_states.len() == max_number_of_moves_to_find as usize {
-                // we have found the max number of turns we want so let's just end here
-                return;
+    return capture_paths;
+}
+
+/// Counts the kings the capture path takes, which are the kings on the diagonals between its spaces.
+fn count_captured_kings<const BOARD_SIZE: usize>(
+    current_game_state: &Board<BOARD_SIZE>,
+    capture_path: &Vec<(usize, usize)>,
+) -> usize {
+    let mut captured_kings_count = 0;
+    for step in capture_path.windows(2) {
+        let row_direction: i8 = if step[1].0 > step[0].0 { 1 } else { -1 };
+        let col_direction: i8 = if step[1].1 > step[0].1 { 1 } else { -1 };
+        let mut coor = (
+            (step[0].0 as i8 + row_direction) as usize,
+            (step[0].1 as i8 + col_direction) as usize,
+        );
+        while coor != step[1] {
+            // the capturing piece may pass over the space it left, which is the only one of its own on the way
+            let space_value = current_game_state[coor.0][coor.1];
+            if coor != capture_path[0]
+                && (space_value == FIRST_PLAYER_DOUBLE_PIECE_VALUE
+                    || space_value == SECOND_PLAYER_DOUBLE_PIECE_VALUE)
+            {
+                captured_kings_count += 1;
             }
+            coor = (
+                (coor.0 as i8 + row_direction) as usize,
+                (coor.1 as i8 + col_direction) as usize,
+            );
         }
     }
+    return captured_kings_count;
 }
 
-pub fn fill_vector_with_available_capture_move_states_for_piece(
+/// A cheaper check than finding all the available moves, as a single available move is enough.
+pub fn has_any_available_move<const BOARD_SIZE: usize>(
     current_player_index: i32,
-    current_game_state: &CheckersGameState,
+    current_game_state: &Board<BOARD_SIZE>,
+    rules: &CheckersRules,
+) -> bool {
+    let move_search_params =
+        get_player_specific_move_search_parameters(current_player_index, BOARD_SIZE);
+    let mut owned_pieces_info: Vec<((usize, usize), &[(i8, i8)])> = vec![];
+    fill_vector_with_current_player_owned_pieces_info_for_move_search(
+        current_game_state,
+        &move_search_params,
+        &mut owned_pieces_info,
+    );
+
+    // see if any simple moves exist for any pieces first since that's cheaper
+    // a single step is enough to tell, even for flying kings
+    let mut simple_move_paths: Vec<Vec<(usize, usize)>> = vec![];
+    for (current_coor, available_directions) in owned_pieces_info.iter() {
+        fill_vector_with_available_simple_move_paths_for_piece(
+            current_game_state,
+            current_coor,
+            available_directions,
+            false,
+            &mut simple_move_paths,
+        );
+        if !simple_move_paths.is_empty() {
+            return true;
+        }
+    }
+
+    // single pieces that capture backwards can be stuck moving forwards while still being able to capture
+    return !find_available_capture_paths(current_player_index, current_game_state, rules)
+        .is_empty();
+}
+
+pub fn fill_vector_with_available_simple_move_paths_for_piece<const BOARD_SIZE: usize>(
+    current_game_state: &Board<BOARD_SIZE>,
     start_coor: &(usize, usize),
     available_directions: &[(i8, i8)],
-    single_piece_value: u8,
-    double_piece_value: u8,
-    double_row: usize,
-    available_capture_move_states: &mut Vec<CheckersGameState>,
-    max_number_of_moves_to_find: i32,
+    is_flying: bool,
+    available_simple_move_paths: &mut Vec<Vec<(usize, usize)>>,
 ) {
-    let mut capture_possibilities_stack: Vec<(CheckersGameState, (usize, usize), (i8, i8))> =
-        vec![];
     for direction in available_directions.iter() {
-        capture_possibilities_stack.push((
-            current_game_state.clone(),
-            (start_coor.0, start_coor.1),
-            (direction.0, direction.1),
-        ));
+        let mut simple_move_coor = (
+            start_coor.0 as i8 + direction.0,
+            start_coor.1 as i8 + direction.1,
+        );
+
+        while is_valid_board_coordinate(BOARD_SIZE, simple_move_coor.0, simple_move_coor.1)
+            && current_game_state[simple_move_coor.0 as usize][simple_move_coor.1 as usize]
+                == EMPTY_SPACE_VALUE
+        {
+            available_simple_move_paths.push(vec![
+                *start_coor,
+                (simple_move_coor.0 as usize, simple_move_coor.1 as usize),
+            ]);
+
+            if !is_flying {
+                break;
+            }
+
+            simple_move_coor = (
+                simple_move_coor.0 + direction.0,
+                simple_move_coor.1 + direction.1,
+            );
+        }
     }
+}
 
-    let mut visited_options: HashSet<(Vec<u8>, (usize, usize), (i8, i8))> = HashSet::new();
+/// Finds the complete capture paths of a piece, meaning the ones that end once the piece has nothing left to capture.
+pub fn fill_vector_with_available_capture_paths_for_piece<const BOARD_SIZE: usize>(
+    current_game_state: &Board<BOARD_SIZE>,
+    start_coor: &(usize, usize),
+    move_search_params: &MoveSearchParameters,
+    rules: &CheckersRules,
+    available_capture_paths: &mut Vec<Vec<(usize, usize)>>,
+) {
+    // the piece leaves its space as soon as it moves, so that space is free to jump through or land on
+    let mut capture_game_state = current_game_state.clone();
+    capture_game_state[start_coor.0][start_coor.1] = EMPTY_SPACE_VALUE;
+
+    let is_double_piece =
+        current_game_state[start_coor.0][start_coor.1] == move_search_params.double_piece_value;
+    fill_vector_with_capture_paths_continuing_path(
+        &capture_game_state,
+        move_search_params,
+        rules,
+        is_double_piece,
+        &mut vec![*start_coor],
+        &mut vec![],
+        available_capture_paths,
+    );
+}
 
-    while let Some((start_game_state, move_from_coor, capture_dir)) =
-        capture_possibilities_stack.pop()
-    {
-        let serialized_start_game_state =
-            serialize_game_state(current_player_index, &start_game_state);
-        let visited_options_key = (
-            serialized_start_game_state.clone(),
-            move_from_coor,
-            capture_dir,
+fn fill_vector_with_capture_paths_continuing_path<const BOARD_SIZE: usize>(
+    capture_game_state: &Board<BOARD_SIZE>,
+    move_search_params: &MoveSearchParameters,
+    rules: &CheckersRules,
+    is_double_piece: bool,
+    path: &mut Vec<(usize, usize)>,
+    captured_piece_coors: &mut Vec<(usize, usize)>,
+    available_capture_paths: &mut Vec<Vec<(usize, usize)>>,
+) {
+    let number_of_capture_paths = available_capture_paths.len();
+    let move_from_coor = path[path.len() - 1];
+    let is_flying = is_double_piece && rules.are_kings_flying;
+    let capture_directions: &[(i8, i8)] =
+        if is_double_piece || rules.can_single_pieces_capture_backwards {
+            &move_search_params.double_piece_available_directions
+        } else {
+            &move_search_params.single_piece_available_directions
+        };
+
+    for capture_dir in capture_directions.iter() {
+        // flying kings may capture a piece from afar, everything else captures the piece next to it
+        let mut captured_coor = (
+            move_from_coor.0 as i8 + capture_dir.0,
+            move_from_coor.1 as i8 + capture_dir.1,
         );
-        if visited_options.contains(&visited_options_key) {
-            // already visited this state and explored it
-            continue;
+        while is_flying
+            && is_valid_board_coordinate(BOARD_SIZE, captured_coor.0, captured_coor.1)
+            && capture_game_state[captured_coor.0 as usize][captured_coor.1 as usize]
+                == EMPTY_SPACE_VALUE
+        {
+            captured_coor = (
+                captured_coor.0 + capture_dir.0,
+                captured_coor.1 + capture_dir.1,
+            );
         }
-        visited_options.insert(visited_options_key);
 
-        if !is_valid_board_coordinate(
-            move_from_coor.0 as i8 + (capture_dir.0 * 2),
-            move_from_coor.1 as i8 + (capture_dir.1 * 2),
-        ) {
-            // not a valid jump as it would go out of bounds
+        if !is_valid_board_coordinate(BOARD_SIZE, captured_coor.0, captured_coor.1) {
             continue;
         }
 
-        let captured_piece_coor = (
-            (move_from_coor.0 as i8 + capture_dir.0) as usize,
-            (move_from_coor.1 as i8 + capture_dir.1) as usize,
-        );
+        let captured_piece_coor = (captured_coor.0 as usize, captured_coor.1 as usize);
         let captured_piece_space_value =
-            current_game_state[captured_piece_coor.0][captured_piece_coor.1];
-        if captured_piece_space_value == 0
-            || captured_piece_space_value == single_piece_value
-            || captured_piece_space_value == double_piece_value
+            capture_game_state[captured_piece_coor.0][captured_piece_coor.1];
+        if captured_piece_space_value == EMPTY_SPACE_VALUE
+            || captured_piece_space_value == move_search_params.single_piece_value
+            || captured_piece_space_value == move_search_params.double_piece_value
         {
             // not a valid jump as you jump over your own pieces or empty spaces
             continue;
         }
 
-        let capture_move_space_coor = (
-            (move_from_coor.0 as i8 + (capture_dir.0 * 2)) as usize,
-            (move_from_coor.1 as i8 + (capture_dir.1 * 2)) as usize,
-        );
-        let capture_move_space_value =
-            start_game_state[capture_move_space_coor.0][capture_move_space_coor.1];
-        if capture_move_space_value > 0 {
-            // not a valid jump as the space two ahead is not empty
+        if captured_piece_coors.contains(&captured_piece_coor) {
+            // captured pieces are only taken off the board once the move is over,
+            // so they can't be jumped over a second time
             continue;
         }
 
-        // a capture is possible!
-        let mut capture_move_state = start_game_state.clone();
-        capture_move_state[move_from_coor.0][move_from_coor.1] = EMPTY_SPACE_VALUE;
-        capture_move_state[captured_piece_coor.0][captured_piece_coor.1] = EMPTY_SPACE_VALUE;
+        if !is_double_piece
+            && !rules.can_single_pieces_capture_kings
+            && (captured_piece_space_value == FIRST_PLAYER_DOUBLE_PIECE_VALUE
+                || captured_piece_space_value == SECOND_PLAYER_DOUBLE_PIECE_VALUE)
+        {
+            continue;
+        }
 
-        let start_game_state_space_value = start_game_state[move_from_coor.0][move_from_coor.1];
-        if start_game_state_space_value == single_piece_value
-            && capture_move_space_coor.0 == double_row
+        let mut capture_move_coor = (
+            captured_coor.0 + capture_dir.0,
+            captured_coor.1 + capture_dir.1,
+        );
+        while is_valid_board_coordinate(BOARD_SIZE, capture_move_coor.0, capture_move_coor.1)
+            && capture_game_state[capture_move_coor.0 as usize][capture_move_coor.1 as usize]
+                == EMPTY_SPACE_VALUE
         {
-            // doublin' that piece!
-            capture_move_state[capture_move_space_coor.0][capture_move_space_coor.1] =
-                double_piece_value;
-            // when doubling a piece, the turn ends there - no further jumps allowed
-        } else {
-            capture_move_state[capture_move_space_coor.0 as usize]
-                [capture_move_space_coor.1 as usize] = start_game_state_space_value;
-
-            // now let's explore for multi-jump possibilities by pushing them onto the stack
-            for next_jump_direction in available_directions {
-                capture_possibilities_stack.push((
-                    capture_move_state.clone(),
-                    capture_move_space_coor,
-                    (next_jump_direction.0, next_jump_direction.1),
-                ));
+            // a capture is possible!
+            let capture_move_space_coor =
+                (capture_move_coor.0 as usize, capture_move_coor.1 as usize);
+            let is_continuing_as_double_piece = is_double_piece
+                || (rules.is_promotion_mid_capture_continuing
+                    && capture_move_space_coor.0 == move_search_params.double_row);
+
+            path.push(capture_move_space_coor);
+            captured_piece_coors.push(captured_piece_coor);
+            fill_vector_with_capture_paths_continuing_path(
+                capture_game_state,
+                move_search_params,
+                rules,
+                is_continuing_as_double_piece,
+                path,
+                captured_piece_coors,
+                available_capture_paths,
+            );
+            path.pop();
+            captured_piece_coors.pop();
+
+            if !is_flying {
+                break;
             }
+
+            capture_move_coor = (
+                capture_move_coor.0 + capture_dir.0,
+                capture_move_coor.1 + capture_dir.1,
+            );
         }
+    }
 
-        available_capture_move_states.push(capture_move_state);
+    // the path made of the start space alone isn't a capture
+    if available_capture_paths.len() == number_of_capture_paths && path.len() > 1 {
+        // nothing left to capture, so the move ends here
+        available_capture_paths.push(path.clone());
+    }
+}
 
-        if available_capture_move_states.len() == max_number_of_moves_to_find as usize {
-            // we have found the max number of turns we want so let's just end here
-            return;
+/// Moves the piece along the given path, capturing every piece it jumps over.
+/// A single piece is doubled once the move ends on the far row,
+/// or as soon as it passes through the far row when promotion in the middle of a capture continues the capture.
+pub fn perform_move<const BOARD_SIZE: usize>(
+    current_player_index: i32,
+    current_game_state: &Board<BOARD_SIZE>,
+    rules: &CheckersRules,
+    path: &Vec<(usize, usize)>,
+) -> Board<BOARD_SIZE> {
+    let move_search_params =
+        get_player_specific_move_search_parameters(current_player_index, BOARD_SIZE);
+    let mut next_game_state = current_game_state.clone();
+
+    let start_coor = path[0];
+    let mut piece_value = next_game_state[start_coor.0][start_coor.1];
+    next_game_state[start_coor.0][start_coor.1] = EMPTY_SPACE_VALUE;
+
+    for step in path.windows(2) {
+        let step_dir = (
+            (step[1].0 as i8 - step[0].0 as i8).signum(),
+            (step[1].1 as i8 - step[0].1 as i8).signum(),
+        );
+        let mut passed_coor = (step[0].0 as i8 + step_dir.0, step[0].1 as i8 + step_dir.1);
+        while passed_coor != (step[1].0 as i8, step[1].1 as i8) {
+            next_game_state[passed_coor.0 as usize][passed_coor.1 as usize] = EMPTY_SPACE_VALUE;
+            passed_coor = (passed_coor.0 + step_dir.0, passed_coor.1 + step_dir.1);
         }
     }
+
+    let end_coor = path[path.len() - 1];
+    let is_reaching_double_row = if rules.is_promotion_mid_capture_continuing {
+        path[1..]
+            .iter()
+            .any(|coor| coor.0 == move_search_params.double_row)
+    } else {
+        end_coor.0 == move_search_params.double_row
+    };
+    if piece_value == move_search_params.single_piece_value && is_reaching_double_row {
+        // doublin' that piece!
+        piece_value = move_search_params.double_piece_value;
+    }
+    next_game_state[end_coor.0][end_coor.1] = piece_value;
+
+    return next_game_state;
 }
 
 pub fn get_player_specific_move_search_parameters(
     current_player_index: i32,
+    board_size: usize,
 ) -> MoveSearchParameters {
     let forward_row_direction: i8 = if current_player_index == 0 { 1 } else { -1 };
 
@@ -227,54 +465,62 @@ pub fn get_player_specific_move_search_parameters(
             (-forward_row_direction, -1 as i8),
         ],
         double_row: if current_player_index == 0 {
-            MAX_ROW as usize
+            board_size - 1
         } else {
             0
         },
     };
 }
 
-pub fn is_valid_board_coordinate(row: i8, col: i8) -> bool {
-    return row >= 0 && row <= MAX_ROW && col >= 0 && col <= MAX_COL;
-}
+/// A move makes progress when it captures a piece or moves a single piece, as neither can ever be undone.
+pub fn is_progress_move<const BOARD_SIZE: usize>(
+    previous_game_state: &Board<BOARD_SIZE>,
+    next_game_state: &Board<BOARD_SIZE>,
+) -> bool {
+    let is_single_piece = |space_value: u8| -> bool {
+        return space_value == FIRST_PLAYER_SINGLE_PIECE_VALUE
+            || space_value == SECOND_PLAYER_SINGLE_PIECE_VALUE;
+    };
+
+    let mut number_of_pieces_difference: i32 = 0;
+    for row in 0..previous_game_state.len() {
+        for col in 0..previous_game_state[row].len() {
+            let previous_space_value = previous_game_state[row][col];
+            let next_space_value = next_game_state[row][col];
+            if previous_space_value == next_space_value {
+                continue;
+            }
 
-pub fn serialize_game_state(
-    responsible_player_index: i32,
-    game_state: &CheckersGameState,
-) -> Vec<u8> {
-    let mut number_of_pieces: u8 = 0;
-    let mut serialized_game_state = vec![0 as u8];
-
-    for i in 0..game_state.len() {
-        for j in 0..game_state[i].len() {
-            let position_value = game_state[i][j];
-            if position_value != EMPTY_SPACE_VALUE {
-                number_of_pieces += 1;
-
-                let mut piece_byte = (i * game_state.len() + j) as u8;
-
-                match position_value {
-                    FIRST_PLAYER_SINGLE_PIECE_VALUE => (), // first two bits stay 0, no point in doing an operation here
-                    FIRST_PLAYER_DOUBLE_PIECE_VALUE => piece_byte ^= 0b01_00_00_00,
-                    SECOND_PLAYER_SINGLE_PIECE_VALUE => piece_byte ^= 0b10_00_00_00,
-                    SECOND_PLAYER_DOUBLE_PIECE_VALUE => piece_byte ^= 0b11_00_00_00,
-                    _ => panic!(
-                        "Encountered illegal position value {} at position ({}, {})",
-                        position_value, i, j
-                    ),
-                }
-
-                serialized_game_state.push(piece_byte);
+            if is_single_piece(previous_space_value) || is_single_piece(next_space_value) {
+                return true;
+            }
+
+            if previous_space_value != EMPTY_SPACE_VALUE {
+                number_of_pieces_difference -= 1;
+            }
+            if next_space_value != EMPTY_SPACE_VALUE {
+                number_of_pieces_difference += 1;
             }
         }
     }
 
-    let responsible_player_byte = if responsible_player_index == -1 {
-        0b11_0_00000
-    } else {
-        (responsible_player_index as u8) << 6
-    };
-    serialized_game_state[0] = responsible_player_byte + number_of_pieces;
+    return number_of_pieces_difference != 0;
+}
+
+/// Numbers the playable spaces of a board of the given size row by row, from 0 to 31 on the checkers board.
+pub fn get_playable_space_index(board_size: usize, row: usize, col: usize) -> usize {
+    // Only every other space of a row is playable, so halving the column index keeps the indices dense
+    return row * (board_size / 2) + col / 2;
+}
+
+/// The (row, col) coordinate of a playable space of a board of the given size.
+pub fn get_playable_space_coor(board_size: usize, playable_space_index: usize) -> (usize, usize) {
+    let row = playable_space_index / (board_size / 2);
+    // the playable spaces of even rows are the odd columns and vice versa
+    let col = (playable_space_index % (board_size / 2)) * 2 + (1 - row % 2);
+    return (row, col);
+}
 
-    return serialized_game_state;
+pub fn is_valid_board_coordinate(board_size: usize, row: i8, col: i8) -> bool {
+    return row >= 0 && (row as usize) < board_size && col >= 0 && (col as usize) < board_size;
 }
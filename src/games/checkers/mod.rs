@@ -1,7 +1,8 @@
-mod byte_array_game_state_serialization;
-pub use byte_array_game_state_serialization::{
-    ByteArrayGameStateDeserializer, ByteArrayGameStateSerializer,
-};
+mod action;
+pub use action::Action;
+
+mod checkers_rules;
+pub use checkers_rules::CheckersRules;
 
 mod cli_game_state_formatter;
 pub use cli_game_state_formatter::CLIGameStateFormatter;
@@ -9,18 +10,48 @@ pub use cli_game_state_formatter::CLIGameStateFormatter;
 mod create_initial_game_state;
 pub use create_initial_game_state::create_initial_game_state;
 
+#[cfg(feature = "torch")]
+mod find_move_policy_index;
+#[cfg(feature = "torch")]
+pub use find_move_policy_index::{find_move_policy_index, MOVE_POLICY_SIZE};
+
+mod fixed_width_game_state_serialization;
+pub use fixed_width_game_state_serialization::{
+    FixedWidthGameStateDeserializer, FixedWidthGameStateSerializer,
+};
+
+mod game_definition;
+pub use game_definition::GameDefinition;
+
 mod game_state_type;
 pub use game_state_type::GameStateType;
 
 mod game_rules_authority;
 pub use game_rules_authority::GameRulesAuthority;
 
+mod game_state_symmetries_finder;
+pub use game_state_symmetries_finder::GameStateSymmetriesFinder;
+
 mod internal;
+pub(crate) use internal::{
+    get_playable_space_coor, get_playable_space_index, EMPTY_SPACE_VALUE,
+    FIRST_PLAYER_DOUBLE_PIECE_VALUE, FIRST_PLAYER_SINGLE_PIECE_VALUE,
+    SECOND_PLAYER_DOUBLE_PIECE_VALUE, SECOND_PLAYER_SINGLE_PIECE_VALUE,
+};
+
+#[cfg(feature = "torch")]
+mod policy_value_torch_net;
+#[cfg(feature = "torch")]
+pub use policy_value_torch_net::PolicyValueTorchNet;
 
+#[cfg(feature = "torch")]
 mod torch_net;
+#[cfg(feature = "torch")]
 pub use torch_net::TorchNet;
 
+#[cfg(feature = "torch")]
 mod transform_game_state_to_tensor;
+#[cfg(feature = "torch")]
 pub use transform_game_state_to_tensor::transform_game_state_to_tensor;
 
 mod user_input_game_state_creator;
@@ -1,5 +1,7 @@
 use tch::{nn, Tensor};
 
+/// A value network taking a batch of game state tensors of shape [N, 5, 8, 8] (a single [5, 8, 8] tensor is a batch of one)
+/// and producing a value of shape [N, 1] for each of them.
 #[derive(Debug)]
 pub struct TorchNet {
     conv1: nn::Conv2D,
@@ -10,7 +12,6 @@ pub struct TorchNet {
 }
 
 impl TorchNet {
-    #[allow(dead_code)]
     pub fn new(vs: &nn::Path) -> TorchNet {
         return TorchNet {
             conv1: nn::conv2d(vs, 5, 32, 3, Default::default()),
@@ -25,14 +26,14 @@ impl TorchNet {
 impl nn::Module for TorchNet {
     fn forward(&self, tensor: &Tensor) -> Tensor {
         return tensor
-            .view([1, -1, 8, 8])
+            .view([-1, 5, 8, 8])
             .apply(&self.conv1)
             .relu()
             .apply(&self.conv2)
             .relu()
             .apply(&self.conv3)
             .relu()
-            .view([1, -1])
+            .view([-1, 256])
             .apply(&self.fc1)
             .relu()
             .apply(&self.fc2);
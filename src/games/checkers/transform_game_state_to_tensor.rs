@@ -6,7 +6,6 @@ const ROW_LENGTH: usize = 8;
 const COL_LENGTH: usize = 8;
 const BOARD_SIZE: usize = ROW_LENGTH * COL_LENGTH;
 
-#[allow(dead_code)]
 pub fn transform_game_state_to_tensor(
     responsible_player_index: i32,
     game_state: &CheckersGameState,
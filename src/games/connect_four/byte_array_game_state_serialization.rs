@@ -0,0 +1,109 @@
+use crate::games::connect_four::internal::*;
+use crate::games::connect_four::GameStateType as ConnectFourGameState;
+use crate::traits::{GameStateDeserializer, GameStateSerializer};
+
+/// Each state hashes to 7 bytes, which hold the 49 bits of the first player's bitboard
+/// plus the occupied bitboard and the bottom row bitboard.
+/// Adding the bottom row to the occupied bitboard leaves a single set bit at the top of each column's stack of discs,
+/// and since the first player's discs all lie below that bit, the sum tells apart every possible game state.
+/// The responsible player isn't part of the encoding, but it can be told from the number of discs of each player.
+pub struct ByteArrayGameStateSerializer {}
+
+impl GameStateSerializer<ConnectFourGameState, Vec<u8>> for ByteArrayGameStateSerializer {
+    fn serialize_game_state(
+        &self,
+        _responsible_player_index: i32,
+        game_state: &ConnectFourGameState,
+    ) -> Vec<u8> {
+        let key = game_state.player_bitboards[0]
+            + find_occupied_bitboard(game_state)
+            + BOTTOM_ROW_BITBOARD;
+
+        return key.to_be_bytes()[1..].to_vec();
+    }
+}
+
+pub struct ByteArrayGameStateDeserializer {}
+
+impl GameStateDeserializer<ConnectFourGameState, Vec<u8>> for ByteArrayGameStateDeserializer {
+    fn deserialize_game_state(
+        &self,
+        serialized_game_state: &Vec<u8>,
+    ) -> (i32, ConnectFourGameState) {
+        if serialized_game_state.len() != 7 {
+            panic!("Cannot deserialize invalid serialized connect four game state - expected a total of 7 bytes, got {}.", serialized_game_state.len());
+        }
+
+        let mut key_bytes = [0; 8];
+        key_bytes[1..].copy_from_slice(serialized_game_state);
+        let key = u64::from_be_bytes(key_bytes);
+
+        let mut game_state = ConnectFourGameState {
+            player_bitboards: [0, 0],
+        };
+        for column in 0..NUMBER_OF_COLUMNS {
+            let column_key = (key >> (column * COLUMN_BITS)) & ((1 << COLUMN_BITS) - 1);
+            // The highest set bit of the column marks the top of its stack of discs
+            let column_height = 63 - column_key.leading_zeros();
+            let column_occupied_bitboard = (1 << column_height) - 1;
+            let column_first_player_bitboard = column_key - (1 << column_height);
+
+            game_state.player_bitboards[0] |=
+                column_first_player_bitboard << (column * COLUMN_BITS);
+            game_state.player_bitboards[1] |=
+                (column_occupied_bitboard ^ column_first_player_bitboard) << (column * COLUMN_BITS);
+        }
+
+        let first_player_discs_count = game_state.player_bitboards[0].count_ones();
+        let second_player_discs_count = game_state.player_bitboards[1].count_ones();
+        let responsible_player_index = if first_player_discs_count == 0 {
+            -1
+        } else if first_player_discs_count > second_player_discs_count {
+            0
+        } else {
+            1
+        };
+
+        return (responsible_player_index, game_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::connect_four::{create_initial_game_state, GameRulesAuthority};
+    use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+    #[test]
+    fn test_for_invertibility() {
+        let serializer = ByteArrayGameStateSerializer {};
+        let deserializer = ByteArrayGameStateDeserializer {};
+        let game_rules_authority = GameRulesAuthority {};
+
+        // Fill the board column by column, going through states with empty, partly filled and full columns
+        let mut responsible_player_index = -1;
+        let mut game_state = create_initial_game_state();
+        let mut test_game_states: Vec<(i32, ConnectFourGameState)> =
+            vec![(responsible_player_index, game_state)];
+        for turn_index in 0..(NUMBER_OF_COLUMNS * NUMBER_OF_ROWS) as usize {
+            responsible_player_index = (turn_index % 2) as i32;
+            game_state = game_rules_authority
+                .find_available_next_game_states(responsible_player_index, &game_state)
+                .remove(0);
+            test_game_states.push((responsible_player_index, game_state));
+        }
+
+        for (responsible_player_index, game_state) in test_game_states.iter() {
+            let (deserialized_responsible_player_index, deserialized_game_state) = deserializer
+                .deserialize_game_state(
+                    &serializer.serialize_game_state(*responsible_player_index, game_state),
+                );
+
+            assert_eq!(
+                *responsible_player_index,
+                deserialized_responsible_player_index
+            );
+            assert_eq!(*game_state, deserialized_game_state);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::games::connect_four::internal::*;
+use crate::games::connect_four::GameStateType as ConnectFourGameState;
+use crate::traits::CLIGameStateFormatter as TCLIGameStateFormatter;
+
+pub struct CLIGameStateFormatter {}
+
+impl TCLIGameStateFormatter<ConnectFourGameState> for CLIGameStateFormatter {
+    fn format_game_state_for_cli(&self, game_state: &ConnectFourGameState) -> String {
+        let mut string_pieces: Vec<String> = vec![];
+
+        for row in (0..NUMBER_OF_ROWS).rev() {
+            for column in 0..NUMBER_OF_COLUMNS {
+                let disc_bit = find_disc_bit(column, row);
+                string_pieces.push(String::from(
+                    if game_state.player_bitboards[0] & disc_bit != 0 {
+                        "|x"
+                    } else if game_state.player_bitboards[1] & disc_bit != 0 {
+                        "|o"
+                    } else {
+                        "| "
+                    },
+                ));
+            }
+            string_pieces.push(String::from("|\n"));
+        }
+
+        string_pieces.push(String::from(" 0 1 2 3 4 5 6\n"));
+
+        return string_pieces.join("");
+    }
+}
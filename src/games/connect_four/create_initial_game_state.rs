@@ -0,0 +1,7 @@
+use crate::games::connect_four::GameStateType as ConnectFourGameState;
+
+pub fn create_initial_game_state() -> ConnectFourGameState {
+    return ConnectFourGameState {
+        player_bitboards: [0, 0],
+    };
+}
@@ -0,0 +1,66 @@
+use crate::games::connect_four::{
+    create_initial_game_state, ByteArrayGameStateDeserializer, ByteArrayGameStateSerializer,
+    CLIGameStateFormatter, GameRulesAuthority, GameStateType as ConnectFourGameState,
+    UserInputGameStateCreator,
+};
+use crate::traits::{
+    CLIGameStateFormatter as TCLIGameStateFormatter, GameDefinition as TGameDefinition,
+    GameRulesAuthority as TGameRulesAuthority, GameStateDeserializer, GameStateSerializer,
+    UserInputGameStateCreator as TUserInputGameStateCreator,
+};
+
+pub struct GameDefinition {
+    cli_game_state_formatter: CLIGameStateFormatter,
+    game_rules_authority: GameRulesAuthority,
+    game_state_deserializer: ByteArrayGameStateDeserializer,
+    game_state_serializer: ByteArrayGameStateSerializer,
+    user_input_game_state_creator: UserInputGameStateCreator,
+}
+
+impl GameDefinition {
+    pub fn new() -> GameDefinition {
+        return GameDefinition {
+            cli_game_state_formatter: CLIGameStateFormatter {},
+            game_rules_authority: GameRulesAuthority {},
+            game_state_deserializer: ByteArrayGameStateDeserializer {},
+            game_state_serializer: ByteArrayGameStateSerializer {},
+            user_input_game_state_creator: UserInputGameStateCreator {},
+        };
+    }
+}
+
+impl TGameDefinition<ConnectFourGameState, Vec<u8>> for GameDefinition {
+    fn get_name(&self) -> &str {
+        return "connect-four";
+    }
+
+    fn create_initial_game_state(&self) -> ConnectFourGameState {
+        return create_initial_game_state();
+    }
+
+    fn get_cli_game_state_formatter(&self) -> &dyn TCLIGameStateFormatter<ConnectFourGameState> {
+        return &self.cli_game_state_formatter;
+    }
+
+    fn get_game_rules_authority(&self) -> &(dyn TGameRulesAuthority<ConnectFourGameState> + Sync) {
+        return &self.game_rules_authority;
+    }
+
+    fn get_game_state_deserializer(
+        &self,
+    ) -> &(dyn GameStateDeserializer<ConnectFourGameState, Vec<u8>> + Sync) {
+        return &self.game_state_deserializer;
+    }
+
+    fn get_game_state_serializer(
+        &self,
+    ) -> &(dyn GameStateSerializer<ConnectFourGameState, Vec<u8>> + Sync) {
+        return &self.game_state_serializer;
+    }
+
+    fn get_user_input_game_state_creator(
+        &self,
+    ) -> &dyn TUserInputGameStateCreator<ConnectFourGameState, String> {
+        return &self.user_input_game_state_creator;
+    }
+}
@@ -0,0 +1,127 @@
+use crate::games::connect_four::internal::*;
+use crate::games::connect_four::GameStateType as ConnectFourGameState;
+use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+pub struct GameRulesAuthority {}
+
+impl TGameRulesAuthority<ConnectFourGameState> for GameRulesAuthority {
+    fn analyze_game_state_for_terminality(
+        &self,
+        game_state: &ConnectFourGameState,
+        _next_player_index: i32,
+    ) -> Option<i32> {
+        for player_index in 0..2 {
+            if has_four_in_a_row(game_state.player_bitboards[player_index]) {
+                return Some(player_index as i32);
+            }
+        }
+
+        if find_occupied_bitboard(game_state) == FULL_BOARD_BITBOARD {
+            return Some(-1);
+        }
+
+        return None;
+    }
+
+    fn find_available_next_game_states(
+        &self,
+        current_player_index: i32,
+        current_game_state: &ConnectFourGameState,
+    ) -> Vec<ConnectFourGameState> {
+        let mut available_next_game_states: Vec<ConnectFourGameState> = vec![];
+        let occupied_bitboard = find_occupied_bitboard(current_game_state);
+
+        for column in 0..NUMBER_OF_COLUMNS {
+            let column_height = find_column_height(occupied_bitboard, column);
+            if column_height < NUMBER_OF_ROWS {
+                let mut available_next_game_state = *current_game_state;
+                available_next_game_state.player_bitboards[current_player_index as usize] |=
+                    find_disc_bit(column, column_height);
+                available_next_game_states.push(available_next_game_state);
+            }
+        }
+
+        return available_next_game_states;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a game state with the discs of each player at the given (column, row) positions.
+    fn create_game_state(players_discs: [&[(u32, u32)]; 2]) -> ConnectFourGameState {
+        let mut player_bitboards = [0; 2];
+        for player_index in 0..2 {
+            for (column, row) in players_discs[player_index].iter() {
+                player_bitboards[player_index] |= find_disc_bit(*column, *row);
+            }
+        }
+
+        return ConnectFourGameState {
+            player_bitboards: player_bitboards,
+        };
+    }
+
+    #[test]
+    fn test_four_in_a_row_in_every_direction() {
+        let game_rules_authority = GameRulesAuthority {};
+
+        let vertical_game_state =
+            create_game_state([&[(3, 0), (3, 1), (3, 2), (3, 3)], &[(2, 0), (4, 0), (2, 1)]]);
+        assert_eq!(
+            game_rules_authority.analyze_game_state_for_terminality(&vertical_game_state, 1),
+            Some(0)
+        );
+
+        let horizontal_game_state = create_game_state([
+            &[(2, 1), (3, 1), (4, 1), (0, 0), (6, 0)],
+            &[(2, 0), (3, 0), (4, 0), (5, 0)],
+        ]);
+        assert_eq!(
+            game_rules_authority.analyze_game_state_for_terminality(&horizontal_game_state, 0),
+            Some(1)
+        );
+
+        let ascending_diagonal_game_state = create_game_state([
+            &[(0, 0), (1, 1), (2, 2), (3, 3), (2, 0), (3, 1)],
+            &[(1, 0), (2, 1), (3, 0), (3, 2), (5, 0)],
+        ]);
+        assert_eq!(
+            game_rules_authority
+                .analyze_game_state_for_terminality(&ascending_diagonal_game_state, 1),
+            Some(0)
+        );
+
+        let descending_diagonal_game_state = create_game_state([
+            &[(6, 0), (5, 1), (4, 2), (3, 3), (0, 0), (5, 0), (4, 1)],
+            &[(6, 1), (5, 2), (4, 0), (3, 0), (3, 1), (3, 2)],
+        ]);
+        assert_eq!(
+            game_rules_authority
+                .analyze_game_state_for_terminality(&descending_diagonal_game_state, 1),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_three_in_a_row_not_winning() {
+        let game_rules_authority = GameRulesAuthority {};
+
+        let game_state = create_game_state([&[(0, 0), (1, 0), (2, 0)], &[(0, 1), (1, 1), (2, 1)]]);
+        assert_eq!(
+            game_rules_authority.analyze_game_state_for_terminality(&game_state, 0),
+            None
+        );
+
+        // the top three discs of a column and the bottom disc of the next one are kept apart by the spare bit between them
+        let wrapping_game_state = create_game_state([
+            &[(0, 3), (0, 4), (0, 5), (1, 0)],
+            &[(0, 0), (0, 1), (0, 2), (6, 0)],
+        ]);
+        assert_eq!(
+            game_rules_authority.analyze_game_state_for_terminality(&wrapping_game_state, 1),
+            None
+        );
+    }
+}
@@ -0,0 +1,11 @@
+use crate::traits::BasicGameState;
+
+/// The discs of each player as bitboards, indexed by player index.
+/// The disc in a given column and row (row 0 being the bottom one) is the bit at index column * 7 + row.
+/// The top bit of every column is always left unset so that lines of discs can't wrap around from one column to the next.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GameStateType {
+    pub player_bitboards: [u64; 2],
+}
+
+impl BasicGameState for GameStateType {}
@@ -0,0 +1,40 @@
+use crate::games::connect_four::GameStateType as ConnectFourGameState;
+
+pub const NUMBER_OF_COLUMNS: u32 = 7;
+pub const NUMBER_OF_ROWS: u32 = 6;
+/// Every column takes up one more bit than it has rows, see GameStateType.
+pub const COLUMN_BITS: u32 = NUMBER_OF_ROWS + 1;
+
+/// The bits of the bottom row of every column.
+pub const BOTTOM_ROW_BITBOARD: u64 = 0b0000001_0000001_0000001_0000001_0000001_0000001_0000001;
+/// The bits of all the playable rows of every column.
+pub const FULL_BOARD_BITBOARD: u64 = BOTTOM_ROW_BITBOARD * ((1 << NUMBER_OF_ROWS) - 1);
+
+pub fn find_occupied_bitboard(game_state: &ConnectFourGameState) -> u64 {
+    return game_state.player_bitboards[0] | game_state.player_bitboards[1];
+}
+
+pub fn find_disc_bit(column: u32, row: u32) -> u64 {
+    return 1 << (column * COLUMN_BITS + row);
+}
+
+/// Returns the row the next disc dropped in the given column lands on,
+/// which is the number of rows when the column is full.
+pub fn find_column_height(occupied_bitboard: u64, column: u32) -> u32 {
+    let column_bitboard =
+        (occupied_bitboard >> (column * COLUMN_BITS)) & ((1 << NUMBER_OF_ROWS) - 1);
+    return column_bitboard.count_ones();
+}
+
+/// Checks for four discs in a row vertically, horizontally and along both diagonals.
+/// Shifting by 1 moves a disc up a row, by 7 over a column and by 6 and 8 along the diagonals.
+pub fn has_four_in_a_row(player_bitboard: u64) -> bool {
+    for shift in [1, COLUMN_BITS, COLUMN_BITS - 1, COLUMN_BITS + 1].iter() {
+        let pairs_bitboard = player_bitboard & (player_bitboard >> shift);
+        if pairs_bitboard & (pairs_bitboard >> (2 * shift)) != 0 {
+            return true;
+        }
+    }
+
+    return false;
+}
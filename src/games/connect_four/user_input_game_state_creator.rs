@@ -0,0 +1,29 @@
+use crate::games::connect_four::internal::*;
+use crate::games::connect_four::GameStateType as ConnectFourGameState;
+use crate::traits::UserInputGameStateCreator as TUserInputGameStateCreator;
+
+pub struct UserInputGameStateCreator {}
+
+impl TUserInputGameStateCreator<ConnectFourGameState, String> for UserInputGameStateCreator {
+    fn create_new_game_state_from_user_input(
+        &self,
+        current_player_index: i32,
+        current_game_state: &ConnectFourGameState,
+        user_input: String,
+    ) -> Result<ConnectFourGameState, String> {
+        let column: u32 = match user_input.trim().parse() {
+            Ok(column) if column < NUMBER_OF_COLUMNS => column,
+            _ => return Err(String::from("Invalid input format. Valid format is the 0-based index of a column from the left.")),
+        };
+
+        let column_height = find_column_height(find_occupied_bitboard(current_game_state), column);
+        if column_height == NUMBER_OF_ROWS {
+            return Err(String::from("That column is already full!"));
+        }
+
+        let mut new_state = *current_game_state;
+        new_state.player_bitboards[current_player_index as usize] |=
+            find_disc_bit(column, column_height);
+        return Ok(new_state);
+    }
+}
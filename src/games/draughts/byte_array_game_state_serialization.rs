@@ -0,0 +1,156 @@
+use crate::games::checkers::{
+    get_playable_space_coor, get_playable_space_index, EMPTY_SPACE_VALUE,
+    FIRST_PLAYER_DOUBLE_PIECE_VALUE, FIRST_PLAYER_SINGLE_PIECE_VALUE,
+    SECOND_PLAYER_DOUBLE_PIECE_VALUE, SECOND_PLAYER_SINGLE_PIECE_VALUE,
+};
+use crate::games::draughts::GameStateType as DraughtsGameState;
+use crate::traits::{GameStateDeserializer, GameStateSerializer};
+
+/// Serializes a game state to one byte for every piece on the board after a header byte, like the checkers serializer does.
+/// - The first byte tracks the player who last moved in the two left-most bits (11 for no player responsible)
+/// and the number of pieces on the board in the six right-most bits (there are at most 40 of them)
+/// - Each piece byte uses its first two bits to represent the piece type:
+/// 00 for first player standard, 01 for first player double, 10 for second player standard and 11 for second player double
+/// - The other six bits of a piece byte hold the index of its playable space.
+/// Only the 50 playable spaces are numbered since the 100 spaces of the board wouldn't fit in six bits.
+pub struct ByteArrayGameStateSerializer {}
+
+impl GameStateSerializer<DraughtsGameState, Vec<u8>> for ByteArrayGameStateSerializer {
+    fn serialize_game_state(
+        &self,
+        responsible_player_index: i32,
+        game_state: &DraughtsGameState,
+    ) -> Vec<u8> {
+        let mut number_of_pieces: u8 = 0;
+        let mut serialized_game_state = vec![0 as u8];
+
+        for row in 0..game_state.len() {
+            for col in 0..game_state[row].len() {
+                let position_value = game_state[row][col];
+                if position_value == EMPTY_SPACE_VALUE {
+                    continue;
+                }
+
+                number_of_pieces += 1;
+
+                let piece_type_bits: u8 = match position_value {
+                    FIRST_PLAYER_SINGLE_PIECE_VALUE => 0b00_000000,
+                    FIRST_PLAYER_DOUBLE_PIECE_VALUE => 0b01_000000,
+                    SECOND_PLAYER_SINGLE_PIECE_VALUE => 0b10_000000,
+                    SECOND_PLAYER_DOUBLE_PIECE_VALUE => 0b11_000000,
+                    _ => panic!(
+                        "Encountered illegal position value {} at position ({}, {})",
+                        position_value, row, col
+                    ),
+                };
+
+                serialized_game_state.push(
+                    piece_type_bits + get_playable_space_index(game_state.len(), row, col) as u8,
+                );
+            }
+        }
+
+        let responsible_player_byte = if responsible_player_index == -1 {
+            0b11_000000
+        } else {
+            (responsible_player_index as u8) << 6
+        };
+        serialized_game_state[0] = responsible_player_byte + number_of_pieces;
+
+        return serialized_game_state;
+    }
+}
+
+pub struct ByteArrayGameStateDeserializer {}
+
+impl GameStateDeserializer<DraughtsGameState, Vec<u8>> for ByteArrayGameStateDeserializer {
+    fn deserialize_game_state(&self, serialized_game_state: &Vec<u8>) -> (i32, DraughtsGameState) {
+        let first_byte = serialized_game_state[0];
+        let responsible_player_bits = first_byte >> 6;
+        let number_of_pieces = first_byte & 0b00_111111;
+        if serialized_game_state.len() != (number_of_pieces + 1) as usize {
+            panic!("Cannot deserialize invalid serialized draughts game state - expected a total of {} bytes, got {}.", number_of_pieces + 1, serialized_game_state.len());
+        }
+
+        let responsible_player_index = if responsible_player_bits == 0b000000_11 {
+            -1
+        } else {
+            responsible_player_bits as i32
+        };
+
+        let mut game_state: DraughtsGameState = [[EMPTY_SPACE_VALUE; 10]; 10];
+
+        for piece_byte in serialized_game_state[1..].iter() {
+            let (row, col) =
+                get_playable_space_coor(game_state.len(), (piece_byte & 0b00_111111) as usize);
+
+            game_state[row][col] = match piece_byte >> 6 {
+                0 => FIRST_PLAYER_SINGLE_PIECE_VALUE,
+                1 => FIRST_PLAYER_DOUBLE_PIECE_VALUE,
+                2 => SECOND_PLAYER_SINGLE_PIECE_VALUE,
+                _ => SECOND_PLAYER_DOUBLE_PIECE_VALUE,
+            };
+        }
+
+        return (responsible_player_index, game_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::draughts::create_initial_game_state;
+
+    #[test]
+    fn test_for_invertibility() {
+        let serializer = ByteArrayGameStateSerializer {};
+        let deserializer = ByteArrayGameStateDeserializer {};
+
+        let test_game_states: Vec<(i32, DraughtsGameState)> = vec![
+            (-1, create_initial_game_state()),
+            (
+                0,
+                [
+                    [0, 22, 0, 1, 0, 1, 0, 1, 0, 1],
+                    [1, 0, 1, 0, 1, 0, 1, 0, 1, 0],
+                    [0, 1, 0, 1, 0, 1, 0, 1, 0, 1],
+                    [1, 0, 1, 0, 1, 0, 1, 0, 1, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 2, 0, 2, 0, 2, 0, 2, 0, 2],
+                    [2, 0, 2, 0, 2, 0, 2, 0, 2, 0],
+                    [0, 2, 0, 2, 0, 2, 0, 2, 0, 2],
+                    [2, 0, 2, 0, 2, 0, 2, 0, 11, 0],
+                ],
+            ),
+            (
+                1,
+                [
+                    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 11, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 2, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                    [22, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                ],
+            ),
+        ];
+
+        for (responsible_player_index, game_state) in test_game_states.iter() {
+            let (deserialized_responsible_player_index, deserialized_game_state) = deserializer
+                .deserialize_game_state(
+                    &serializer.serialize_game_state(*responsible_player_index, game_state),
+                );
+
+            assert_eq!(
+                *responsible_player_index,
+                deserialized_responsible_player_index
+            );
+            assert_eq!(*game_state, deserialized_game_state);
+        }
+    }
+}
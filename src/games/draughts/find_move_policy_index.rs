@@ -0,0 +1,59 @@
+use crate::games::checkers::{
+    get_playable_space_index, FIRST_PLAYER_DOUBLE_PIECE_VALUE, FIRST_PLAYER_SINGLE_PIECE_VALUE,
+    SECOND_PLAYER_DOUBLE_PIECE_VALUE, SECOND_PLAYER_SINGLE_PIECE_VALUE,
+};
+use crate::games::draughts::GameStateType as DraughtsGameState;
+
+const PLAYABLE_SPACES_COUNT: usize = 50;
+
+/// Size of the fixed move encoding - every move is identified by the playable space it starts from
+/// and the playable space it ends on, so multi-jumps are encoded the same way simple moves are.
+pub const MOVE_POLICY_SIZE: usize = PLAYABLE_SPACES_COUNT * PLAYABLE_SPACES_COUNT;
+
+/// Finds the index within the fixed move encoding of the move that leads from the current game state to the next one.
+/// Returns None if the move cannot be told apart from the two game states,
+/// which only happens when a multi-jump ends on the space it started from.
+pub fn find_move_policy_index(
+    current_player_index: i32,
+    current_game_state: &DraughtsGameState,
+    next_game_state: &DraughtsGameState,
+) -> Option<usize> {
+    let (single_piece_value, double_piece_value) = if current_player_index == 0 {
+        (
+            FIRST_PLAYER_SINGLE_PIECE_VALUE,
+            FIRST_PLAYER_DOUBLE_PIECE_VALUE,
+        )
+    } else {
+        (
+            SECOND_PLAYER_SINGLE_PIECE_VALUE,
+            SECOND_PLAYER_DOUBLE_PIECE_VALUE,
+        )
+    };
+    let is_owned_piece = |space_value: u8| -> bool {
+        return space_value == single_piece_value || space_value == double_piece_value;
+    };
+
+    let mut origin_space_index: Option<usize> = None;
+    let mut destination_space_index: Option<usize> = None;
+    for row in 0..current_game_state.len() {
+        for col in 0..current_game_state[row].len() {
+            let was_owned = is_owned_piece(current_game_state[row][col]);
+            let is_owned = is_owned_piece(next_game_state[row][col]);
+
+            if was_owned && !is_owned {
+                origin_space_index =
+                    Some(get_playable_space_index(current_game_state.len(), row, col));
+            } else if !was_owned && is_owned {
+                destination_space_index =
+                    Some(get_playable_space_index(current_game_state.len(), row, col));
+            }
+        }
+    }
+
+    return match (origin_space_index, destination_space_index) {
+        (Some(origin_space_index), Some(destination_space_index)) => {
+            Some(origin_space_index * PLAYABLE_SPACES_COUNT + destination_space_index)
+        }
+        _ => None,
+    };
+}
@@ -0,0 +1,114 @@
+use crate::games::checkers::{
+    CLIGameStateFormatter, CheckersRules, GameRulesAuthority, UserInputGameStateCreator,
+};
+use crate::games::draughts::{
+    create_initial_game_state, ByteArrayGameStateDeserializer, ByteArrayGameStateSerializer,
+    GameStateType as DraughtsGameState,
+};
+#[cfg(feature = "torch")]
+use crate::games::draughts::{
+    find_move_policy_index, transform_game_state_to_tensor, PolicyValueTorchNet, TorchNet,
+    MOVE_POLICY_SIZE,
+};
+#[cfg(feature = "torch")]
+use crate::structs::NeuralNetDefinition;
+#[cfg(feature = "torch")]
+use crate::traits::PolicyValueNet;
+use crate::traits::{
+    CLIGameStateFormatter as TCLIGameStateFormatter, GameActionNotationsFinder,
+    GameDefinition as TGameDefinition, GameRulesAuthority as TGameRulesAuthority,
+    GameStateDeserializer, GameStateSerializer,
+    UserInputGameStateCreator as TUserInputGameStateCreator,
+};
+#[cfg(feature = "torch")]
+use tch::nn;
+
+/// International draughts, which plays by the checkers rules on a 10x10 board.
+pub struct GameDefinition {
+    cli_game_state_formatter: CLIGameStateFormatter,
+    game_rules_authority: GameRulesAuthority<10>,
+    game_state_deserializer: ByteArrayGameStateDeserializer,
+    game_state_serializer: ByteArrayGameStateSerializer,
+    #[cfg(feature = "torch")]
+    neural_net_definition: NeuralNetDefinition<DraughtsGameState>,
+    user_input_game_state_creator: UserInputGameStateCreator<10>,
+}
+
+impl GameDefinition {
+    pub fn new() -> GameDefinition {
+        return GameDefinition {
+            cli_game_state_formatter: CLIGameStateFormatter {},
+            game_rules_authority: GameRulesAuthority::new(CheckersRules::international()),
+            game_state_deserializer: ByteArrayGameStateDeserializer {},
+            game_state_serializer: ByteArrayGameStateSerializer {},
+            #[cfg(feature = "torch")]
+            neural_net_definition: NeuralNetDefinition {
+                create_policy_value_net: create_policy_value_net,
+                create_value_net: create_value_net,
+                find_move_policy_index: find_move_policy_index,
+                move_policy_size: MOVE_POLICY_SIZE,
+                transform_game_state_to_tensor: transform_game_state_to_tensor,
+            },
+            user_input_game_state_creator: UserInputGameStateCreator::new(
+                CheckersRules::international(),
+            ),
+        };
+    }
+}
+
+impl TGameDefinition<DraughtsGameState, Vec<u8>> for GameDefinition {
+    fn get_name(&self) -> &str {
+        return "draughts";
+    }
+
+    fn create_initial_game_state(&self) -> DraughtsGameState {
+        return create_initial_game_state();
+    }
+
+    fn get_cli_game_state_formatter(&self) -> &dyn TCLIGameStateFormatter<DraughtsGameState> {
+        return &self.cli_game_state_formatter;
+    }
+
+    fn get_game_rules_authority(&self) -> &(dyn TGameRulesAuthority<DraughtsGameState> + Sync) {
+        return &self.game_rules_authority;
+    }
+
+    fn get_game_action_notations_finder(
+        &self,
+    ) -> Option<&(dyn GameActionNotationsFinder<DraughtsGameState> + Sync)> {
+        return Some(&self.game_rules_authority);
+    }
+
+    fn get_game_state_deserializer(
+        &self,
+    ) -> &(dyn GameStateDeserializer<DraughtsGameState, Vec<u8>> + Sync) {
+        return &self.game_state_deserializer;
+    }
+
+    fn get_game_state_serializer(
+        &self,
+    ) -> &(dyn GameStateSerializer<DraughtsGameState, Vec<u8>> + Sync) {
+        return &self.game_state_serializer;
+    }
+
+    fn get_user_input_game_state_creator(
+        &self,
+    ) -> &dyn TUserInputGameStateCreator<DraughtsGameState, String> {
+        return &self.user_input_game_state_creator;
+    }
+
+    #[cfg(feature = "torch")]
+    fn get_neural_net_definition(&self) -> Option<&NeuralNetDefinition<DraughtsGameState>> {
+        return Some(&self.neural_net_definition);
+    }
+}
+
+#[cfg(feature = "torch")]
+fn create_policy_value_net(vs: &nn::Path) -> Box<dyn PolicyValueNet + Sync> {
+    return Box::new(PolicyValueTorchNet::new(vs));
+}
+
+#[cfg(feature = "torch")]
+fn create_value_net(vs: &nn::Path) -> Box<dyn nn::Module + Sync> {
+    return Box::new(TorchNet::new(vs));
+}
@@ -0,0 +1,5 @@
+use crate::traits::BasicGameState;
+
+pub type GameStateType = [[u8; 10]; 10];
+
+impl BasicGameState for GameStateType {}
@@ -0,0 +1,33 @@
+mod byte_array_game_state_serialization;
+pub use byte_array_game_state_serialization::{
+    ByteArrayGameStateDeserializer, ByteArrayGameStateSerializer,
+};
+
+mod create_initial_game_state;
+pub use create_initial_game_state::create_initial_game_state;
+
+#[cfg(feature = "torch")]
+mod find_move_policy_index;
+#[cfg(feature = "torch")]
+pub use find_move_policy_index::{find_move_policy_index, MOVE_POLICY_SIZE};
+
+mod game_definition;
+pub use game_definition::GameDefinition;
+
+mod game_state_type;
+pub use game_state_type::GameStateType;
+
+#[cfg(feature = "torch")]
+mod policy_value_torch_net;
+#[cfg(feature = "torch")]
+pub use policy_value_torch_net::PolicyValueTorchNet;
+
+#[cfg(feature = "torch")]
+mod torch_net;
+#[cfg(feature = "torch")]
+pub use torch_net::TorchNet;
+
+#[cfg(feature = "torch")]
+mod transform_game_state_to_tensor;
+#[cfg(feature = "torch")]
+pub use transform_game_state_to_tensor::transform_game_state_to_tensor;
@@ -0,0 +1,57 @@
+use crate::games::draughts::MOVE_POLICY_SIZE;
+use crate::traits::PolicyValueNet;
+use tch::{nn, Tensor};
+
+/// A network with a shared convolutional trunk feeding two heads:
+/// one producing the logits of the fixed move encoding and one producing the value (in [-1, 1]) of the position.
+/// It takes the same input as TorchNet, with the player index plane holding the index of the player about to move.
+#[derive(Debug)]
+pub struct PolicyValueTorchNet {
+    conv1: nn::Conv2D,
+    conv2: nn::Conv2D,
+    conv3: nn::Conv2D,
+    policy_fc: nn::Linear,
+    value_fc1: nn::Linear,
+    value_fc2: nn::Linear,
+}
+
+impl PolicyValueTorchNet {
+    pub fn new(vs: &nn::Path) -> PolicyValueTorchNet {
+        let conv_config = nn::ConvConfig {
+            padding: 1,
+            ..Default::default()
+        };
+
+        return PolicyValueTorchNet {
+            conv1: nn::conv2d(vs, 5, 32, 3, conv_config),
+            conv2: nn::conv2d(vs, 32, 64, 3, conv_config),
+            conv3: nn::conv2d(vs, 64, 64, 3, conv_config),
+            policy_fc: nn::linear(vs, 6400, MOVE_POLICY_SIZE as i64, Default::default()),
+            value_fc1: nn::linear(vs, 6400, 256, Default::default()),
+            value_fc2: nn::linear(vs, 256, 1, Default::default()),
+        };
+    }
+}
+
+impl PolicyValueNet for PolicyValueTorchNet {
+    fn forward_policy_and_value(&self, tensor: &Tensor) -> (Tensor, Tensor) {
+        let trunk_tensor = tensor
+            .view([-1, 5, 10, 10])
+            .apply(&self.conv1)
+            .relu()
+            .apply(&self.conv2)
+            .relu()
+            .apply(&self.conv3)
+            .relu()
+            .view([-1, 6400]);
+
+        let policy_tensor = trunk_tensor.apply(&self.policy_fc);
+        let value_tensor = trunk_tensor
+            .apply(&self.value_fc1)
+            .relu()
+            .apply(&self.value_fc2)
+            .tanh();
+
+        return (policy_tensor, value_tensor);
+    }
+}
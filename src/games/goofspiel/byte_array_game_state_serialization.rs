@@ -0,0 +1,123 @@
+use crate::games::goofspiel::GameStateType as GoofspielGameState;
+use crate::traits::{GameStateDeserializer, GameStateSerializer};
+
+/// Each state hashes to 9 bytes:
+/// - The first byte holds the player who made the last bid (0b11 for no player responsible)
+/// - The next 2 bytes hold the hands of the players
+/// - The next 2 bytes hold the prize deck and the prize card
+/// - The last 4 bytes hold the bids and the scores
+pub struct ByteArrayGameStateSerializer {}
+
+impl GameStateSerializer<GoofspielGameState, Vec<u8>> for ByteArrayGameStateSerializer {
+    fn serialize_game_state(
+        &self,
+        responsible_player_index: i32,
+        game_state: &GoofspielGameState,
+    ) -> Vec<u8> {
+        let responsible_player_byte = if responsible_player_index == -1 {
+            0b11
+        } else {
+            responsible_player_index as u8
+        };
+
+        return vec![
+            responsible_player_byte,
+            game_state.player_hands[0],
+            game_state.player_hands[1],
+            game_state.prize_deck,
+            game_state.prize_card,
+            game_state.bids[0],
+            game_state.bids[1],
+            game_state.scores[0],
+            game_state.scores[1],
+        ];
+    }
+}
+
+pub struct ByteArrayGameStateDeserializer {}
+
+impl GameStateDeserializer<GoofspielGameState, Vec<u8>> for ByteArrayGameStateDeserializer {
+    fn deserialize_game_state(&self, serialized_game_state: &Vec<u8>) -> (i32, GoofspielGameState) {
+        if serialized_game_state.len() != 9 {
+            panic!("Cannot deserialize invalid serialized Goofspiel game state - expected a total of 9 bytes, got {}.", serialized_game_state.len());
+        }
+
+        let responsible_player_index = if serialized_game_state[0] == 0b11 {
+            -1
+        } else {
+            serialized_game_state[0] as i32
+        };
+
+        return (
+            responsible_player_index,
+            GoofspielGameState {
+                player_hands: [serialized_game_state[1], serialized_game_state[2]],
+                prize_deck: serialized_game_state[3],
+                prize_card: serialized_game_state[4],
+                bids: [serialized_game_state[5], serialized_game_state[6]],
+                scores: [serialized_game_state[7], serialized_game_state[8]],
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::goofspiel::{create_initial_game_state, GameRulesAuthority};
+    use crate::traits::{
+        GameRulesAuthority as TGameRulesAuthority,
+        SimultaneousGameRulesAuthority as TSimultaneousGameRulesAuthority,
+    };
+
+    #[test]
+    fn test_for_invertibility() {
+        let serializer = ByteArrayGameStateSerializer {};
+        let deserializer = ByteArrayGameStateDeserializer {};
+        let game_rules_authority = GameRulesAuthority {};
+
+        // Reveal the prize cards in a shuffled order, with the first player bidding their lowest card
+        // and the second player their highest card, going through won, lost and tied rounds
+        let mut game_state = create_initial_game_state();
+        let mut test_game_states: Vec<(i32, GoofspielGameState)> = vec![(-1, game_state)];
+        let mut round_index = 0;
+        while game_rules_authority
+            .analyze_game_state_for_terminality(&game_state, 0)
+            .is_none()
+        {
+            let chance_outcomes = game_rules_authority.find_chance_outcomes(0, &game_state);
+            game_state = chance_outcomes[round_index % chance_outcomes.len()].0;
+            test_game_states.push((-1, game_state));
+
+            let first_player_next_game_state = game_rules_authority
+                .find_available_next_game_states(0, &game_state)
+                .remove(0);
+            let second_player_next_game_state = game_rules_authority
+                .find_available_next_game_states(1, &game_state)
+                .pop()
+                .unwrap();
+            test_game_states.push((0, first_player_next_game_state));
+            test_game_states.push((1, second_player_next_game_state));
+
+            game_state = game_rules_authority.resolve_joint_game_states(
+                &game_state,
+                &vec![first_player_next_game_state, second_player_next_game_state],
+            );
+            test_game_states.push((-1, game_state));
+            round_index += 1;
+        }
+
+        for (responsible_player_index, game_state) in test_game_states.iter() {
+            let (deserialized_responsible_player_index, deserialized_game_state) = deserializer
+                .deserialize_game_state(
+                    &serializer.serialize_game_state(*responsible_player_index, game_state),
+                );
+
+            assert_eq!(
+                *responsible_player_index,
+                deserialized_responsible_player_index
+            );
+            assert_eq!(*game_state, deserialized_game_state);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::games::goofspiel::internal::*;
+use crate::games::goofspiel::GameStateType as GoofspielGameState;
+use crate::traits::CLIGameStateFormatter as TCLIGameStateFormatter;
+
+/// Shows the prize card up for grabs and the prize cards left to reveal,
+/// followed by the hand and the score of the first player (x) and the second player (o).
+pub struct CLIGameStateFormatter {}
+
+impl TCLIGameStateFormatter<GoofspielGameState> for CLIGameStateFormatter {
+    fn format_game_state_for_cli(&self, game_state: &GoofspielGameState) -> String {
+        return format!(
+            "Prize card: {}, prize deck: {}\nx hand: {}, score: {}\no hand: {}, score: {}\n",
+            game_state.prize_card,
+            format_cards(game_state.prize_deck),
+            format_cards(game_state.player_hands[0]),
+            game_state.scores[0],
+            format_cards(game_state.player_hands[1]),
+            game_state.scores[1]
+        );
+    }
+}
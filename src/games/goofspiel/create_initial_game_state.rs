@@ -0,0 +1,13 @@
+use crate::games::goofspiel::internal::*;
+use crate::games::goofspiel::GameStateType as GoofspielGameState;
+
+/// Each player holds a full suit, and another full suit is shuffled into the prize deck.
+pub fn create_initial_game_state() -> GoofspielGameState {
+    return GoofspielGameState {
+        player_hands: [FULL_SUIT, FULL_SUIT],
+        prize_deck: FULL_SUIT,
+        prize_card: 0,
+        bids: [0, 0],
+        scores: [0, 0],
+    };
+}
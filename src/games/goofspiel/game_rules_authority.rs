@@ -0,0 +1,99 @@
+use crate::games::goofspiel::internal::*;
+use crate::games::goofspiel::GameStateType as GoofspielGameState;
+use crate::traits::{
+    GameRulesAuthority as TGameRulesAuthority,
+    SimultaneousGameRulesAuthority as TSimultaneousGameRulesAuthority,
+};
+use std::cmp::Ordering;
+
+/// Every round a prize card is revealed from the prize deck, and both players bid for it at once with a card from their hand.
+/// The higher bid wins the prize card, while the prize card is discarded if the bids are equal.
+/// Bid cards are gone for good, and once the prize deck runs out the player with the higher sum of prize cards wins.
+pub struct GameRulesAuthority {}
+
+impl TGameRulesAuthority<GoofspielGameState> for GameRulesAuthority {
+    fn analyze_game_state_for_terminality(
+        &self,
+        game_state: &GoofspielGameState,
+        _next_player_index: i32,
+    ) -> Option<i32> {
+        if game_state.prize_deck != 0 || game_state.prize_card != 0 {
+            return None;
+        }
+
+        return match game_state.scores[0].cmp(&game_state.scores[1]) {
+            Ordering::Greater => Some(0),
+            Ordering::Less => Some(1),
+            Ordering::Equal => Some(-1),
+        };
+    }
+
+    fn find_available_next_game_states(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GoofspielGameState,
+    ) -> Vec<GoofspielGameState> {
+        let player_index = current_player_index as usize;
+        if current_game_state.prize_card == 0 || current_game_state.bids[player_index] != 0 {
+            return vec![];
+        }
+
+        return find_cards(current_game_state.player_hands[player_index])
+            .into_iter()
+            .map(|card| {
+                let mut next_game_state = *current_game_state;
+                next_game_state.player_hands[player_index] &= !find_card_bit(card);
+                next_game_state.bids[player_index] = card;
+                return next_game_state;
+            })
+            .collect();
+    }
+
+    fn find_chance_outcomes(
+        &self,
+        _current_player_index: i32,
+        current_game_state: &GoofspielGameState,
+    ) -> Vec<(GoofspielGameState, f32)> {
+        if current_game_state.prize_card != 0 {
+            return vec![];
+        }
+
+        let prize_cards = find_cards(current_game_state.prize_deck);
+        let probability = 1.0 / prize_cards.len() as f32;
+        return prize_cards
+            .into_iter()
+            .map(|prize_card| {
+                let mut chance_outcome = *current_game_state;
+                chance_outcome.prize_deck &= !find_card_bit(prize_card);
+                chance_outcome.prize_card = prize_card;
+                return (chance_outcome, probability);
+            })
+            .collect();
+    }
+}
+
+impl TSimultaneousGameRulesAuthority<GoofspielGameState> for GameRulesAuthority {
+    fn resolve_joint_game_states(
+        &self,
+        current_game_state: &GoofspielGameState,
+        players_next_game_states: &Vec<GoofspielGameState>,
+    ) -> GoofspielGameState {
+        let mut next_game_state = *current_game_state;
+        for player_index in 0..2 {
+            let player_next_game_state = &players_next_game_states[player_index];
+            next_game_state.player_hands[player_index] =
+                player_next_game_state.player_hands[player_index];
+            next_game_state.bids[player_index] = player_next_game_state.bids[player_index];
+        }
+
+        match next_game_state.bids[0].cmp(&next_game_state.bids[1]) {
+            Ordering::Greater => next_game_state.scores[0] += next_game_state.prize_card,
+            Ordering::Less => next_game_state.scores[1] += next_game_state.prize_card,
+            Ordering::Equal => (),
+        }
+
+        next_game_state.prize_card = 0;
+        next_game_state.bids = [0, 0];
+        return next_game_state;
+    }
+}
@@ -0,0 +1,18 @@
+use crate::traits::BasicGameState;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GameStateType {
+    /// The cards left in each player's hand, indexed by player index,
+    /// as bit sets in which the bit at index i stands for the card of value i + 1.
+    pub player_hands: [u8; 2],
+    /// The prize cards yet to be revealed, as a bit set like the hands.
+    pub prize_deck: u8,
+    /// The prize card up for grabs this round, or 0 while the next one is yet to be revealed.
+    pub prize_card: u8,
+    /// The card each player bid for the prize card, indexed by player index, or 0 while they are yet to bid.
+    pub bids: [u8; 2],
+    /// The sum of the prize cards each player won, indexed by player index.
+    pub scores: [u8; 2],
+}
+
+impl BasicGameState for GameStateType {}
@@ -0,0 +1,22 @@
+pub const NUMBER_OF_CARDS: u8 = 6;
+/// The bit set of all the cards of a suit.
+pub const FULL_SUIT: u8 = (1 << NUMBER_OF_CARDS) - 1;
+
+pub fn find_card_bit(card: u8) -> u8 {
+    return 1 << (card - 1);
+}
+
+/// Lists the cards of the given bit set in ascending order.
+pub fn find_cards(cards_bit_set: u8) -> Vec<u8> {
+    return (1..NUMBER_OF_CARDS + 1)
+        .filter(|card| cards_bit_set & find_card_bit(*card) != 0)
+        .collect();
+}
+
+pub fn format_cards(cards_bit_set: u8) -> String {
+    let card_pieces: Vec<String> = find_cards(cards_bit_set)
+        .iter()
+        .map(|card| card.to_string())
+        .collect();
+    return card_pieces.join(" ");
+}
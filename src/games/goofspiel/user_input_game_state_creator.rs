@@ -0,0 +1,35 @@
+use crate::games::goofspiel::internal::*;
+use crate::games::goofspiel::GameStateType as GoofspielGameState;
+use crate::traits::UserInputGameStateCreator as TUserInputGameStateCreator;
+
+/// Takes the value of the card to bid, e.g. "3".
+pub struct UserInputGameStateCreator {}
+
+impl TUserInputGameStateCreator<GoofspielGameState, String> for UserInputGameStateCreator {
+    fn create_new_game_state_from_user_input(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GoofspielGameState,
+        user_input: String,
+    ) -> Result<GoofspielGameState, String> {
+        let player_index = current_player_index as usize;
+        let card = match user_input.trim().parse::<u8>() {
+            Ok(card) if card >= 1 && card <= NUMBER_OF_CARDS => card,
+            _ => {
+                return Err(format!(
+                    "Invalid input format. Valid format is the value of a card, from 1 to {}.",
+                    NUMBER_OF_CARDS
+                ))
+            }
+        };
+
+        if current_game_state.player_hands[player_index] & find_card_bit(card) == 0 {
+            return Err(String::from("That card isn't in your hand!"));
+        }
+
+        let mut new_game_state = *current_game_state;
+        new_game_state.player_hands[player_index] &= !find_card_bit(card);
+        new_game_state.bids[player_index] = card;
+        return Ok(new_game_state);
+    }
+}
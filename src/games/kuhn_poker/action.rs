@@ -0,0 +1,8 @@
+/// A betting action, which means different things depending on whether a bet was made before it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Action {
+    /// Checking when no bet was made yet, folding otherwise.
+    Pass,
+    /// Betting when no bet was made yet, calling otherwise.
+    Bet,
+}
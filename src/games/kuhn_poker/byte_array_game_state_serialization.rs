@@ -0,0 +1,121 @@
+use crate::games::kuhn_poker::{Action, GameStateType as KuhnPokerGameState};
+use crate::traits::{GameStateDeserializer, GameStateSerializer};
+
+/// Each state hashes to 3 to 6 bytes:
+/// - The first byte holds the player who last moved (0b11 for no player responsible)
+/// - The next 2 bytes hold the card of each player, 0 for a card that wasn't dealt or is hidden
+/// - The remaining bytes hold the betting actions in order, 0 for passing and 1 for betting
+/// Serialized observations only differ where the observing player can tell game states apart,
+/// so each of them stands for a whole information set.
+pub struct ByteArrayGameStateSerializer {}
+
+impl GameStateSerializer<KuhnPokerGameState, Vec<u8>> for ByteArrayGameStateSerializer {
+    fn serialize_game_state(
+        &self,
+        responsible_player_index: i32,
+        game_state: &KuhnPokerGameState,
+    ) -> Vec<u8> {
+        let responsible_player_byte = if responsible_player_index == -1 {
+            0b11
+        } else {
+            responsible_player_index as u8
+        };
+
+        let mut serialized_game_state = vec![responsible_player_byte];
+        serialized_game_state.extend_from_slice(&game_state.player_cards);
+        for action in game_state.actions.iter() {
+            serialized_game_state.push(match action {
+                Action::Pass => 0,
+                Action::Bet => 1,
+            });
+        }
+
+        return serialized_game_state;
+    }
+}
+
+pub struct ByteArrayGameStateDeserializer {}
+
+impl GameStateDeserializer<KuhnPokerGameState, Vec<u8>> for ByteArrayGameStateDeserializer {
+    fn deserialize_game_state(&self, serialized_game_state: &Vec<u8>) -> (i32, KuhnPokerGameState) {
+        if serialized_game_state.len() < 3 || serialized_game_state.len() > 6 {
+            panic!("Cannot deserialize invalid serialized Kuhn poker game state - expected 3 to 6 bytes, got {}.", serialized_game_state.len());
+        }
+
+        let responsible_player_index = if serialized_game_state[0] == 0b11 {
+            -1
+        } else {
+            serialized_game_state[0] as i32
+        };
+
+        return (
+            responsible_player_index,
+            KuhnPokerGameState {
+                player_cards: [serialized_game_state[1], serialized_game_state[2]],
+                actions: serialized_game_state[3..]
+                    .iter()
+                    .map(|action_byte| {
+                        if *action_byte == 0 {
+                            Action::Pass
+                        } else {
+                            Action::Bet
+                        }
+                    })
+                    .collect(),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::kuhn_poker::{create_initial_game_state, GameRulesAuthority};
+    use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+    #[test]
+    fn test_for_invertibility() {
+        let serializer = ByteArrayGameStateSerializer {};
+        let deserializer = ByteArrayGameStateDeserializer {};
+        let game_rules_authority = GameRulesAuthority {};
+
+        // Go through every deal and every betting sequence, along with what each player observes of them
+        let initial_game_state = create_initial_game_state();
+        let mut test_game_states: Vec<(i32, KuhnPokerGameState)> =
+            vec![(-1, initial_game_state.clone())];
+        let mut pending_game_states: Vec<KuhnPokerGameState> = game_rules_authority
+            .find_chance_outcomes(0, &initial_game_state)
+            .into_iter()
+            .map(|(chance_outcome, _)| chance_outcome)
+            .collect();
+        while let Some(game_state) = pending_game_states.pop() {
+            let responsible_player_index = game_state.actions.len() as i32 - 1;
+            for observing_player_index in -1..2 {
+                test_game_states.push((
+                    responsible_player_index % 2,
+                    game_rules_authority.observe_game_state(observing_player_index, &game_state),
+                ));
+            }
+
+            pending_game_states.extend(
+                game_rules_authority.find_available_next_game_states(
+                    (responsible_player_index + 1) % 2,
+                    &game_state,
+                ),
+            );
+        }
+
+        for (responsible_player_index, game_state) in test_game_states.iter() {
+            let (deserialized_responsible_player_index, deserialized_game_state) = deserializer
+                .deserialize_game_state(
+                    &serializer.serialize_game_state(*responsible_player_index, game_state),
+                );
+
+            assert_eq!(
+                *responsible_player_index,
+                deserialized_responsible_player_index
+            );
+            assert_eq!(*game_state, deserialized_game_state);
+        }
+    }
+}
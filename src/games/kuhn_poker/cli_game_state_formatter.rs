@@ -0,0 +1,33 @@
+use crate::games::kuhn_poker::internal::*;
+use crate::games::kuhn_poker::{Action, GameStateType as KuhnPokerGameState};
+use crate::traits::CLIGameStateFormatter as TCLIGameStateFormatter;
+
+/// Shows the cards of the first player (x) and the second player (o), ? standing for a card that is hidden or not dealt yet,
+/// followed by the betting actions taken so far and the chips in the pot.
+pub struct CLIGameStateFormatter {}
+
+impl TCLIGameStateFormatter<KuhnPokerGameState> for CLIGameStateFormatter {
+    fn format_game_state_for_cli(&self, game_state: &KuhnPokerGameState) -> String {
+        let action_pieces: Vec<&str> = game_state
+            .actions
+            .iter()
+            .map(|action| match action {
+                Action::Pass => "pass",
+                Action::Bet => "bet",
+            })
+            .collect();
+        let contributions = find_contributions(&game_state.actions);
+
+        return format!(
+            "Cards: x {}, o {}\nActions: {}\nPot: {}\n",
+            format_card(game_state.player_cards[0]),
+            format_card(game_state.player_cards[1]),
+            if action_pieces.is_empty() {
+                String::from("none")
+            } else {
+                action_pieces.join(", ")
+            },
+            contributions[0] + contributions[1]
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use crate::games::kuhn_poker::GameStateType as KuhnPokerGameState;
+
+/// The cards are dealt by the chance event that starts the game.
+pub fn create_initial_game_state() -> KuhnPokerGameState {
+    return KuhnPokerGameState {
+        player_cards: [0, 0],
+        actions: vec![],
+    };
+}
@@ -0,0 +1,84 @@
+use crate::games::kuhn_poker::internal::*;
+use crate::games::kuhn_poker::{Action, GameStateType as KuhnPokerGameState};
+use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+/// Each player antes a chip and is dealt one card out of a jack, a queen and a king, which the other player doesn't get to see.
+/// The first player then checks or bets a chip, and the second player may check behind, bet, call or fold in turn,
+/// after which the first player gets to call or fold if the second player bet.
+/// The player with the higher card wins the pot unless the other player folded.
+/// Only who wins the pot is recorded, not how many chips it held.
+/// Turn takers that search the game tree only ever see the observation of the player they play for,
+/// in which the other player's card is hidden, so the game is best played by the record based turn takers.
+pub struct GameRulesAuthority {}
+
+impl TGameRulesAuthority<KuhnPokerGameState> for GameRulesAuthority {
+    fn analyze_game_state_for_terminality(
+        &self,
+        game_state: &KuhnPokerGameState,
+        _next_player_index: i32,
+    ) -> Option<i32> {
+        if !is_dealt(game_state) || !is_betting_over(&game_state.actions) {
+            return None;
+        }
+
+        return Some(find_winning_player_index(game_state) as i32);
+    }
+
+    fn find_available_next_game_states(
+        &self,
+        _current_player_index: i32,
+        current_game_state: &KuhnPokerGameState,
+    ) -> Vec<KuhnPokerGameState> {
+        if !is_dealt(current_game_state) || is_betting_over(&current_game_state.actions) {
+            return vec![];
+        }
+
+        return [Action::Pass, Action::Bet]
+            .iter()
+            .map(|action| {
+                let mut next_game_state = current_game_state.clone();
+                next_game_state.actions.push(*action);
+                return next_game_state;
+            })
+            .collect();
+    }
+
+    fn find_chance_outcomes(
+        &self,
+        _current_player_index: i32,
+        current_game_state: &KuhnPokerGameState,
+    ) -> Vec<(KuhnPokerGameState, f32)> {
+        if is_dealt(current_game_state) {
+            return vec![];
+        }
+
+        let number_of_deals = (NUMBER_OF_CARDS * (NUMBER_OF_CARDS - 1)) as f32;
+        let mut chance_outcomes: Vec<(KuhnPokerGameState, f32)> = vec![];
+        for first_player_card in 1..NUMBER_OF_CARDS + 1 {
+            for second_player_card in 1..NUMBER_OF_CARDS + 1 {
+                if first_player_card == second_player_card {
+                    continue;
+                }
+
+                let mut chance_outcome = current_game_state.clone();
+                chance_outcome.player_cards = [first_player_card, second_player_card];
+                chance_outcomes.push((chance_outcome, 1.0 / number_of_deals));
+            }
+        }
+
+        return chance_outcomes;
+    }
+
+    fn observe_game_state(
+        &self,
+        observing_player_index: i32,
+        game_state: &KuhnPokerGameState,
+    ) -> KuhnPokerGameState {
+        let mut observed_game_state = game_state.clone();
+        if observing_player_index >= 0 {
+            observed_game_state.player_cards[1 - observing_player_index as usize] = 0;
+        }
+
+        return observed_game_state;
+    }
+}
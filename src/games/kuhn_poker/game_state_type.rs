@@ -0,0 +1,13 @@
+use crate::games::kuhn_poker::Action;
+use crate::traits::BasicGameState;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GameStateType {
+    /// The card dealt to each player, indexed by player index, 1 being a jack, 2 a queen and 3 a king.
+    /// Both cards are 0 until the cards are dealt, and the card of the other player is 0 in a player's observation.
+    pub player_cards: [u8; 2],
+    /// The betting actions taken so far, in order, the first player acting first.
+    pub actions: Vec<Action>,
+}
+
+impl BasicGameState for GameStateType {}
@@ -0,0 +1,55 @@
+use crate::games::kuhn_poker::{Action, GameStateType as KuhnPokerGameState};
+
+pub const NUMBER_OF_CARDS: u8 = 3;
+/// The number of chips each player puts into the pot before the cards are dealt.
+pub const ANTE: u8 = 1;
+pub const BET_SIZE: u8 = 1;
+
+pub fn is_dealt(game_state: &KuhnPokerGameState) -> bool {
+    return game_state.player_cards != [0, 0];
+}
+
+/// Betting is over once both players checked, or once the first bet was either called or folded to.
+pub fn is_betting_over(actions: &Vec<Action>) -> bool {
+    return match actions.as_slice() {
+        [Action::Pass, Action::Pass] | [Action::Bet, _] | [Action::Pass, Action::Bet, _] => true,
+        _ => false,
+    };
+}
+
+/// Finds the number of chips each player put into the pot, indexed by player index.
+pub fn find_contributions(actions: &Vec<Action>) -> [u8; 2] {
+    let mut contributions = [ANTE, ANTE];
+    for (action_index, action) in actions.iter().enumerate() {
+        if *action == Action::Bet {
+            contributions[action_index % 2] += BET_SIZE;
+        }
+    }
+
+    return contributions;
+}
+
+/// Finds the player who takes the pot once betting is over.
+/// A player who passes after a bet folds, otherwise the higher card wins at the showdown.
+pub fn find_winning_player_index(game_state: &KuhnPokerGameState) -> usize {
+    let actions = &game_state.actions;
+    let last_acting_player_index = (actions.len() - 1) % 2;
+    if actions[actions.len() - 1] == Action::Pass && actions.contains(&Action::Bet) {
+        return 1 - last_acting_player_index;
+    }
+
+    return if game_state.player_cards[0] > game_state.player_cards[1] {
+        0
+    } else {
+        1
+    };
+}
+
+pub fn format_card(card: u8) -> &'static str {
+    return match card {
+        1 => "J",
+        2 => "Q",
+        3 => "K",
+        _ => "?",
+    };
+}
@@ -0,0 +1,33 @@
+use crate::games::kuhn_poker::internal::*;
+use crate::games::kuhn_poker::{Action, GameStateType as KuhnPokerGameState};
+use crate::traits::UserInputGameStateCreator as TUserInputGameStateCreator;
+
+/// Takes "pass" (or "check" and "fold") and "bet" (or "call").
+pub struct UserInputGameStateCreator {}
+
+impl TUserInputGameStateCreator<KuhnPokerGameState, String> for UserInputGameStateCreator {
+    fn create_new_game_state_from_user_input(
+        &self,
+        _current_player_index: i32,
+        current_game_state: &KuhnPokerGameState,
+        user_input: String,
+    ) -> Result<KuhnPokerGameState, String> {
+        let action = match user_input.trim() {
+            "pass" | "check" | "fold" => Action::Pass,
+            "bet" | "call" => Action::Bet,
+            _ => {
+                return Err(String::from(
+                    "Invalid input format. Valid format is \"pass\", \"check\", \"fold\", \"bet\" or \"call\".",
+                ))
+            }
+        };
+
+        if !is_dealt(current_game_state) || is_betting_over(&current_game_state.actions) {
+            return Err(String::from("There's no betting to be done right now!"));
+        }
+
+        let mut new_game_state = current_game_state.clone();
+        new_game_state.actions.push(action);
+        return Ok(new_game_state);
+    }
+}
@@ -1,2 +1,10 @@
+pub mod bitboard_checkers;
 pub mod checkers;
+pub mod connect_four;
+pub mod draughts;
+pub mod goofspiel;
+pub mod kuhn_poker;
+pub mod othello;
+pub mod simplified_backgammon;
+pub mod three_player_tic_tac_toe;
 pub mod tic_tac_toe;
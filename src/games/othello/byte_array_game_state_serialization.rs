@@ -0,0 +1,105 @@
+use crate::games::othello::GameStateType as OthelloGameState;
+use crate::traits::{GameStateDeserializer, GameStateSerializer};
+
+/// Each state hashes to 17 bytes:
+/// - The first byte holds the player who last moved (0b11 for no player responsible)
+/// - The next 8 bytes hold the first player's bitboard and the last 8 bytes the second player's bitboard
+/// Unlike in connect four, the responsible player can't be told from the number of discs,
+/// since a player who had to pass leaves the board as it was for the other player to move again.
+pub struct ByteArrayGameStateSerializer {}
+
+impl GameStateSerializer<OthelloGameState, Vec<u8>> for ByteArrayGameStateSerializer {
+    fn serialize_game_state(
+        &self,
+        responsible_player_index: i32,
+        game_state: &OthelloGameState,
+    ) -> Vec<u8> {
+        let responsible_player_byte = if responsible_player_index == -1 {
+            0b11
+        } else {
+            responsible_player_index as u8
+        };
+
+        let mut serialized_game_state = vec![responsible_player_byte];
+        serialized_game_state.extend_from_slice(&game_state.player_bitboards[0].to_be_bytes());
+        serialized_game_state.extend_from_slice(&game_state.player_bitboards[1].to_be_bytes());
+        return serialized_game_state;
+    }
+}
+
+pub struct ByteArrayGameStateDeserializer {}
+
+impl GameStateDeserializer<OthelloGameState, Vec<u8>> for ByteArrayGameStateDeserializer {
+    fn deserialize_game_state(&self, serialized_game_state: &Vec<u8>) -> (i32, OthelloGameState) {
+        if serialized_game_state.len() != 17 {
+            panic!("Cannot deserialize invalid serialized othello game state - expected a total of 17 bytes, got {}.", serialized_game_state.len());
+        }
+
+        let responsible_player_index = if serialized_game_state[0] == 0b11 {
+            -1
+        } else {
+            serialized_game_state[0] as i32
+        };
+
+        let mut first_player_bitboard_bytes = [0; 8];
+        first_player_bitboard_bytes.copy_from_slice(&serialized_game_state[1..9]);
+        let mut second_player_bitboard_bytes = [0; 8];
+        second_player_bitboard_bytes.copy_from_slice(&serialized_game_state[9..]);
+
+        return (
+            responsible_player_index,
+            OthelloGameState {
+                player_bitboards: [
+                    u64::from_be_bytes(first_player_bitboard_bytes),
+                    u64::from_be_bytes(second_player_bitboard_bytes),
+                ],
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::othello::{create_initial_game_state, GameRulesAuthority};
+    use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+    #[test]
+    fn test_for_invertibility() {
+        let serializer = ByteArrayGameStateSerializer {};
+        let deserializer = ByteArrayGameStateDeserializer {};
+        let game_rules_authority = GameRulesAuthority {};
+
+        // Play the last available move every turn until the game is over, passing whenever there is none
+        let mut responsible_player_index = -1;
+        let mut game_state = create_initial_game_state();
+        let mut test_game_states: Vec<(i32, OthelloGameState)> =
+            vec![(responsible_player_index, game_state)];
+        while game_rules_authority
+            .analyze_game_state_for_terminality(&game_state, (responsible_player_index + 1) % 2)
+            .is_none()
+        {
+            responsible_player_index = (responsible_player_index + 1) % 2;
+            if let Some(next_game_state) = game_rules_authority
+                .find_available_next_game_states(responsible_player_index, &game_state)
+                .pop()
+            {
+                game_state = next_game_state;
+            }
+            test_game_states.push((responsible_player_index, game_state));
+        }
+
+        for (responsible_player_index, game_state) in test_game_states.iter() {
+            let (deserialized_responsible_player_index, deserialized_game_state) = deserializer
+                .deserialize_game_state(
+                    &serializer.serialize_game_state(*responsible_player_index, game_state),
+                );
+
+            assert_eq!(
+                *responsible_player_index,
+                deserialized_responsible_player_index
+            );
+            assert_eq!(*game_state, deserialized_game_state);
+        }
+    }
+}
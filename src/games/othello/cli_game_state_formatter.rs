@@ -0,0 +1,36 @@
+use crate::games::othello::internal::*;
+use crate::games::othello::GameStateType as OthelloGameState;
+use crate::traits::CLIGameStateFormatter as TCLIGameStateFormatter;
+
+pub struct CLIGameStateFormatter {}
+
+impl TCLIGameStateFormatter<OthelloGameState> for CLIGameStateFormatter {
+    fn format_game_state_for_cli(&self, game_state: &OthelloGameState) -> String {
+        let mut string_pieces: Vec<String> = vec![String::from("  a b c d e f g h\n")];
+
+        for row in 0..BOARD_SIZE {
+            string_pieces.push(format!("{}", row + 1));
+            for column in 0..BOARD_SIZE {
+                let square_bit = find_square_bit(column, row);
+                string_pieces.push(String::from(
+                    if game_state.player_bitboards[0] & square_bit != 0 {
+                        "|x"
+                    } else if game_state.player_bitboards[1] & square_bit != 0 {
+                        "|o"
+                    } else {
+                        "| "
+                    },
+                ));
+            }
+            string_pieces.push(String::from("|\n"));
+        }
+
+        string_pieces.push(format!(
+            "x: {} discs, o: {} discs\n",
+            game_state.player_bitboards[0].count_ones(),
+            game_state.player_bitboards[1].count_ones()
+        ));
+
+        return string_pieces.join("");
+    }
+}
@@ -0,0 +1,12 @@
+use crate::games::othello::internal::*;
+use crate::games::othello::GameStateType as OthelloGameState;
+
+/// The first player starts out with the discs on d5 and e4, the second player with the discs on d4 and e5.
+pub fn create_initial_game_state() -> OthelloGameState {
+    return OthelloGameState {
+        player_bitboards: [
+            find_square_bit(3, 4) | find_square_bit(4, 3),
+            find_square_bit(3, 3) | find_square_bit(4, 4),
+        ],
+    };
+}
@@ -0,0 +1,128 @@
+use crate::games::othello::internal::*;
+use crate::games::othello::GameStateType as OthelloGameState;
+use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+pub struct GameRulesAuthority {}
+
+impl TGameRulesAuthority<OthelloGameState> for GameRulesAuthority {
+    /// The game only ends once neither player can place a disc, at which point the player with the most discs wins.
+    fn analyze_game_state_for_terminality(
+        &self,
+        game_state: &OthelloGameState,
+        _next_player_index: i32,
+    ) -> Option<i32> {
+        if find_moves_bitboard(game_state, 0) != 0 || find_moves_bitboard(game_state, 1) != 0 {
+            return None;
+        }
+
+        let first_player_discs_count = game_state.player_bitboards[0].count_ones();
+        let second_player_discs_count = game_state.player_bitboards[1].count_ones();
+        if first_player_discs_count > second_player_discs_count {
+            return Some(0);
+        } else if second_player_discs_count > first_player_discs_count {
+            return Some(1);
+        }
+
+        return Some(-1);
+    }
+
+    fn find_available_next_game_states(
+        &self,
+        current_player_index: i32,
+        current_game_state: &OthelloGameState,
+    ) -> Vec<OthelloGameState> {
+        let mut available_next_game_states: Vec<OthelloGameState> = vec![];
+        let mut moves_bitboard =
+            find_moves_bitboard(current_game_state, current_player_index as usize);
+
+        while moves_bitboard != 0 {
+            let move_bit = moves_bitboard & moves_bitboard.wrapping_neg();
+            available_next_game_states.push(play_move(
+                current_game_state,
+                current_player_index as usize,
+                move_bit,
+            ));
+            moves_bitboard ^= move_bit;
+        }
+
+        return available_next_game_states;
+    }
+
+    fn is_passing_forced(
+        &self,
+        current_player_index: i32,
+        current_game_state: &OthelloGameState,
+    ) -> bool {
+        return find_moves_bitboard(current_game_state, current_player_index as usize) == 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::perft;
+    use crate::game_runners::StandardTurnBasedGameRunner;
+    use crate::games::othello::{
+        create_initial_game_state, ByteArrayGameStateDeserializer, ByteArrayGameStateSerializer,
+    };
+    use crate::traits::{GameRunner, GameStateDeserializer, TurnTaker};
+    use crate::turn_takers::WeightedRandomSelectionTurnTaker;
+    use crate::weights_calculators::UniformGameStateWeightsCalculator;
+
+    #[test]
+    fn test_perft_from_initial_game_state() {
+        let game_rules_authority = GameRulesAuthority {};
+        let initial_game_state = create_initial_game_state();
+
+        let leaf_nodes_counts: Vec<u64> = (1..=5)
+            .map(|depth| perft(&game_rules_authority, 2, 0, &initial_game_state, depth))
+            .collect();
+        assert_eq!(leaf_nodes_counts, vec![4, 12, 56, 244, 1396]);
+    }
+
+    #[test]
+    fn test_forced_pass_through_game_runner() {
+        let game_rules_authority = GameRulesAuthority {};
+        let game_state_serializer = ByteArrayGameStateSerializer {};
+        let weights_calculator = UniformGameStateWeightsCalculator {};
+
+        // The first row reads X O . . . . O X, so that the first player gets to flip either of the second player's discs
+        // while the second player never has a disc to flip, and has to pass in between
+        let game_state = OthelloGameState {
+            player_bitboards: [
+                find_square_bit(0, 0) | find_square_bit(7, 0),
+                find_square_bit(1, 0) | find_square_bit(6, 0),
+            ],
+        };
+        assert!(!game_rules_authority.is_passing_forced(0, &game_state));
+        assert!(game_rules_authority.is_passing_forced(1, &game_state));
+
+        let game_runner =
+            StandardTurnBasedGameRunner::new(&game_rules_authority, &game_state_serializer);
+        let first_turn_taker =
+            WeightedRandomSelectionTurnTaker::new(&game_rules_authority, &weights_calculator, 0);
+        let second_turn_taker =
+            WeightedRandomSelectionTurnTaker::new(&game_rules_authority, &weights_calculator, 1);
+        let turn_takers: Vec<&dyn TurnTaker<OthelloGameState>> =
+            vec![&first_turn_taker, &second_turn_taker];
+        let game_report = game_runner
+            .run_game(game_state, &turn_takers, -1, true)
+            .unwrap()
+            .unwrap();
+
+        let responsible_player_indices: Vec<i32> = game_report
+            .game_state_updates
+            .iter()
+            .map(|game_state_update| game_state_update.responsible_player_index)
+            .collect();
+        assert_eq!(responsible_player_indices, vec![-1, 0, 1, 0]);
+        // the pass leaves the game state as it was after the first player's move
+        let game_state_deserializer = ByteArrayGameStateDeserializer {};
+        let (_, moved_game_state) = game_state_deserializer
+            .deserialize_game_state(&game_report.game_state_updates[1].new_serialized_game_state);
+        let (_, passed_game_state) = game_state_deserializer
+            .deserialize_game_state(&game_report.game_state_updates[2].new_serialized_game_state);
+        assert_eq!(passed_game_state, moved_game_state);
+        assert_eq!(game_report.winning_player_index, 0);
+    }
+}
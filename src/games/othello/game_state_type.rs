@@ -0,0 +1,10 @@
+use crate::traits::BasicGameState;
+
+/// The discs of each player as bitboards, indexed by player index.
+/// The disc in a given column and row (row 0 being the top one) is the bit at index row * 8 + column.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GameStateType {
+    pub player_bitboards: [u64; 2],
+}
+
+impl BasicGameState for GameStateType {}
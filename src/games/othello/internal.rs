@@ -0,0 +1,87 @@
+use crate::games::othello::GameStateType as OthelloGameState;
+
+pub const BOARD_SIZE: u32 = 8;
+
+/// Every square but those of the first column.
+const NOT_FIRST_COLUMN_BITBOARD: u64 = 0xfefe_fefe_fefe_fefe;
+/// Every square but those of the last column.
+const NOT_LAST_COLUMN_BITBOARD: u64 = 0x7f7f_7f7f_7f7f_7f7f;
+
+/// The shift moving a bitboard one square along each of the 8 directions,
+/// along with the mask of the squares that can be reached without wrapping around from one side of the board to the other.
+const DIRECTIONS: [(i32, u64); 8] = [
+    (1, NOT_FIRST_COLUMN_BITBOARD),
+    (-1, NOT_LAST_COLUMN_BITBOARD),
+    (8, u64::MAX),
+    (-8, u64::MAX),
+    (9, NOT_FIRST_COLUMN_BITBOARD),
+    (-9, NOT_LAST_COLUMN_BITBOARD),
+    (7, NOT_LAST_COLUMN_BITBOARD),
+    (-7, NOT_FIRST_COLUMN_BITBOARD),
+];
+
+pub fn find_square_bit(column: u32, row: u32) -> u64 {
+    return 1 << (row * BOARD_SIZE + column);
+}
+
+fn shift_bitboard(bitboard: u64, direction: (i32, u64)) -> u64 {
+    let (shift, mask) = direction;
+    let shifted_bitboard = if shift > 0 {
+        bitboard << shift
+    } else {
+        bitboard >> -shift
+    };
+
+    return shifted_bitboard & mask;
+}
+
+/// Returns the bitboard of the empty squares the given player may place a disc on,
+/// which are those that close off a line of opponent discs against one of the player's discs.
+pub fn find_moves_bitboard(game_state: &OthelloGameState, player_index: usize) -> u64 {
+    let player_bitboard = game_state.player_bitboards[player_index];
+    let opponent_bitboard = game_state.player_bitboards[1 - player_index];
+    let empty_bitboard = !(player_bitboard | opponent_bitboard);
+
+    let mut moves_bitboard = 0;
+    for direction in DIRECTIONS.iter() {
+        // A line of opponent discs is at most 6 discs long
+        let mut line_bitboard = shift_bitboard(player_bitboard, *direction) & opponent_bitboard;
+        for _ in 0..5 {
+            line_bitboard |= shift_bitboard(line_bitboard, *direction) & opponent_bitboard;
+        }
+
+        moves_bitboard |= shift_bitboard(line_bitboard, *direction) & empty_bitboard;
+    }
+
+    return moves_bitboard;
+}
+
+/// Places a disc of the given player on the given square and flips all the opponent discs it closes off.
+/// The square is expected to be one of the moves found by find_moves_bitboard.
+pub fn play_move(
+    game_state: &OthelloGameState,
+    player_index: usize,
+    move_bit: u64,
+) -> OthelloGameState {
+    let player_bitboard = game_state.player_bitboards[player_index];
+    let opponent_bitboard = game_state.player_bitboards[1 - player_index];
+
+    let mut flipped_bitboard = 0;
+    for direction in DIRECTIONS.iter() {
+        let mut line_bitboard = 0;
+        let mut square_bit = shift_bitboard(move_bit, *direction);
+        while square_bit & opponent_bitboard != 0 {
+            line_bitboard |= square_bit;
+            square_bit = shift_bitboard(square_bit, *direction);
+        }
+
+        if square_bit & player_bitboard != 0 {
+            flipped_bitboard |= line_bitboard;
+        }
+    }
+
+    let mut new_game_state = *game_state;
+    new_game_state.player_bitboards[player_index] |= move_bit | flipped_bitboard;
+    new_game_state.player_bitboards[1 - player_index] &= !flipped_bitboard;
+    return new_game_state;
+}
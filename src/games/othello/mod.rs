@@ -0,0 +1,24 @@
+mod byte_array_game_state_serialization;
+pub use byte_array_game_state_serialization::{
+    ByteArrayGameStateDeserializer, ByteArrayGameStateSerializer,
+};
+
+mod cli_game_state_formatter;
+pub use cli_game_state_formatter::CLIGameStateFormatter;
+
+mod create_initial_game_state;
+pub use create_initial_game_state::create_initial_game_state;
+
+mod game_definition;
+pub use game_definition::GameDefinition;
+
+mod game_state_type;
+pub use game_state_type::GameStateType;
+
+mod game_rules_authority;
+pub use game_rules_authority::GameRulesAuthority;
+
+mod internal;
+
+mod user_input_game_state_creator;
+pub use user_input_game_state_creator::UserInputGameStateCreator;
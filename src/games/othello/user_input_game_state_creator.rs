@@ -0,0 +1,35 @@
+use crate::games::othello::internal::*;
+use crate::games::othello::GameStateType as OthelloGameState;
+use crate::traits::UserInputGameStateCreator as TUserInputGameStateCreator;
+
+pub struct UserInputGameStateCreator {}
+
+impl TUserInputGameStateCreator<OthelloGameState, String> for UserInputGameStateCreator {
+    fn create_new_game_state_from_user_input(
+        &self,
+        current_player_index: i32,
+        current_game_state: &OthelloGameState,
+        user_input: String,
+    ) -> Result<OthelloGameState, String> {
+        let square_chars: Vec<char> = user_input.trim().to_lowercase().chars().collect();
+        let (column, row) = match square_chars[..] {
+            [column_char @ 'a'..='h', row_char @ '1'..='8'] => {
+                (column_char as u32 - 'a' as u32, row_char as u32 - '1' as u32)
+            }
+            _ => return Err(String::from("Invalid input format. Valid format is a column letter followed by a row number, e.g. d3.")),
+        };
+
+        let move_bit = find_square_bit(column, row);
+        if find_moves_bitboard(current_game_state, current_player_index as usize) & move_bit == 0 {
+            return Err(String::from(
+                "A disc placed there wouldn't flip any of the opponent's discs!",
+            ));
+        }
+
+        return Ok(play_move(
+            current_game_state,
+            current_player_index as usize,
+            move_bit,
+        ));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::games::simplified_backgammon::internal::*;
+use crate::games::simplified_backgammon::GameStateType as SimplifiedBackgammonGameState;
+use crate::traits::{GameStateDeserializer, GameStateSerializer};
+
+/// Each state hashes to 18 bytes:
+/// - The first byte holds the player who last moved (0b11 for no player responsible)
+/// - The next 12 bytes hold the signed checker count of each point
+/// - The last 5 bytes hold the bar counts, the borne off counts and the die roll
+/// Whose turn it is can't be told from the board, so unlike in tic-tac-toe the responsible player is part of the encoding.
+pub struct ByteArrayGameStateSerializer {}
+
+impl GameStateSerializer<SimplifiedBackgammonGameState, Vec<u8>> for ByteArrayGameStateSerializer {
+    fn serialize_game_state(
+        &self,
+        responsible_player_index: i32,
+        game_state: &SimplifiedBackgammonGameState,
+    ) -> Vec<u8> {
+        let responsible_player_byte = if responsible_player_index == -1 {
+            0b11
+        } else {
+            responsible_player_index as u8
+        };
+
+        let mut serialized_game_state = vec![responsible_player_byte];
+        for point_value in game_state.points.iter() {
+            serialized_game_state.push(*point_value as u8);
+        }
+        serialized_game_state.extend_from_slice(&game_state.bar_counts);
+        serialized_game_state.extend_from_slice(&game_state.borne_off_counts);
+        serialized_game_state.push(game_state.die_roll);
+
+        return serialized_game_state;
+    }
+}
+
+pub struct ByteArrayGameStateDeserializer {}
+
+impl GameStateDeserializer<SimplifiedBackgammonGameState, Vec<u8>>
+    for ByteArrayGameStateDeserializer
+{
+    fn deserialize_game_state(
+        &self,
+        serialized_game_state: &Vec<u8>,
+    ) -> (i32, SimplifiedBackgammonGameState) {
+        let expected_length = NUMBER_OF_POINTS + 6;
+        if serialized_game_state.len() != expected_length {
+            panic!("Cannot deserialize invalid serialized simplified backgammon game state - expected a total of {} bytes, got {}.", expected_length, serialized_game_state.len());
+        }
+
+        let responsible_player_index = if serialized_game_state[0] == 0b11 {
+            -1
+        } else {
+            serialized_game_state[0] as i32
+        };
+
+        let mut points = [0; NUMBER_OF_POINTS];
+        for point_index in 0..NUMBER_OF_POINTS {
+            points[point_index] = serialized_game_state[point_index + 1] as i8;
+        }
+
+        let counts_bytes = &serialized_game_state[NUMBER_OF_POINTS + 1..];
+        return (
+            responsible_player_index,
+            SimplifiedBackgammonGameState {
+                points: points,
+                bar_counts: [counts_bytes[0], counts_bytes[1]],
+                borne_off_counts: [counts_bytes[2], counts_bytes[3]],
+                die_roll: counts_bytes[4],
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::simplified_backgammon::{create_initial_game_state, GameRulesAuthority};
+    use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+    #[test]
+    fn test_for_invertibility() {
+        let serializer = ByteArrayGameStateSerializer {};
+        let deserializer = ByteArrayGameStateDeserializer {};
+        let game_rules_authority = GameRulesAuthority {};
+
+        // Cycle through the die rolls and play the last available move every turn, going through hits and forfeited rolls
+        let mut responsible_player_index = -1;
+        let mut game_state = create_initial_game_state();
+        let mut test_game_states: Vec<(i32, SimplifiedBackgammonGameState)> =
+            vec![(responsible_player_index, game_state)];
+        for turn_index in 0..200 {
+            if game_rules_authority
+                .analyze_game_state_for_terminality(&game_state, (responsible_player_index + 1) % 2)
+                .is_some()
+            {
+                break;
+            }
+
+            let chance_outcomes = game_rules_authority
+                .find_chance_outcomes((responsible_player_index + 1) % 2, &game_state);
+            game_state = chance_outcomes[turn_index % chance_outcomes.len()].0;
+            test_game_states.push((responsible_player_index, game_state));
+
+            responsible_player_index = (responsible_player_index + 1) % 2;
+            game_state = game_rules_authority
+                .find_available_next_game_states(responsible_player_index, &game_state)
+                .pop()
+                .unwrap();
+            test_game_states.push((responsible_player_index, game_state));
+        }
+
+        for (responsible_player_index, game_state) in test_game_states.iter() {
+            let (deserialized_responsible_player_index, deserialized_game_state) = deserializer
+                .deserialize_game_state(
+                    &serializer.serialize_game_state(*responsible_player_index, game_state),
+                );
+
+            assert_eq!(
+                *responsible_player_index,
+                deserialized_responsible_player_index
+            );
+            assert_eq!(*game_state, deserialized_game_state);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::games::simplified_backgammon::internal::*;
+use crate::games::simplified_backgammon::GameStateType as SimplifiedBackgammonGameState;
+use crate::traits::CLIGameStateFormatter as TCLIGameStateFormatter;
+
+/// Shows the points in order along with the checkers on them, x for the first player and o for the second player.
+/// The first player moves from left to right and the second player from right to left.
+pub struct CLIGameStateFormatter {}
+
+impl TCLIGameStateFormatter<SimplifiedBackgammonGameState> for CLIGameStateFormatter {
+    fn format_game_state_for_cli(&self, game_state: &SimplifiedBackgammonGameState) -> String {
+        let mut point_index_pieces: Vec<String> = vec![];
+        let mut checker_pieces: Vec<String> = vec![];
+        for point_index in 0..NUMBER_OF_POINTS {
+            let point_value = game_state.points[point_index];
+            point_index_pieces.push(format!("{:>3}", point_index));
+            checker_pieces.push(if point_value > 0 {
+                format!("{:>3}", format!("x{}", point_value))
+            } else if point_value < 0 {
+                format!("{:>3}", format!("o{}", -point_value))
+            } else {
+                String::from("  .")
+            });
+        }
+
+        return format!(
+            "{}\n{}\nBar: x{} o{}, borne off: x{} o{}, die roll: {}\n",
+            point_index_pieces.join(""),
+            checker_pieces.join(""),
+            game_state.bar_counts[0],
+            game_state.bar_counts[1],
+            game_state.borne_off_counts[0],
+            game_state.borne_off_counts[1],
+            game_state.die_roll
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use crate::games::simplified_backgammon::internal::*;
+use crate::games::simplified_backgammon::GameStateType as SimplifiedBackgammonGameState;
+
+/// Each player starts out with 2 checkers where they enter the board and 3 checkers halfway around it.
+pub fn create_initial_game_state() -> SimplifiedBackgammonGameState {
+    let mut points = [0; NUMBER_OF_POINTS];
+    points[find_point_index(0, 0)] = 2;
+    points[find_point_index(0, NUMBER_OF_POINTS / 2 - 1)] = 3;
+    points[find_point_index(1, 0)] = -2;
+    points[find_point_index(1, NUMBER_OF_POINTS / 2 - 1)] = -3;
+
+    return SimplifiedBackgammonGameState {
+        points: points,
+        bar_counts: [0, 0],
+        borne_off_counts: [0, 0],
+        die_roll: 0,
+    };
+}
@@ -0,0 +1,63 @@
+use crate::games::simplified_backgammon::internal::*;
+use crate::games::simplified_backgammon::GameStateType as SimplifiedBackgammonGameState;
+use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+/// Every turn starts with a roll of a single die, after which the player moves one checker by the rolled number of points.
+/// A player who can't move with their roll loses their turn.
+/// The first player to bear off all of their checkers wins.
+pub struct GameRulesAuthority {}
+
+impl TGameRulesAuthority<SimplifiedBackgammonGameState> for GameRulesAuthority {
+    fn analyze_game_state_for_terminality(
+        &self,
+        game_state: &SimplifiedBackgammonGameState,
+        _next_player_index: i32,
+    ) -> Option<i32> {
+        for player_index in 0..2 {
+            if game_state.borne_off_counts[player_index] == NUMBER_OF_CHECKERS {
+                return Some(player_index as i32);
+            }
+        }
+
+        return None;
+    }
+
+    fn find_available_next_game_states(
+        &self,
+        current_player_index: i32,
+        current_game_state: &SimplifiedBackgammonGameState,
+    ) -> Vec<SimplifiedBackgammonGameState> {
+        let mut available_next_game_states: Vec<SimplifiedBackgammonGameState> =
+            find_moves(current_game_state, current_player_index as usize)
+                .into_iter()
+                .map(|(_, next_game_state)| next_game_state)
+                .collect();
+
+        if available_next_game_states.is_empty() && current_game_state.die_roll > 0 {
+            // The roll is forfeited, leaving the board as it is
+            let mut next_game_state = *current_game_state;
+            next_game_state.die_roll = 0;
+            available_next_game_states.push(next_game_state);
+        }
+
+        return available_next_game_states;
+    }
+
+    fn find_chance_outcomes(
+        &self,
+        _current_player_index: i32,
+        current_game_state: &SimplifiedBackgammonGameState,
+    ) -> Vec<(SimplifiedBackgammonGameState, f32)> {
+        if current_game_state.die_roll > 0 {
+            return vec![];
+        }
+
+        return (1..NUMBER_OF_DIE_FACES + 1)
+            .map(|die_roll| {
+                let mut chance_outcome = *current_game_state;
+                chance_outcome.die_roll = die_roll;
+                return (chance_outcome, 1.0 / NUMBER_OF_DIE_FACES as f32);
+            })
+            .collect();
+    }
+}
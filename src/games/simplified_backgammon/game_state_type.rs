@@ -0,0 +1,17 @@
+use crate::games::simplified_backgammon::internal::NUMBER_OF_POINTS;
+use crate::traits::BasicGameState;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GameStateType {
+    /// The checkers on each point, counted positively for the first player and negatively for the second player.
+    /// The first player moves their checkers towards the last point while the second player moves theirs towards the first point.
+    pub points: [i8; NUMBER_OF_POINTS],
+    /// The number of checkers of each player that were hit and have to enter the board again, indexed by player index.
+    pub bar_counts: [u8; 2],
+    /// The number of checkers of each player that were borne off the board, indexed by player index.
+    pub borne_off_counts: [u8; 2],
+    /// The roll of the die for the player to move, or 0 while the die is yet to be rolled.
+    pub die_roll: u8,
+}
+
+impl BasicGameState for GameStateType {}
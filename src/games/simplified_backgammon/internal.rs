@@ -0,0 +1,149 @@
+use crate::games::simplified_backgammon::GameStateType as SimplifiedBackgammonGameState;
+
+pub const NUMBER_OF_POINTS: usize = 12;
+pub const NUMBER_OF_CHECKERS: u8 = 5;
+/// The number of points at the end of a player's way around the board that their checkers must all reach before bearing off.
+pub const HOME_BOARD_SIZE: usize = 3;
+pub const NUMBER_OF_DIE_FACES: u8 = 6;
+
+/// The origin of a checker move.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MoveSource {
+    Bar,
+    /// The position of the checker along the player's way around the board, see find_point_index.
+    Position(usize),
+}
+
+/// Turns a position along the given player's way around the board, 0 being where they enter the board, into a point index.
+pub fn find_point_index(player_index: usize, position: usize) -> usize {
+    if player_index == 0 {
+        return position;
+    }
+
+    return NUMBER_OF_POINTS - 1 - position;
+}
+
+pub fn count_player_checkers_on_point(
+    game_state: &SimplifiedBackgammonGameState,
+    player_index: usize,
+    point_index: usize,
+) -> u8 {
+    let point_value = game_state.points[point_index];
+    if player_index == 0 && point_value > 0 {
+        return point_value as u8;
+    } else if player_index == 1 && point_value < 0 {
+        return (-point_value) as u8;
+    }
+
+    return 0;
+}
+
+fn add_player_checker_to_point(
+    game_state: &mut SimplifiedBackgammonGameState,
+    player_index: usize,
+    point_index: usize,
+) {
+    game_state.points[point_index] += if player_index == 0 { 1 } else { -1 };
+}
+
+fn remove_player_checker_from_point(
+    game_state: &mut SimplifiedBackgammonGameState,
+    player_index: usize,
+    point_index: usize,
+) {
+    game_state.points[point_index] -= if player_index == 0 { 1 } else { -1 };
+}
+
+/// Finds the moves the given player can make with the rolled die, along with the game states they lead to.
+/// Checkers on the bar have to enter the board before any other checker can move.
+/// A checker can land on any point that isn't held by two or more of the opponent's checkers,
+/// and landing on a single opponent checker sends it to the bar.
+/// Once all of a player's checkers are in their home board, they can bear off with the exact roll,
+/// or with a higher roll if no checker of theirs is any further away from bearing off.
+pub fn find_moves(
+    game_state: &SimplifiedBackgammonGameState,
+    player_index: usize,
+) -> Vec<(MoveSource, SimplifiedBackgammonGameState)> {
+    let mut moves: Vec<(MoveSource, SimplifiedBackgammonGameState)> = vec![];
+    let die_roll = game_state.die_roll as usize;
+    if die_roll == 0 {
+        return moves;
+    }
+
+    if game_state.bar_counts[player_index] > 0 {
+        let mut new_game_state = *game_state;
+        new_game_state.bar_counts[player_index] -= 1;
+        if try_land_checker(&mut new_game_state, player_index, die_roll - 1) {
+            new_game_state.die_roll = 0;
+            moves.push((MoveSource::Bar, new_game_state));
+        }
+
+        return moves;
+    }
+
+    let mut lowest_occupied_position = NUMBER_OF_POINTS;
+    for position in 0..NUMBER_OF_POINTS {
+        if count_player_checkers_on_point(
+            game_state,
+            player_index,
+            find_point_index(player_index, position),
+        ) > 0
+        {
+            lowest_occupied_position = position;
+            break;
+        }
+    }
+    let is_bearing_off_allowed = lowest_occupied_position >= NUMBER_OF_POINTS - HOME_BOARD_SIZE;
+
+    for position in lowest_occupied_position..NUMBER_OF_POINTS {
+        let point_index = find_point_index(player_index, position);
+        if count_player_checkers_on_point(game_state, player_index, point_index) == 0 {
+            continue;
+        }
+
+        let mut new_game_state = *game_state;
+        remove_player_checker_from_point(&mut new_game_state, player_index, point_index);
+
+        let target_position = position + die_roll;
+        let is_move_legal = if target_position < NUMBER_OF_POINTS {
+            try_land_checker(&mut new_game_state, player_index, target_position)
+        } else if is_bearing_off_allowed
+            && (target_position == NUMBER_OF_POINTS || position == lowest_occupied_position)
+        {
+            new_game_state.borne_off_counts[player_index] += 1;
+            true
+        } else {
+            false
+        };
+
+        if is_move_legal {
+            new_game_state.die_roll = 0;
+            moves.push((MoveSource::Position(position), new_game_state));
+        }
+    }
+
+    return moves;
+}
+
+/// Lands a checker of the given player on the given position, hitting a lone opponent checker if there is one.
+/// Returns false if the point is held by the opponent, in which case the game state is left as it was.
+fn try_land_checker(
+    game_state: &mut SimplifiedBackgammonGameState,
+    player_index: usize,
+    position: usize,
+) -> bool {
+    let opponent_index = 1 - player_index;
+    let point_index = find_point_index(player_index, position);
+
+    match count_player_checkers_on_point(game_state, opponent_index, point_index) {
+        0 => (),
+        1 => {
+            remove_player_checker_from_point(game_state, opponent_index, point_index);
+            game_state.bar_counts[opponent_index] += 1;
+        }
+        _ => return false,
+    }
+
+    add_player_checker_to_point(game_state, player_index, point_index);
+    return true;
+}
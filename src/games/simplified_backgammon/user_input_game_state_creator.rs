@@ -0,0 +1,52 @@
+use crate::games::simplified_backgammon::internal::*;
+use crate::games::simplified_backgammon::GameStateType as SimplifiedBackgammonGameState;
+use crate::traits::UserInputGameStateCreator as TUserInputGameStateCreator;
+
+/// Takes the index of the point to move a checker from, "bar" to enter a checker or "pass" when no checker can move.
+pub struct UserInputGameStateCreator {}
+
+impl TUserInputGameStateCreator<SimplifiedBackgammonGameState, String>
+    for UserInputGameStateCreator
+{
+    fn create_new_game_state_from_user_input(
+        &self,
+        current_player_index: i32,
+        current_game_state: &SimplifiedBackgammonGameState,
+        user_input: String,
+    ) -> Result<SimplifiedBackgammonGameState, String> {
+        let player_index = current_player_index as usize;
+        let moves = find_moves(current_game_state, player_index);
+
+        let move_source = match user_input.trim() {
+            "pass" => {
+                if !moves.is_empty() {
+                    return Err(String::from(
+                        "You can only pass when none of your checkers can move!",
+                    ));
+                }
+
+                let mut new_state = *current_game_state;
+                new_state.die_roll = 0;
+                return Ok(new_state);
+            }
+            "bar" => MoveSource::Bar,
+            point_index_input => match point_index_input.parse::<usize>() {
+                Ok(point_index) if point_index < NUMBER_OF_POINTS => {
+                    // Going from positions to point indices and back is the same operation
+                    MoveSource::Position(find_point_index(player_index, point_index))
+                }
+                _ => return Err(String::from("Invalid input format. Valid format is the index of the point to move a checker from, \"bar\" or \"pass\".")),
+            },
+        };
+
+        return match moves
+            .into_iter()
+            .find(|(available_move_source, _)| *available_move_source == move_source)
+        {
+            Some((_, new_state)) => Ok(new_state),
+            None => Err(String::from(
+                "No checker of yours can move from there with this roll!",
+            )),
+        };
+    }
+}
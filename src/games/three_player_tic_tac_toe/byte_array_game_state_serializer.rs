@@ -0,0 +1,108 @@
+use crate::games::three_player_tic_tac_toe::internal::*;
+use crate::games::three_player_tic_tac_toe::GameStateType as ThreePlayerTicTacToeGameState;
+use crate::traits::{GameStateDeserializer, GameStateSerializer};
+
+/// Each state hashes to 4 bytes, with 2 bits for each of the 16 spaces of the board holding its value.
+pub struct ByteArrayGameStateSerializer {}
+
+impl GameStateSerializer<ThreePlayerTicTacToeGameState, Vec<u8>> for ByteArrayGameStateSerializer {
+    fn serialize_game_state(
+        &self,
+        _responsible_player_index: i32,
+        game_state: &ThreePlayerTicTacToeGameState,
+    ) -> Vec<u8> {
+        let mut state_raw_value: u32 = 0;
+        for i in 0..BOARD_SIZE {
+            for j in 0..BOARD_SIZE {
+                state_raw_value |= (game_state[i][j] as u32) << (2 * (i * BOARD_SIZE + j));
+            }
+        }
+
+        return state_raw_value.to_be_bytes().to_vec();
+    }
+}
+
+/// Reverses the encoding of ByteArrayGameStateSerializer.
+/// The responsible player isn't part of the encoding, but it can be told from the number of marks on the board.
+pub struct ByteArrayGameStateDeserializer {}
+
+impl GameStateDeserializer<ThreePlayerTicTacToeGameState, Vec<u8>>
+    for ByteArrayGameStateDeserializer
+{
+    fn deserialize_game_state(
+        &self,
+        serialized_game_state: &Vec<u8>,
+    ) -> (i32, ThreePlayerTicTacToeGameState) {
+        if serialized_game_state.len() != 4 {
+            panic!("Cannot deserialize invalid serialized three player tic-tac-toe game state - expected a total of 4 bytes, got {}.", serialized_game_state.len());
+        }
+
+        let state_raw_value = u32::from_be_bytes([
+            serialized_game_state[0],
+            serialized_game_state[1],
+            serialized_game_state[2],
+            serialized_game_state[3],
+        ]);
+
+        let mut game_state = [[0; BOARD_SIZE]; BOARD_SIZE];
+        let mut marks_count = 0;
+        for i in 0..BOARD_SIZE {
+            for j in 0..BOARD_SIZE {
+                game_state[i][j] = ((state_raw_value >> (2 * (i * BOARD_SIZE + j))) & 0b11) as u8;
+                if game_state[i][j] > 0 {
+                    marks_count += 1;
+                }
+            }
+        }
+
+        // The players take turns in order starting from the first one, so the last mark tells who moved last
+        let responsible_player_index = if marks_count == 0 {
+            -1
+        } else {
+            (marks_count - 1) % NUMBER_OF_PLAYERS
+        };
+
+        return (responsible_player_index, game_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::three_player_tic_tac_toe::{create_initial_game_state, GameRulesAuthority};
+    use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+    #[test]
+    fn test_for_invertibility() {
+        let serializer = ByteArrayGameStateSerializer {};
+        let deserializer = ByteArrayGameStateDeserializer {};
+        let game_rules_authority = GameRulesAuthority {};
+
+        // Mark the last empty space every turn until the board is full, regardless of whether the game was won
+        let mut responsible_player_index = -1;
+        let mut game_state = create_initial_game_state();
+        let mut test_game_states: Vec<(i32, ThreePlayerTicTacToeGameState)> =
+            vec![(responsible_player_index, game_state.clone())];
+        for turn_index in 0..(BOARD_SIZE * BOARD_SIZE) as i32 {
+            responsible_player_index = turn_index % NUMBER_OF_PLAYERS;
+            game_state = game_rules_authority
+                .find_available_next_game_states(responsible_player_index, &game_state)
+                .pop()
+                .unwrap();
+            test_game_states.push((responsible_player_index, game_state.clone()));
+        }
+
+        for (responsible_player_index, game_state) in test_game_states.iter() {
+            let (deserialized_responsible_player_index, deserialized_game_state) = deserializer
+                .deserialize_game_state(
+                    &serializer.serialize_game_state(*responsible_player_index, game_state),
+                );
+
+            assert_eq!(
+                *responsible_player_index,
+                deserialized_responsible_player_index
+            );
+            assert_eq!(*game_state, deserialized_game_state);
+        }
+    }
+}
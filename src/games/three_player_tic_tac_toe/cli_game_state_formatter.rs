@@ -0,0 +1,30 @@
+use crate::games::three_player_tic_tac_toe::internal::*;
+use crate::games::three_player_tic_tac_toe::GameStateType as ThreePlayerTicTacToeGameState;
+use crate::traits::CLIGameStateFormatter as TCLIGameStateFormatter;
+
+pub struct CLIGameStateFormatter {}
+
+impl TCLIGameStateFormatter<ThreePlayerTicTacToeGameState> for CLIGameStateFormatter {
+    fn format_game_state_for_cli(&self, game_state: &ThreePlayerTicTacToeGameState) -> String {
+        let mut string_pieces: Vec<String> = vec![String::from("    0|1|2|3\n")];
+
+        for i in 0..BOARD_SIZE {
+            let row_string_pieces: Vec<String> = game_state[i]
+                .iter()
+                .map(|space_value| convert_space_value_to_cli_string(*space_value))
+                .collect();
+            string_pieces.push(format!("{} - {}\n", i, row_string_pieces.join("|")));
+        }
+
+        return string_pieces.join("");
+    }
+}
+
+fn convert_space_value_to_cli_string(space_value: u8) -> String {
+    return String::from(match space_value {
+        1 => "x",
+        2 => "o",
+        3 => "+",
+        _ => " ",
+    });
+}
@@ -0,0 +1,6 @@
+use crate::games::three_player_tic_tac_toe::internal::*;
+use crate::games::three_player_tic_tac_toe::GameStateType as ThreePlayerTicTacToeGameState;
+
+pub fn create_initial_game_state() -> ThreePlayerTicTacToeGameState {
+    return [[0; BOARD_SIZE]; BOARD_SIZE];
+}
@@ -0,0 +1,75 @@
+use crate::games::three_player_tic_tac_toe::internal::NUMBER_OF_PLAYERS;
+use crate::games::three_player_tic_tac_toe::{
+    create_initial_game_state, ByteArrayGameStateDeserializer, ByteArrayGameStateSerializer,
+    CLIGameStateFormatter, GameRulesAuthority, GameStateType as ThreePlayerTicTacToeGameState,
+    UserInputGameStateCreator,
+};
+use crate::traits::{
+    CLIGameStateFormatter as TCLIGameStateFormatter, GameDefinition as TGameDefinition,
+    GameRulesAuthority as TGameRulesAuthority, GameStateDeserializer, GameStateSerializer,
+    UserInputGameStateCreator as TUserInputGameStateCreator,
+};
+
+pub struct GameDefinition {
+    cli_game_state_formatter: CLIGameStateFormatter,
+    game_rules_authority: GameRulesAuthority,
+    game_state_deserializer: ByteArrayGameStateDeserializer,
+    game_state_serializer: ByteArrayGameStateSerializer,
+    user_input_game_state_creator: UserInputGameStateCreator,
+}
+
+impl GameDefinition {
+    pub fn new() -> GameDefinition {
+        return GameDefinition {
+            cli_game_state_formatter: CLIGameStateFormatter {},
+            game_rules_authority: GameRulesAuthority {},
+            game_state_deserializer: ByteArrayGameStateDeserializer {},
+            game_state_serializer: ByteArrayGameStateSerializer {},
+            user_input_game_state_creator: UserInputGameStateCreator {},
+        };
+    }
+}
+
+impl TGameDefinition<ThreePlayerTicTacToeGameState, Vec<u8>> for GameDefinition {
+    fn get_name(&self) -> &str {
+        return "three-player-tic-tac-toe";
+    }
+
+    fn get_number_of_players(&self) -> i32 {
+        return NUMBER_OF_PLAYERS;
+    }
+
+    fn create_initial_game_state(&self) -> ThreePlayerTicTacToeGameState {
+        return create_initial_game_state();
+    }
+
+    fn get_cli_game_state_formatter(
+        &self,
+    ) -> &dyn TCLIGameStateFormatter<ThreePlayerTicTacToeGameState> {
+        return &self.cli_game_state_formatter;
+    }
+
+    fn get_game_rules_authority(
+        &self,
+    ) -> &(dyn TGameRulesAuthority<ThreePlayerTicTacToeGameState> + Sync) {
+        return &self.game_rules_authority;
+    }
+
+    fn get_game_state_deserializer(
+        &self,
+    ) -> &(dyn GameStateDeserializer<ThreePlayerTicTacToeGameState, Vec<u8>> + Sync) {
+        return &self.game_state_deserializer;
+    }
+
+    fn get_game_state_serializer(
+        &self,
+    ) -> &(dyn GameStateSerializer<ThreePlayerTicTacToeGameState, Vec<u8>> + Sync) {
+        return &self.game_state_serializer;
+    }
+
+    fn get_user_input_game_state_creator(
+        &self,
+    ) -> &dyn TUserInputGameStateCreator<ThreePlayerTicTacToeGameState, String> {
+        return &self.user_input_game_state_creator;
+    }
+}
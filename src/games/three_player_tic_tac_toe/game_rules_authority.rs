@@ -0,0 +1,76 @@
+use crate::games::three_player_tic_tac_toe::internal::*;
+use crate::games::three_player_tic_tac_toe::GameStateType as ThreePlayerTicTacToeGameState;
+use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+
+/// The row and column steps along a row, a column and both diagonals.
+static LINE_DIRECTIONS: &'static [(i32, i32)] = &[(0, 1), (1, 0), (1, 1), (1, -1)];
+
+/// The first player to get 3 marks in a row on the 4x4 board wins, ahead of the two other players who share the second rank.
+/// When the board fills up without anyone doing so, all three players share the first rank.
+pub struct GameRulesAuthority {}
+
+impl TGameRulesAuthority<ThreePlayerTicTacToeGameState> for GameRulesAuthority {
+    fn analyze_game_state_for_terminality(
+        &self,
+        game_state: &ThreePlayerTicTacToeGameState,
+        _next_player_index: i32,
+    ) -> Option<i32> {
+        for row in 0..BOARD_SIZE as i32 {
+            for column in 0..BOARD_SIZE as i32 {
+                let space_value = game_state[row as usize][column as usize];
+                if space_value == 0 {
+                    continue;
+                }
+
+                for (row_step, column_step) in LINE_DIRECTIONS.iter() {
+                    let line_end_row = row + row_step * (WINNING_LINE_LENGTH as i32 - 1);
+                    let line_end_column = column + column_step * (WINNING_LINE_LENGTH as i32 - 1);
+                    if line_end_row >= BOARD_SIZE as i32
+                        || line_end_column < 0
+                        || line_end_column >= BOARD_SIZE as i32
+                    {
+                        continue;
+                    }
+
+                    let is_winning_line = (1..WINNING_LINE_LENGTH as i32).all(|step_index| {
+                        return game_state[(row + row_step * step_index) as usize]
+                            [(column + column_step * step_index) as usize]
+                            == space_value;
+                    });
+                    if is_winning_line {
+                        return Some(space_value as i32 - 1);
+                    }
+                }
+            }
+        }
+
+        let is_board_full = game_state
+            .iter()
+            .all(|game_state_row| game_state_row.iter().all(|space_value| *space_value > 0));
+        if is_board_full {
+            return Some(-1);
+        }
+
+        return None;
+    }
+
+    fn find_available_next_game_states(
+        &self,
+        current_player_index: i32,
+        current_game_state: &ThreePlayerTicTacToeGameState,
+    ) -> Vec<ThreePlayerTicTacToeGameState> {
+        let mut available_next_game_states: Vec<ThreePlayerTicTacToeGameState> = vec![];
+
+        for i in 0..BOARD_SIZE {
+            for j in 0..BOARD_SIZE {
+                if current_game_state[i][j] == 0 {
+                    let mut available_next_game_state = current_game_state.clone();
+                    available_next_game_state[i][j] = current_player_index as u8 + 1;
+                    available_next_game_states.push(available_next_game_state);
+                }
+            }
+        }
+
+        return available_next_game_states;
+    }
+}
@@ -0,0 +1,7 @@
+use crate::games::three_player_tic_tac_toe::internal::BOARD_SIZE;
+use crate::traits::BasicGameState;
+
+/// The spaces of the board by row and column, each holding 0 when empty or the index of the player who marked it plus 1.
+pub type GameStateType = [[u8; BOARD_SIZE]; BOARD_SIZE];
+
+impl BasicGameState for GameStateType {}
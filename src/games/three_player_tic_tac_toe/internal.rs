@@ -0,0 +1,4 @@
+pub const BOARD_SIZE: usize = 4;
+pub const NUMBER_OF_PLAYERS: i32 = 3;
+/// The number of marks in a row, column or diagonal it takes to win.
+pub const WINNING_LINE_LENGTH: usize = 3;
@@ -0,0 +1,34 @@
+use crate::games::three_player_tic_tac_toe::internal::*;
+use crate::games::three_player_tic_tac_toe::GameStateType as ThreePlayerTicTacToeGameState;
+use crate::traits::UserInputGameStateCreator as TUserInputGameStateCreator;
+
+pub struct UserInputGameStateCreator {}
+
+impl TUserInputGameStateCreator<ThreePlayerTicTacToeGameState, String>
+    for UserInputGameStateCreator
+{
+    fn create_new_game_state_from_user_input(
+        &self,
+        current_player_index: i32,
+        current_game_state: &ThreePlayerTicTacToeGameState,
+        user_input: String,
+    ) -> Result<ThreePlayerTicTacToeGameState, String> {
+        let coordinates: Vec<usize> = user_input
+            .trim()
+            .split(',')
+            .filter_map(|coordinate| coordinate.trim().parse().ok())
+            .collect();
+        let input_coor = match coordinates[..] {
+            [i, j] if i < BOARD_SIZE && j < BOARD_SIZE => (i, j),
+            _ => return Err(String::from("Invalid input format. Valid format is row,col using 0-based indices from the top-left.")),
+        };
+
+        let mut new_state = current_game_state.clone();
+        if new_state[input_coor.0][input_coor.1] > 0 {
+            return Err(String::from("That space is already taken!"));
+        }
+
+        new_state[input_coor.0][input_coor.1] = current_player_index as u8 + 1;
+        return Ok(new_state);
+    }
+}
@@ -0,0 +1,2 @@
+/// The row and the column of the space to mark, using 0-based indices from the top-left.
+pub type Action = (usize, usize);
@@ -0,0 +1,66 @@
+use crate::games::tic_tac_toe::GameStateType as TicTacToeGameState;
+use crate::structs::FixedWidthSerializedGameState;
+use crate::traits::{GameStateDeserializer, GameStateSerializer};
+
+/// Each state hashes to the base_3 sum of the elements from the 2D array working state
+/// There are (3^9 - 1) total possible states according to a naive calculation (when only allowing for legal states, actually far fewer)
+/// log_2(3^9 - 1) < 16, so the value takes up no more than the 16 lowest bits of the fixed width serialized game state
+pub struct FixedWidthGameStateSerializer {}
+
+impl GameStateSerializer<TicTacToeGameState, FixedWidthSerializedGameState>
+    for FixedWidthGameStateSerializer
+{
+    fn serialize_game_state(
+        &self,
+        _responsible_player_index: i32,
+        game_state: &TicTacToeGameState,
+    ) -> FixedWidthSerializedGameState {
+        let mut state_raw_value: u128 = 0;
+        let mut ternary_digit_multiplier: u128 = 1;
+        for i in 0..game_state.len() {
+            for j in 0..game_state.len() {
+                let location_value = game_state[i][j] as u128;
+                state_raw_value += location_value * ternary_digit_multiplier;
+                ternary_digit_multiplier *= 3;
+            }
+        }
+
+        return FixedWidthSerializedGameState::new(state_raw_value);
+    }
+}
+
+/// Reverses the base_3 encoding of FixedWidthGameStateSerializer.
+/// The responsible player isn't part of the encoding, but it can be told from the number of marks on the board.
+pub struct FixedWidthGameStateDeserializer {}
+
+impl GameStateDeserializer<TicTacToeGameState, FixedWidthSerializedGameState>
+    for FixedWidthGameStateDeserializer
+{
+    fn deserialize_game_state(
+        &self,
+        serialized_game_state: &FixedWidthSerializedGameState,
+    ) -> (i32, TicTacToeGameState) {
+        let mut state_raw_value = serialized_game_state.bits;
+
+        let mut game_state = vec![vec![0; 3]; 3];
+        let mut marks_count = 0;
+        for i in 0..game_state.len() {
+            for j in 0..game_state.len() {
+                game_state[i][j] = (state_raw_value % 3) as u8;
+                state_raw_value /= 3;
+                if game_state[i][j] > 0 {
+                    marks_count += 1;
+                }
+            }
+        }
+
+        // The first player goes first, so an odd number of marks means the first player moved last
+        let responsible_player_index = if marks_count == 0 {
+            -1
+        } else {
+            (marks_count + 1) % 2
+        };
+
+        return (responsible_player_index, game_state);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::games::tic_tac_toe::{
+    create_initial_game_state, CLIGameStateFormatter, FixedWidthGameStateDeserializer,
+    FixedWidthGameStateSerializer, GameRulesAuthority, GameStateSymmetriesFinder,
+    GameStateType as TicTacToeGameState, UserInputGameStateCreator,
+};
+use crate::structs::FixedWidthSerializedGameState;
+use crate::traits::{
+    CLIGameStateFormatter as TCLIGameStateFormatter, GameActionNotationsFinder,
+    GameDefinition as TGameDefinition, GameRulesAuthority as TGameRulesAuthority,
+    GameStateDeserializer, GameStateSerializer,
+    GameStateSymmetriesFinder as TGameStateSymmetriesFinder,
+    UserInputGameStateCreator as TUserInputGameStateCreator,
+};
+
+pub struct GameDefinition {
+    cli_game_state_formatter: CLIGameStateFormatter,
+    game_rules_authority: GameRulesAuthority,
+    game_state_deserializer: FixedWidthGameStateDeserializer,
+    game_state_serializer: FixedWidthGameStateSerializer,
+    game_state_symmetries_finder: GameStateSymmetriesFinder,
+    user_input_game_state_creator: UserInputGameStateCreator,
+}
+
+impl GameDefinition {
+    pub fn new() -> GameDefinition {
+        return GameDefinition {
+            cli_game_state_formatter: CLIGameStateFormatter {},
+            game_rules_authority: GameRulesAuthority {},
+            game_state_deserializer: FixedWidthGameStateDeserializer {},
+            game_state_serializer: FixedWidthGameStateSerializer {},
+            game_state_symmetries_finder: GameStateSymmetriesFinder {},
+            user_input_game_state_creator: UserInputGameStateCreator {},
+        };
+    }
+}
+
+impl TGameDefinition<TicTacToeGameState, FixedWidthSerializedGameState> for GameDefinition {
+    fn get_name(&self) -> &str {
+        return "tic-tac-toe";
+    }
+
+    fn get_game_state_records_version(&self) -> i32 {
+        // Game states used to be serialized to 2 bytes rather than to 16
+        return 2;
+    }
+
+    fn create_initial_game_state(&self) -> TicTacToeGameState {
+        return create_initial_game_state();
+    }
+
+    fn get_cli_game_state_formatter(&self) -> &dyn TCLIGameStateFormatter<TicTacToeGameState> {
+        return &self.cli_game_state_formatter;
+    }
+
+    fn get_game_rules_authority(&self) -> &(dyn TGameRulesAuthority<TicTacToeGameState> + Sync) {
+        return &self.game_rules_authority;
+    }
+
+    fn get_game_action_notations_finder(
+        &self,
+    ) -> Option<&(dyn GameActionNotationsFinder<TicTacToeGameState> + Sync)> {
+        return Some(&self.game_rules_authority);
+    }
+
+    fn get_game_state_deserializer(
+        &self,
+    ) -> &(dyn GameStateDeserializer<TicTacToeGameState, FixedWidthSerializedGameState> + Sync)
+    {
+        return &self.game_state_deserializer;
+    }
+
+    fn get_game_state_serializer(
+        &self,
+    ) -> &(dyn GameStateSerializer<TicTacToeGameState, FixedWidthSerializedGameState> + Sync) {
+        return &self.game_state_serializer;
+    }
+
+    fn get_game_state_symmetries_finder(
+        &self,
+    ) -> Option<&(dyn TGameStateSymmetriesFinder<TicTacToeGameState> + Sync)> {
+        return Some(&self.game_state_symmetries_finder);
+    }
+
+    fn get_user_input_game_state_creator(
+        &self,
+    ) -> &dyn TUserInputGameStateCreator<TicTacToeGameState, String> {
+        return &self.user_input_game_state_creator;
+    }
+
+    fn is_solvable(&self) -> bool {
+        return true;
+    }
+}
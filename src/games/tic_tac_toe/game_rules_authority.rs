@@ -1,5 +1,7 @@
-use crate::games::tic_tac_toe::GameStateType as TicTacToeGameState;
-use crate::traits::GameRulesAuthority as TGameRulesAuthority;
+use crate::games::tic_tac_toe::{Action, GameStateType as TicTacToeGameState};
+use crate::traits::{
+    GameActionsAuthority as TGameActionsAuthority, GameRulesAuthority as TGameRulesAuthority,
+};
 
 type Position = (usize, usize);
 static WINNING_POSITION_SETS: &'static [(Position, Position, Position)] = &[
@@ -76,3 +78,106 @@ impl TGameRulesAuthority<TicTacToeGameState> for GameRulesAuthority {
         return available_next_game_states;
     }
 }
+
+impl TGameActionsAuthority<TicTacToeGameState> for GameRulesAuthority {
+    type Action = Action;
+
+    fn find_legal_actions(
+        &self,
+        _current_player_index: i32,
+        current_game_state: &TicTacToeGameState,
+    ) -> Vec<Action> {
+        let mut legal_actions: Vec<Action> = vec![];
+
+        for i in 0..current_game_state.len() {
+            for j in 0..current_game_state.len() {
+                if current_game_state[i][j] == 0 {
+                    legal_actions.push((i, j));
+                }
+            }
+        }
+
+        return legal_actions;
+    }
+
+    fn apply_action(
+        &self,
+        current_player_index: i32,
+        current_game_state: &TicTacToeGameState,
+        action: &Action,
+    ) -> TicTacToeGameState {
+        let mut next_game_state = current_game_state.clone();
+        next_game_state[action.0][action.1] = current_player_index as u8 + 1;
+        return next_game_state;
+    }
+
+    fn format_action(&self, action: &Action) -> String {
+        return format!("{},{}", action.0, action.1);
+    }
+
+    fn get_number_of_action_indices(&self) -> usize {
+        return 9;
+    }
+
+    fn find_action_index(&self, _current_player_index: i32, action: &Action) -> usize {
+        return action.0 * 3 + action.1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::find_decision_game_states;
+    use crate::games::tic_tac_toe::{create_initial_game_state, FixedWidthGameStateSerializer};
+    use crate::traits::GameStateWeightsCalculator;
+    use crate::weights_calculators::SolvedGameStateWeightsCalculator;
+
+    #[test]
+    fn test_solving_game_states() {
+        let game_rules_authority = GameRulesAuthority {};
+        let game_state_serializer = FixedWidthGameStateSerializer {};
+        let weights_calculator =
+            SolvedGameStateWeightsCalculator::new(&game_rules_authority, &game_state_serializer);
+
+        // Every first move draws with perfect play
+        let initial_game_state = create_initial_game_state();
+        let first_moves_weights = weights_calculator.weigh_game_states(
+            0,
+            &game_rules_authority.find_available_next_game_states(0, &initial_game_state),
+        );
+        assert_eq!(first_moves_weights, vec![0.0; 9]);
+
+        // The first player can complete the top row, only draw by blocking the middle row, or let the second player complete it
+        let game_state = vec![vec![1, 1, 0], vec![2, 2, 0], vec![0, 0, 0]];
+        let winning_game_state = vec![vec![1, 1, 1], vec![2, 2, 0], vec![0, 0, 0]];
+        let losing_game_state = vec![vec![1, 1, 0], vec![2, 2, 0], vec![0, 0, 1]];
+        let drawing_game_state = vec![vec![1, 1, 0], vec![2, 2, 1], vec![0, 0, 0]];
+        assert_eq!(
+            weights_calculator.weigh_game_states(
+                0,
+                &vec![winning_game_state, losing_game_state, drawing_game_state]
+            ),
+            vec![1.0, -1.0, 0.0]
+        );
+        assert_eq!(
+            weights_calculator.weigh_game_states(1, &vec![game_state]),
+            vec![-1.0]
+        );
+    }
+
+    #[test]
+    fn test_finding_decision_game_states() {
+        let game_rules_authority = GameRulesAuthority {};
+        let initial_game_state = create_initial_game_state();
+
+        // Of the 5478 legal game states, 958 are over
+        let decision_game_states = find_decision_game_states(
+            &game_rules_authority,
+            &FixedWidthGameStateSerializer {},
+            2,
+            0,
+            &initial_game_state,
+        );
+        assert_eq!(decision_game_states.len(), 4520);
+    }
+}
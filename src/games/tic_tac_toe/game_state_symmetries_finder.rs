@@ -0,0 +1,96 @@
+use crate::games::tic_tac_toe::GameStateType as TicTacToeGameState;
+use crate::traits::GameStateSymmetriesFinder as TGameStateSymmetriesFinder;
+
+/// Finds the 8 symmetries of the board: its 4 rotations, each of them with and without a reflection.
+pub struct GameStateSymmetriesFinder {}
+
+impl TGameStateSymmetriesFinder<TicTacToeGameState> for GameStateSymmetriesFinder {
+    fn find_symmetric_game_states(
+        &self,
+        responsible_player_index: i32,
+        game_state: &TicTacToeGameState,
+    ) -> Vec<(i32, TicTacToeGameState)> {
+        let mut symmetric_game_states: Vec<(i32, TicTacToeGameState)> = vec![];
+
+        let mut rotated_game_state = game_state.clone();
+        for _ in 0..4 {
+            symmetric_game_states.push((
+                responsible_player_index,
+                reflect_game_state(&rotated_game_state),
+            ));
+            symmetric_game_states.push((responsible_player_index, rotated_game_state.clone()));
+            rotated_game_state = rotate_game_state(&rotated_game_state);
+        }
+
+        return symmetric_game_states;
+    }
+}
+
+/// Rotates the board a quarter turn clockwise.
+fn rotate_game_state(game_state: &TicTacToeGameState) -> TicTacToeGameState {
+    let size = game_state.len();
+    return (0..size)
+        .map(|row| {
+            return (0..size)
+                .map(|col| game_state[size - 1 - col][row])
+                .collect();
+        })
+        .collect();
+}
+
+/// Reflects the board along its main diagonal.
+fn reflect_game_state(game_state: &TicTacToeGameState) -> TicTacToeGameState {
+    let size = game_state.len();
+    return (0..size)
+        .map(|row| (0..size).map(|col| game_state[col][row]).collect())
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composites::CanonicalizingGameStateSerializer;
+    use crate::games::tic_tac_toe::FixedWidthGameStateSerializer;
+    use crate::traits::GameStateSerializer;
+
+    #[test]
+    fn test_rotations_and_reflections_share_serialized_game_state() {
+        let serializer = CanonicalizingGameStateSerializer::new(
+            &FixedWidthGameStateSerializer {},
+            &GameStateSymmetriesFinder {},
+        );
+
+        let corner_game_states = vec![
+            vec![vec![1, 0, 0], vec![0, 0, 0], vec![0, 0, 0]],
+            vec![vec![0, 0, 1], vec![0, 0, 0], vec![0, 0, 0]],
+            vec![vec![0, 0, 0], vec![0, 0, 0], vec![1, 0, 0]],
+            vec![vec![0, 0, 0], vec![0, 0, 0], vec![0, 0, 1]],
+        ];
+        for corner_game_state in corner_game_states.iter() {
+            assert_eq!(
+                serializer.serialize_game_state(0, corner_game_state),
+                serializer.serialize_game_state(0, &corner_game_states[0])
+            );
+        }
+
+        let edge_game_state = vec![vec![0, 1, 0], vec![0, 0, 0], vec![0, 0, 0]];
+        assert_ne!(
+            serializer.serialize_game_state(0, &edge_game_state),
+            serializer.serialize_game_state(0, &corner_game_states[0])
+        );
+
+        // Reflecting the board along its anti-diagonal
+        assert_eq!(
+            serializer.serialize_game_state(1, &vec![vec![1, 2, 0], vec![0, 0, 0], vec![0, 0, 0]]),
+            serializer.serialize_game_state(1, &vec![vec![0, 0, 0], vec![0, 0, 2], vec![0, 0, 1]])
+        );
+
+        let game_state = vec![vec![1, 2, 0], vec![0, 1, 0], vec![2, 0, 0]];
+        assert_eq!(
+            GameStateSymmetriesFinder {}
+                .find_symmetric_game_states(0, &game_state)
+                .len(),
+            8
+        );
+    }
+}
@@ -1,5 +1,5 @@
-mod byte_array_game_state_serializer;
-pub use byte_array_game_state_serializer::ByteArrayGameStateSerializer;
+mod action;
+pub use action::Action;
 
 mod cli_game_state_formatter;
 pub use cli_game_state_formatter::CLIGameStateFormatter;
@@ -7,11 +7,22 @@ pub use cli_game_state_formatter::CLIGameStateFormatter;
 mod create_initial_game_state;
 pub use create_initial_game_state::create_initial_game_state;
 
+mod fixed_width_game_state_serializer;
+pub use fixed_width_game_state_serializer::{
+    FixedWidthGameStateDeserializer, FixedWidthGameStateSerializer,
+};
+
+mod game_definition;
+pub use game_definition::GameDefinition;
+
 mod game_state_type;
 pub use game_state_type::GameStateType;
 
 mod game_rules_authority;
 pub use game_rules_authority::GameRulesAuthority;
 
+mod game_state_symmetries_finder;
+pub use game_state_symmetries_finder::GameStateSymmetriesFinder;
+
 mod user_input_game_state_creator;
 pub use user_input_game_state_creator::UserInputGameStateCreator;
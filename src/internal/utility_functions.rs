@@ -1,7 +1,178 @@
 use crate::structs::GameStateRecord;
+use crate::traits::{
+    BasicGameState, BasicSerializedGameState, GameRulesAuthority, GameStateSerializer,
+};
 
 pub fn count_visits(game_state_record: &GameStateRecord) -> i32 {
     return game_state_record.draws_count
         + game_state_record.losses_count
         + game_state_record.wins_count;
 }
+
+/// Breaks the outcome of a game down into a game against each of the other players,
+/// won by the given player if they ranked better, drawn if they shared a rank and lost otherwise.
+/// This way, two player games count as a single win, draw or loss.
+pub fn find_player_game_state_record(
+    player_ranks: &Vec<i32>,
+    player_index: i32,
+) -> GameStateRecord {
+    let mut game_state_record = GameStateRecord::new_zeros();
+    if player_index < 0 {
+        return game_state_record;
+    }
+
+    let player_rank = player_ranks[player_index as usize];
+    for (other_player_index, other_player_rank) in player_ranks.iter().enumerate() {
+        if other_player_index as i32 == player_index {
+            continue;
+        }
+
+        if player_rank < *other_player_rank {
+            game_state_record.wins_count += 1;
+        } else if player_rank == *other_player_rank {
+            game_state_record.draws_count += 1;
+        } else {
+            game_state_record.losses_count += 1;
+        }
+    }
+
+    return game_state_record;
+}
+
+/// Scores a game state record from -1 when all of its games were lost to 1 when all of them were won.
+#[cfg(feature = "torch")]
+pub fn calculate_result_value(game_state_record: &GameStateRecord) -> f32 {
+    let visits_count = count_visits(game_state_record);
+    if visits_count == 0 {
+        return 0.0;
+    }
+
+    return (game_state_record.wins_count - game_state_record.losses_count) as f32
+        / visits_count as f32;
+}
+
+/// Finds the actual next game state that the given player's decision, made from their observation of the current game state, leads to.
+/// Returns None if none of the available next game states looks to the player like the one they decided on.
+pub fn find_next_game_state_from_observation<
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+    Rules: GameRulesAuthority<GameState> + ?Sized,
+>(
+    game_rules_authority: &Rules,
+    game_state_serializer: &dyn GameStateSerializer<GameState, SerializedGameState>,
+    current_player_index: i32,
+    current_game_state: &GameState,
+    observed_game_state: &GameState,
+    new_observed_game_state: &GameState,
+) -> Option<GameState> {
+    let serialized_observed_game_state =
+        game_state_serializer.serialize_game_state(current_player_index, observed_game_state);
+    if serialized_observed_game_state
+        == game_state_serializer.serialize_game_state(current_player_index, current_game_state)
+    {
+        // Nothing is hidden from the player, so the game state they decided on is the actual one
+        return Some(new_observed_game_state.clone());
+    }
+
+    let serialized_new_observed_game_state =
+        game_state_serializer.serialize_game_state(current_player_index, new_observed_game_state);
+    return game_rules_authority
+        .find_available_next_game_states(current_player_index, current_game_state)
+        .into_iter()
+        .find(|available_next_game_state| {
+            return game_state_serializer.serialize_game_state(
+                current_player_index,
+                &game_rules_authority
+                    .observe_game_state(current_player_index, available_next_game_state),
+            ) == serialized_new_observed_game_state;
+        });
+}
+
+/// Tells whether a game is drawn by its history, either by the current game state coming up for the given number of times
+/// with the same player to move, or by the given number of moves in a row going by without a progress move.
+/// Game states from before the last progress move can never come up again, so only the moves since then matter.
+pub fn is_game_history_drawn<GameState: BasicGameState + PartialEq>(
+    game_states_history: &Vec<GameState>,
+    is_progress_move: fn(&GameState, &GameState) -> bool,
+    no_progress_moves_limit: usize,
+    repetitions_for_draw: usize,
+) -> bool {
+    let current_game_state = &game_states_history[game_states_history.len() - 1];
+    let mut no_progress_moves_count = 0;
+    let mut repetitions_count = 1;
+    for history_index in (1..game_states_history.len()).rev() {
+        if is_progress_move(
+            &game_states_history[history_index - 1],
+            &game_states_history[history_index],
+        ) {
+            break;
+        }
+
+        no_progress_moves_count += 1;
+        // an even number of moves back, the same player was to move
+        if no_progress_moves_count % 2 == 0
+            && game_states_history[history_index - 1] == *current_game_state
+        {
+            repetitions_count += 1;
+        }
+    }
+
+    return no_progress_moves_count >= no_progress_moves_limit
+        || repetitions_count >= repetitions_for_draw;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::kuhn_poker::{
+        Action, ByteArrayGameStateSerializer, GameRulesAuthority as KuhnPokerGameRulesAuthority,
+        GameStateType as KuhnPokerGameState,
+    };
+
+    #[test]
+    fn test_recovering_hidden_game_state_from_observation() {
+        let game_rules_authority = KuhnPokerGameRulesAuthority {};
+        let game_state_serializer = ByteArrayGameStateSerializer {};
+
+        // The first player holds the king and checked, the second player only gets to see their own jack
+        let current_game_state = KuhnPokerGameState {
+            player_cards: [3, 1],
+            actions: vec![Action::Pass],
+        };
+        let observed_game_state = game_rules_authority.observe_game_state(1, &current_game_state);
+        assert_eq!(observed_game_state.player_cards, [0, 1]);
+
+        let mut new_observed_game_state = observed_game_state.clone();
+        new_observed_game_state.actions.push(Action::Bet);
+        let next_game_state = find_next_game_state_from_observation(
+            &game_rules_authority,
+            &game_state_serializer,
+            1,
+            &current_game_state,
+            &observed_game_state,
+            &new_observed_game_state,
+        );
+        assert_eq!(
+            next_game_state,
+            Some(KuhnPokerGameState {
+                player_cards: [3, 1],
+                actions: vec![Action::Pass, Action::Bet],
+            })
+        );
+
+        // A decision that doesn't match any of the available moves leads to no game state
+        let mut unavailable_observed_game_state = observed_game_state.clone();
+        unavailable_observed_game_state.actions = vec![Action::Bet, Action::Bet];
+        assert_eq!(
+            find_next_game_state_from_observation(
+                &game_rules_authority,
+                &game_state_serializer,
+                1,
+                &current_game_state,
+                &observed_game_state,
+                &unavailable_observed_game_state,
+            ),
+            None
+        );
+    }
+}
@@ -1,12 +1,16 @@
+pub mod analysis;
 pub mod composites;
 pub mod constants;
 pub mod enums;
 pub mod game_runners;
 pub mod game_state_records_providers;
+pub mod games;
 mod internal;
 pub mod persistence;
 pub mod simulating;
 pub mod structs;
+pub mod tournaments;
+#[cfg(feature = "torch")]
 pub mod training;
 pub mod traits;
 pub mod turn_takers;
@@ -1,6 +1,8 @@
 use argparse;
+#[cfg(feature = "torch")]
 use tch;
 
+mod analysis;
 mod cli;
 mod composites;
 mod constants;
@@ -12,6 +14,8 @@ mod internal;
 mod persistence;
 mod simulating;
 mod structs;
+mod tournaments;
+#[cfg(feature = "torch")]
 mod training;
 mod traits;
 mod turn_takers;
@@ -28,7 +32,7 @@ fn main() -> Result<(), ()> {
         ap.refer(&mut command).required().add_argument(
             "command",
             argparse::Store,
-            r#"Command to run (either "interactive-game" or "simulate-games")"#,
+            r#"Command to run (either "evaluate-records", "interactive-game", "perft", "simulate-games" or "tournament")"#,
         );
 
         ap.refer(&mut args)
@@ -38,11 +42,17 @@ fn main() -> Result<(), ()> {
         ap.parse_args_or_exit();
     }
 
+    #[cfg(feature = "torch")]
     tch::maybe_init_cuda();
 
+    let game_registry = cli::GameRegistry::new();
+
     args.insert(0, format!("command {:?}", command));
     match command {
-        cli::enums::Command::InteractiveGame => return cli::interactive_game(args),
-        cli::enums::Command::SimulateGames => return cli::simulate_games(args),
+        cli::enums::Command::EvaluateRecords => return cli::evaluate_records(args, &game_registry),
+        cli::enums::Command::InteractiveGame => return cli::interactive_game(args, &game_registry),
+        cli::enums::Command::Perft => return cli::perft(args, &game_registry),
+        cli::enums::Command::SimulateGames => return cli::simulate_games(args, &game_registry),
+        cli::enums::Command::Tournament => return cli::tournament(args, &game_registry),
     }
 }
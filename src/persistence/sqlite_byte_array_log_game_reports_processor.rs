@@ -1,42 +1,58 @@
 use crate::structs::GameReport;
-use crate::traits::{GameReportsProcessor, PendingUpdatesManager};
+use crate::traits::{
+    ByteArrayConvertibleSerializedGameState, GameReportsProcessor, PendingUpdatesManager,
+};
 use rusqlite::{Connection, Transaction};
-use std::cell::RefCell;
+use std::sync::Mutex;
 use std::thread;
 
 const MAX_ATTEMPTS_PER_GAME_REPORT: u8 = 3;
 
-pub struct SqliteByteArrayLogGameReportsProcessor {
+/// Logs every game as the byte arrays of its serialized game states put one after the other.
+pub struct SqliteByteArrayLogGameReportsProcessor<
+    SerializedGameState: ByteArrayConvertibleSerializedGameState,
+> {
     game_name: String,
     log_serializer_version: i32,
     max_batch_size: usize,
-    pending_game_reports_ref_cell: RefCell<Vec<GameReport<Vec<u8>>>>,
+    pending_game_reports_mutex: Mutex<Vec<GameReport<SerializedGameState>>>,
     sqlite_db_path: String,
 }
 
-impl SqliteByteArrayLogGameReportsProcessor {
+impl<SerializedGameState: ByteArrayConvertibleSerializedGameState>
+    SqliteByteArrayLogGameReportsProcessor<SerializedGameState>
+{
     pub fn new(
         game_name: &str,
         log_serializer_version: i32,
         max_batch_size: usize,
         sqlite_db_path: &str,
-    ) -> SqliteByteArrayLogGameReportsProcessor {
+    ) -> SqliteByteArrayLogGameReportsProcessor<SerializedGameState> {
         return SqliteByteArrayLogGameReportsProcessor {
             game_name: String::from(game_name),
             log_serializer_version: log_serializer_version,
             max_batch_size: max_batch_size,
-            pending_game_reports_ref_cell: RefCell::new(vec![]),
+            pending_game_reports_mutex: Mutex::new(vec![]),
             sqlite_db_path: String::from(sqlite_db_path),
         };
     }
 }
 
-impl GameReportsProcessor<Vec<u8>, ()> for SqliteByteArrayLogGameReportsProcessor {
-    fn process_game_report(&self, game_report: &mut GameReport<Vec<u8>>) -> Result<(), ()> {
-        self.pending_game_reports_ref_cell
-            .borrow_mut()
-            .push(game_report.clone());
-        if self.pending_game_reports_ref_cell.borrow().len() >= self.max_batch_size {
+impl<SerializedGameState: ByteArrayConvertibleSerializedGameState>
+    GameReportsProcessor<SerializedGameState, ()>
+    for SqliteByteArrayLogGameReportsProcessor<SerializedGameState>
+{
+    fn process_game_report(
+        &self,
+        game_report: &mut GameReport<SerializedGameState>,
+    ) -> Result<(), ()> {
+        let pending_game_reports_count = {
+            let mut pending_game_reports = self.pending_game_reports_mutex.lock().unwrap();
+            pending_game_reports.push(game_report.clone());
+            pending_game_reports.len()
+        };
+
+        if pending_game_reports_count >= self.max_batch_size {
             self.try_commit_pending_updates_in_background(self.max_batch_size as usize);
         }
 
@@ -44,17 +60,28 @@ impl GameReportsProcessor<Vec<u8>, ()> for SqliteByteArrayLogGameReportsProcesso
     }
 }
 
-impl PendingUpdatesManager for SqliteByteArrayLogGameReportsProcessor {
+impl<SerializedGameState: ByteArrayConvertibleSerializedGameState> PendingUpdatesManager
+    for SqliteByteArrayLogGameReportsProcessor<SerializedGameState>
+{
     fn try_commit_pending_updates_in_background(
         &self,
         max_number_to_commit: usize,
     ) -> std::thread::JoinHandle<()> {
-        let mut game_reports_to_commit: Vec<GameReport<Vec<u8>>> = vec![];
-        while let Some(game_report) = self.pending_game_reports_ref_cell.borrow_mut().pop() {
-            game_reports_to_commit.push(game_report);
+        // Holds the log entry of every game to commit along with the index of its winning player
+        let mut log_entries_to_commit: Vec<(Vec<u8>, i32)> = vec![];
+        {
+            let mut pending_game_reports = self.pending_game_reports_mutex.lock().unwrap();
+            while let Some(mut game_report) = pending_game_reports.pop() {
+                let mut log_entry: Vec<u8> = vec![];
+                while let Some(game_state_update) = game_report.game_state_updates.pop() {
+                    log_entry
+                        .append(&mut game_state_update.new_serialized_game_state.to_byte_array());
+                }
+                log_entries_to_commit.push((log_entry, game_report.winning_player_index));
 
-            if game_reports_to_commit.len() == max_number_to_commit {
-                break;
+                if log_entries_to_commit.len() == max_number_to_commit {
+                    break;
+                }
             }
         }
 
@@ -85,19 +112,14 @@ impl PendingUpdatesManager for SqliteByteArrayLogGameReportsProcessor {
                 }
             }
 
-            while let Some(mut game_report) = game_reports_to_commit.pop() {
+            while let Some((log_entry, winning_player_index)) = log_entries_to_commit.pop() {
                 let mut attempts_counter: u8 = 0;
 
-                let mut log_entry: Vec<u8> = vec![];
-                while let Some(mut game_state_update) = game_report.game_state_updates.pop() {
-                    log_entry.append(&mut game_state_update.new_serialized_game_state);
-                }
-
                 'attempts_loop: while attempts_counter < MAX_ATTEMPTS_PER_GAME_REPORT {
                     let execute_result = sqlite_transaction
                         .execute(
                             "INSERT INTO GameLogs (GameName, Log, LogSerializerVersion, WinningPlayerIndex) VALUES (?1, ?2, ?3, ?4)",
-                            rusqlite::params![game_name, log_entry, log_serializer_version, game_report.winning_player_index],
+                            rusqlite::params![game_name, log_entry, log_serializer_version, winning_player_index],
                         );
 
                     match execute_result {
@@ -1,10 +1,11 @@
 use crate::structs::{GameStateRecord, IncrementPersistedGameStateRecordValuesTask};
 use crate::traits::{
-    BasicSerializedGameState, GameStateRecordUpdatesPersister, GameStateRecordsDAL,
-    GameStateRecordsFetcher,
+    BasicSerializedGameState, ByteArrayConvertibleSerializedGameState,
+    GameStateRecordUpdatesPersister, GameStateRecordsDAL, GameStateRecordsFetcher,
 };
 use rusqlite::Connection;
 use rusqlite::Error::QueryReturnedNoRows;
+use std::sync::Mutex;
 use std::thread;
 
 const MAX_ATTEMPTS_PER_UPDATE: u8 = 3;
@@ -12,21 +13,29 @@ const MAX_ATTEMPTS_PER_UPDATE: u8 = 3;
 pub struct SqliteGameStateRecordsDAL {
     is_saving_enabled: bool,
     game_name: String,
-    read_only_connection: Connection,
+    read_only_connection_mutex: Mutex<Connection>,
     sqlite_db_path: String,
 }
 
 impl SqliteGameStateRecordsDAL {
+    /// Records of later versions than the first one are stored under the game name suffixed with their version,
+    /// while the ones of the first version keep being stored under the bare game name they were always stored under.
     pub fn new(
         game_name: &str,
+        game_state_records_version: i32,
         sqlite_db_path: &str,
     ) -> Result<SqliteGameStateRecordsDAL, rusqlite::Error> {
         let read_only_connection = Connection::open(&sqlite_db_path)?;
+        let records_game_name = if game_state_records_version == 1 {
+            String::from(game_name)
+        } else {
+            format!("{}@v{}", game_name, game_state_records_version)
+        };
 
         return Ok(SqliteGameStateRecordsDAL {
             is_saving_enabled: true,
-            game_name: String::from(game_name),
-            read_only_connection: read_only_connection,
+            game_name: records_game_name,
+            read_only_connection_mutex: Mutex::new(read_only_connection),
             sqlite_db_path: String::from(sqlite_db_path),
         });
     }
@@ -42,10 +51,31 @@ impl SqliteGameStateRecordsDAL {
 
 impl BasicSerializedGameState for Vec<u8> {}
 
-impl GameStateRecordsFetcher<Vec<u8>> for SqliteGameStateRecordsDAL {
-    fn get_game_state_record(&self, state_hash: &Vec<u8>) -> Option<GameStateRecord> {
-        match try_get_state_record_from_db(&self.read_only_connection, &self.game_name, &state_hash)
-        {
+impl ByteArrayConvertibleSerializedGameState for Vec<u8> {
+    fn to_byte_array(&self) -> Vec<u8> {
+        return self.clone();
+    }
+
+    fn from_byte_array(byte_array: &[u8]) -> Option<Vec<u8>> {
+        return Some(byte_array.to_vec());
+    }
+}
+
+/// Game states are stored by their byte arrays, whatever kind of serialized game state they come as.
+impl<SerializedGameState: ByteArrayConvertibleSerializedGameState>
+    GameStateRecordsFetcher<SerializedGameState> for SqliteGameStateRecordsDAL
+{
+    fn get_game_state_record(&self, state_hash: &SerializedGameState) -> Option<GameStateRecord> {
+        let read_only_connection = match self.read_only_connection_mutex.lock() {
+            Ok(value) => value,
+            Err(poisoned_error) => poisoned_error.into_inner(),
+        };
+
+        match try_get_state_record_from_db(
+            &read_only_connection,
+            &self.game_name,
+            &state_hash.to_byte_array(),
+        ) {
             Ok(Some(state_record)) => return Some(state_record),
             Ok(None) => return None,
             Err(err) => {
@@ -59,11 +89,26 @@ impl GameStateRecordsFetcher<Vec<u8>> for SqliteGameStateRecordsDAL {
     }
 }
 
-impl GameStateRecordUpdatesPersister<Vec<u8>> for SqliteGameStateRecordsDAL {
+impl<SerializedGameState: ByteArrayConvertibleSerializedGameState>
+    GameStateRecordUpdatesPersister<SerializedGameState> for SqliteGameStateRecordsDAL
+{
     fn increment_game_state_records_values_in_background(
         &self,
-        increment_tasks: Vec<IncrementPersistedGameStateRecordValuesTask<Vec<u8>>>,
+        increment_tasks: Vec<IncrementPersistedGameStateRecordValuesTask<SerializedGameState>>,
     ) -> thread::JoinHandle<()> {
+        // The tasks are handed over to the background thread with their game states as byte arrays
+        let increment_tasks: Vec<IncrementPersistedGameStateRecordValuesTask<Vec<u8>>> =
+            increment_tasks
+                .iter()
+                .map(
+                    |increment_task| IncrementPersistedGameStateRecordValuesTask {
+                        serialized_game_state: increment_task.serialized_game_state.to_byte_array(),
+                        draws_count_addend: increment_task.draws_count_addend,
+                        losses_count_addend: increment_task.losses_count_addend,
+                        wins_count_addend: increment_task.wins_count_addend,
+                    },
+                )
+                .collect();
         let is_saving_enabled = self.is_saving_enabled;
         let sqlite_db_path = self.sqlite_db_path.clone();
         let game_name = self.game_name.clone();
@@ -121,16 +166,19 @@ impl GameStateRecordUpdatesPersister<Vec<u8>> for SqliteGameStateRecordsDAL {
     }
 }
 
-impl GameStateRecordsDAL<Vec<u8>> for SqliteGameStateRecordsDAL {}
+impl<SerializedGameState: ByteArrayConvertibleSerializedGameState>
+    GameStateRecordsDAL<SerializedGameState> for SqliteGameStateRecordsDAL
+{
+}
 
 fn try_get_state_record_from_db(
     connection: &Connection,
     game_name: &str,
-    state_hash: &Vec<u8>,
+    state_hash: &[u8],
 ) -> rusqlite::Result<Option<GameStateRecord>> {
     let query_result = connection.query_row(
         "SELECT DrawsCount, LossesCount, WinsCount FROM GameStateRecords WHERE GameName = ?1 AND StateHash = ?2",
-        rusqlite::params![game_name, &state_hash],
+        rusqlite::params![game_name, state_hash],
         |row| {
             return Ok(GameStateRecord {
                 draws_count: row.get(0)?,
@@ -1,2 +1,5 @@
+mod parallel_simulator;
+pub use parallel_simulator::ParallelSimulator;
+
 mod standard_simulator;
 pub use standard_simulator::StandardSimulator;
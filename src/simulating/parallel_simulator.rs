@@ -0,0 +1,311 @@
+use crate::enums::RunGameError;
+use crate::structs::GameReport;
+use crate::traits::{BasicSerializedGameState, GameReportsProcessor, PendingUpdatesManager};
+use chrono::Local;
+use crossbeam::channel;
+use std::io::{stdout, Write};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Instant;
+
+/// Number of finished games each worker thread may queue up before waiting on the reports to be processed.
+const GAME_REPORTS_CHANNEL_CAPACITY_PER_THREAD: usize = 4;
+
+/// Plays games on several worker threads at once.
+/// Each worker plays whole games through the given play_game function, which is expected to build its own turn takers,
+/// and sends the resulting game reports through a channel back to the calling thread.
+/// The game reports processor only ever runs on the calling thread, one game report at a time,
+/// but anything that the play_game function shares between workers (such as a records provider) must be thread-safe.
+pub struct ParallelSimulator<
+    'a,
+    SerializedGameState: BasicSerializedGameState + Send,
+    GameReportsPersisterErrorType,
+> {
+    game_name: String,
+    game_reports_processor:
+        &'a dyn GameReportsProcessor<SerializedGameState, GameReportsPersisterErrorType>,
+    is_verbose: bool,
+    number_of_threads: usize,
+    pending_updates_managers: &'a Vec<&'a dyn PendingUpdatesManager>,
+}
+
+impl<'a, SerializedGameState: BasicSerializedGameState + Send, GameReportsPersisterErrorType>
+    ParallelSimulator<'a, SerializedGameState, GameReportsPersisterErrorType>
+{
+    pub fn new(
+        game_name: &str,
+        game_reports_processor: &'a dyn GameReportsProcessor<
+            SerializedGameState,
+            GameReportsPersisterErrorType,
+        >,
+        is_verbose: bool,
+        number_of_threads: usize,
+        pending_updates_managers: &'a Vec<&'a dyn PendingUpdatesManager>,
+    ) -> ParallelSimulator<'a, SerializedGameState, GameReportsPersisterErrorType> {
+        return ParallelSimulator {
+            game_name: String::from(game_name),
+            game_reports_processor: game_reports_processor,
+            is_verbose: is_verbose,
+            number_of_threads: number_of_threads.max(1),
+            pending_updates_managers: pending_updates_managers,
+        };
+    }
+
+    /// Runs the given number of games, calling play_game with the number of each game from the worker threads.
+    pub fn run_simulations(
+        &self,
+        number_of_games: u32,
+        play_game: &(dyn Fn(u32) -> Result<Option<GameReport<SerializedGameState>>, RunGameError>
+              + Sync),
+    ) -> Result<(), GameReportsPersisterErrorType> {
+        self.write_line_if_verbose(
+            &format!(
+                "Starting simulation of {} games of {} on {} threads. Initial start date and time is {}.",
+                number_of_games,
+                &self.game_name,
+                self.number_of_threads,
+                Local::now().format("%Y-%m-%d - %H:%M:%S"),
+            )[..],
+        );
+
+        let simulations_start_instant = Instant::now();
+        let next_game_number = AtomicU32::new(0);
+        let (run_game_results_sender, run_game_results_receiver) =
+            channel::bounded(self.number_of_threads * GAME_REPORTS_CHANNEL_CAPACITY_PER_THREAD);
+
+        let simulations_result = crossbeam::scope(|scope| {
+            for _ in 0..self.number_of_threads {
+                let run_game_results_sender = run_game_results_sender.clone();
+                let next_game_number = &next_game_number;
+
+                scope.spawn(move |_| loop {
+                    let game_number = next_game_number.fetch_add(1, Ordering::SeqCst);
+                    if game_number >= number_of_games {
+                        return;
+                    }
+
+                    if run_game_results_sender
+                        .send(play_game(game_number))
+                        .is_err()
+                    {
+                        // The receiving end is gone, so nobody is waiting on any more games
+                        return;
+                    }
+                });
+            }
+
+            // Only the workers should be holding senders now so that the receiver stops once they're all done
+            drop(run_game_results_sender);
+
+            return self.process_run_game_results(number_of_games, run_game_results_receiver);
+        })
+        .expect("A simulation worker thread panicked");
+
+        self.write_line_if_verbose("");
+        self.write_line_if_verbose(
+            &format!(
+                "Simulations complete. Duration: {:?}.",
+                simulations_start_instant.elapsed()
+            )[..],
+        );
+
+        let (
+            draws_count,
+            failed_games_count,
+            inconclusive_games_count,
+            wins_counts_by_player_index,
+        ) = simulations_result?;
+
+        self.write_line_if_verbose(
+            &format!(
+                "Number of games that failed to run: {}.",
+                failed_games_count
+            )[..],
+        );
+        self.write_line_if_verbose(
+            &format!(
+                "Number of games that were inconclusive: {}.",
+                inconclusive_games_count
+            )[..],
+        );
+        self.write_line_if_verbose(
+            &format!(
+                "Number of games that ended in a draw or with the best rank shared: {}.",
+                draws_count
+            )[..],
+        );
+        self.write_line_if_verbose(
+            &format!(
+                "Number of games won by player index: {:#?}.",
+                wins_counts_by_player_index
+            )[..],
+        );
+
+        self.write_line_if_verbose("Waiting for all pending updates to be committed.");
+        let pending_updates_start_instant = Instant::now();
+
+        for pending_updates_manager in self.pending_updates_managers.iter() {
+            pending_updates_manager
+                .try_commit_pending_updates_in_background(usize::MAX)
+                .join()
+                .expect("Failed to commit pending updates");
+        }
+
+        self.write_line_if_verbose(
+            &format!(
+                "Pending updates commited. Duration: {:?}.",
+                pending_updates_start_instant.elapsed()
+            )[..],
+        );
+
+        self.write_line_if_verbose("Done training.");
+        return Ok(());
+    }
+
+    /// Processes the results sent by the workers until all of them are done.
+    /// Returns the number of draws, the number of games that failed to run, the number of inconclusive games
+    /// and the number of wins by player index.
+    fn process_run_game_results(
+        &self,
+        number_of_games: u32,
+        run_game_results_receiver: channel::Receiver<
+            Result<Option<GameReport<SerializedGameState>>, RunGameError>,
+        >,
+    ) -> Result<(i32, i32, i32, Vec<i32>), GameReportsPersisterErrorType> {
+        let mut draws_count = 0;
+        let mut failed_games_count = 0;
+        let mut inconclusive_games_count = 0;
+        let mut wins_counts_by_player_index: Vec<i32> = vec![];
+
+        let mut stdout = stdout();
+
+        let mut displayed_progress_percentage: i32 = -1;
+        let mut completed_games_count: u32 = 0;
+        for run_game_result in run_game_results_receiver.iter() {
+            match run_game_result {
+                Ok(Some(mut game_report)) => {
+                    wins_counts_by_player_index.resize(
+                        wins_counts_by_player_index
+                            .len()
+                            .max(game_report.number_of_players as usize),
+                        0,
+                    );
+                    if game_report.winning_player_index >= 0 {
+                        wins_counts_by_player_index[game_report.winning_player_index as usize] += 1;
+                    } else if game_report.winning_player_index == -1 {
+                        draws_count += 1;
+                    }
+
+                    // Returning early drops the receiver, which in turn stops the workers
+                    self.game_reports_processor
+                        .process_game_report(&mut game_report)?;
+                }
+                Ok(None) => inconclusive_games_count += 1,
+                Err(err) => {
+                    failed_games_count += 1;
+                    println!("Failed to run game: {:?}", err);
+                }
+            }
+
+            completed_games_count += 1;
+            let new_displayed_progress_percentage =
+                ((completed_games_count as u64 * 100) / number_of_games as u64) as i32;
+            if new_displayed_progress_percentage > displayed_progress_percentage {
+                displayed_progress_percentage = new_displayed_progress_percentage;
+                if self.is_verbose {
+                    print!(
+                        "\rCompleted {}% of {} simulations",
+                        new_displayed_progress_percentage, number_of_games
+                    );
+                    stdout.flush().unwrap();
+                }
+            }
+        }
+
+        return Ok((
+            draws_count,
+            failed_games_count,
+            inconclusive_games_count,
+            wins_counts_by_player_index,
+        ));
+    }
+
+    fn write_line_if_verbose(&self, text: &str) {
+        if self.is_verbose {
+            println!("{}", text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_runners::StandardTurnBasedGameRunner;
+    use crate::games::tic_tac_toe::{
+        create_initial_game_state, FixedWidthGameStateSerializer, GameRulesAuthority,
+    };
+    use crate::structs::FixedWidthSerializedGameState;
+    use crate::traits::{GameRunner, TurnTaker};
+    use crate::turn_takers::WeightedRandomSelectionTurnTaker;
+    use crate::weights_calculators::UniformGameStateWeightsCalculator;
+    use std::cell::Cell;
+    use std::sync::Mutex;
+
+    struct CountingGameReportsProcessor {
+        game_reports_count: Cell<u32>,
+    }
+
+    impl GameReportsProcessor<FixedWidthSerializedGameState, ()> for CountingGameReportsProcessor {
+        fn process_game_report(
+            &self,
+            _game_report: &mut GameReport<FixedWidthSerializedGameState>,
+        ) -> Result<(), ()> {
+            self.game_reports_count
+                .set(self.game_reports_count.get() + 1);
+            return Ok(());
+        }
+    }
+
+    #[test]
+    fn test_processing_every_game_played_on_several_threads() {
+        let game_rules_authority = GameRulesAuthority {};
+        let game_state_serializer = FixedWidthGameStateSerializer {};
+        let weights_calculator = UniformGameStateWeightsCalculator {};
+        let game_reports_processor = CountingGameReportsProcessor {
+            game_reports_count: Cell::new(0),
+        };
+        let pending_updates_managers: Vec<&dyn PendingUpdatesManager> = vec![];
+        let played_game_numbers: Mutex<Vec<u32>> = Mutex::new(vec![]);
+
+        let play_game = |game_number: u32| {
+            played_game_numbers.lock().unwrap().push(game_number);
+            let game_runner =
+                StandardTurnBasedGameRunner::new(&game_rules_authority, &game_state_serializer);
+            let first_turn_taker = WeightedRandomSelectionTurnTaker::new(
+                &game_rules_authority,
+                &weights_calculator,
+                0,
+            );
+            let second_turn_taker = WeightedRandomSelectionTurnTaker::new(
+                &game_rules_authority,
+                &weights_calculator,
+                1,
+            );
+            let turn_takers: Vec<&dyn TurnTaker<_>> = vec![&first_turn_taker, &second_turn_taker];
+            return game_runner.run_game(create_initial_game_state(), &turn_takers, -1, true);
+        };
+
+        let simulator = ParallelSimulator::new(
+            "tic-tac-toe",
+            &game_reports_processor,
+            false,
+            4,
+            &pending_updates_managers,
+        );
+        simulator.run_simulations(100, &play_game).unwrap();
+
+        assert_eq!(game_reports_processor.game_reports_count.get(), 100);
+        let mut played_game_numbers = played_game_numbers.into_inner().unwrap();
+        played_game_numbers.sort();
+        assert_eq!(played_game_numbers, (0..100).collect::<Vec<u32>>());
+    }
+}
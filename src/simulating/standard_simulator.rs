@@ -1,3 +1,4 @@
+use crate::structs::GameReport;
 use crate::traits::{
     BasicGameState, BasicSerializedGameState, GameReportsProcessor, GameRunner,
     PendingUpdatesManager, TurnTaker,
@@ -49,7 +50,7 @@ impl<
     pub fn run_simulations<'b>(
         &mut self,
         number_of_games: u32,
-        create_initial_game_state: fn() -> GameState,
+        create_initial_game_state: &dyn Fn() -> GameState,
         create_turn_takers: &mut dyn FnMut() -> Vec<&'b dyn TurnTaker<GameState>>,
         max_number_of_turns: i32,
         is_reaching_max_number_of_turns_a_draw: bool,
@@ -65,11 +66,18 @@ impl<
 
         let mut draws_count = 0;
         let mut inconclusive_games_count = 0;
-        let mut wins_counts_by_player_index = vec![0; 2];
-        let mut update_result_counts = |winning_player_index: i32| {
-            if winning_player_index >= 0 {
-                wins_counts_by_player_index[winning_player_index as usize] += 1;
-            } else if winning_player_index == -1 {
+        let mut wins_counts_by_player_index: Vec<i32> = vec![];
+        let mut update_result_counts = |game_report: &GameReport<SerializedGameState>| {
+            // Games are only known to have as many players as their turn takers once they are played
+            wins_counts_by_player_index.resize(
+                wins_counts_by_player_index
+                    .len()
+                    .max(game_report.number_of_players as usize),
+                0,
+            );
+            if game_report.winning_player_index >= 0 {
+                wins_counts_by_player_index[game_report.winning_player_index as usize] += 1;
+            } else if game_report.winning_player_index == -1 {
                 draws_count += 1;
             }
         };
@@ -90,7 +98,7 @@ impl<
             match run_game_result {
                 Ok(game_report_option) => match game_report_option {
                     Some(game_report) => {
-                        update_result_counts(game_report.winning_player_index);
+                        update_result_counts(&game_report);
                         self.game_reports_processor
                             .process_game_report(&mut game_report.clone())?;
                     }
@@ -128,7 +136,10 @@ impl<
             )[..],
         );
         self.write_line_if_verbose(
-            &format!("Number of games that ended in a draw: {}.", draws_count)[..],
+            &format!(
+                "Number of games that ended in a draw or with the best rank shared: {}.",
+                draws_count
+            )[..],
         );
         self.write_line_if_verbose(
             &format!(
@@ -1,5 +1,48 @@
-use crate::traits::BasicSerializedGameState;
+#[cfg(feature = "torch")]
+use crate::traits::PolicyValueNet;
+use crate::traits::{
+    BasicGameState, BasicSerializedGameState, ByteArrayConvertibleSerializedGameState, TurnTaker,
+};
+#[cfg(feature = "torch")]
+use serde::Deserialize;
 use std::hash::Hash;
+#[cfg(feature = "torch")]
+use tch::{nn, Tensor};
+
+/// A serialized game state packed into 128 bits, for games small enough to fit.
+/// Unlike a byte vector, it lives on the stack and is copied around without any allocation.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FixedWidthSerializedGameState {
+    pub bits: u128,
+}
+
+impl FixedWidthSerializedGameState {
+    pub fn new(bits: u128) -> FixedWidthSerializedGameState {
+        return FixedWidthSerializedGameState { bits: bits };
+    }
+}
+
+impl BasicSerializedGameState for FixedWidthSerializedGameState {}
+
+impl ByteArrayConvertibleSerializedGameState for FixedWidthSerializedGameState {
+    /// Always writes all 16 bytes, leading zeros included, on purpose: game logs are the byte arrays of their game states
+    /// put one after the other, and the fixed width is what lets them be split back into game states.
+    fn to_byte_array(&self) -> Vec<u8> {
+        return self.bits.to_be_bytes().to_vec();
+    }
+
+    fn from_byte_array(byte_array: &[u8]) -> Option<FixedWidthSerializedGameState> {
+        let mut bytes = [0_u8; 16];
+        if byte_array.len() != bytes.len() {
+            return None;
+        }
+
+        bytes.copy_from_slice(byte_array);
+        return Some(FixedWidthSerializedGameState::new(u128::from_be_bytes(
+            bytes,
+        )));
+    }
+}
 
 #[derive(Copy, Clone)]
 pub struct GameStateRecord {
@@ -32,10 +75,27 @@ pub struct GameStateUpdate<SerializedGameState: BasicSerializedGameState> {
     pub responsible_player_index: i32,
 }
 
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MatchupRecord {
+    pub draws_count: u32,
+    pub losses_count: u32,
+    pub wins_count: u32,
+}
+
 #[derive(Clone, Debug)]
 pub struct GameReport<SerializedGameState: BasicSerializedGameState> {
+    /// The notation and the policy tensor index of the action behind each game state update, for games whose actions the game runner knows.
+    /// Updates that no action is behind, such as the initial game state and the outcomes of chance events, have no action.
+    pub actions: Option<Vec<Option<(String, usize)>>>,
     pub game_state_updates: Vec<GameStateUpdate<SerializedGameState>>,
+    /// For games in which all players move at once, the move each player made in each round, indexed by round and then by player index.
+    /// A move is recorded as the serialized observation of the game state the player moved to. Empty for turn based games.
+    pub joint_actions: Vec<Vec<SerializedGameState>>,
     pub number_of_players: i32,
+    /// The rank each player finished the game at, indexed by player index.
+    /// 0 is the best rank and players who tied share the same rank.
+    pub player_ranks: Vec<i32>,
+    /// The index of the player alone at the best rank, or -1 if no single player won the game.
     pub winning_player_index: i32,
 }
 
@@ -48,3 +108,54 @@ pub struct IncrementPersistedGameStateRecordValuesTask<
     pub losses_count_addend: i32,
     pub wins_count_addend: i32,
 }
+
+/// The neural nets of a game, along with the functions to feed its game states to them.
+#[cfg(feature = "torch")]
+pub struct NeuralNetDefinition<GameState: BasicGameState> {
+    pub create_policy_value_net: fn(&nn::Path) -> Box<dyn PolicyValueNet + Sync>,
+    pub create_value_net: fn(&nn::Path) -> Box<dyn nn::Module + Sync>,
+    pub find_move_policy_index: fn(i32, &GameState, &GameState) -> Option<usize>,
+    pub move_policy_size: usize,
+    pub transform_game_state_to_tensor: fn(i32, &GameState) -> Tensor,
+}
+
+/// Controls how a trainer samples from its replay buffer.
+/// Every games_per_training_round games, training_steps_per_round optimizer steps are taken,
+/// each on a minibatch of minibatch_size samples drawn at random from the replay buffer.
+#[cfg(feature = "torch")]
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReplayBufferTrainingParameters {
+    pub games_per_training_round: u32,
+    pub learning_rate: f64,
+    pub minibatch_size: usize,
+    pub replay_buffer_capacity: usize,
+    pub training_steps_per_round: u32,
+}
+
+#[cfg(feature = "torch")]
+impl Default for ReplayBufferTrainingParameters {
+    fn default() -> ReplayBufferTrainingParameters {
+        return ReplayBufferTrainingParameters {
+            games_per_training_round: 8,
+            learning_rate: 1e-4,
+            minibatch_size: 256,
+            replay_buffer_capacity: 100_000,
+            training_steps_per_round: 16,
+        };
+    }
+}
+
+pub struct TournamentAgent<'a, GameState: BasicGameState> {
+    pub name: String,
+    /// The turn takers playing on behalf of the agent, indexed by the player index they play as.
+    pub turn_takers: Vec<&'a dyn TurnTaker<GameState>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TournamentResults {
+    pub agent_names: Vec<String>,
+    pub inconclusive_games_count: u32,
+    /// The record of the agent at the first index against the agent at the second index.
+    pub matchup_records: Vec<Vec<MatchupRecord>>,
+}
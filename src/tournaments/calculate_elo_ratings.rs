@@ -0,0 +1,138 @@
+use crate::structs::MatchupRecord;
+
+const AVERAGE_RATING: f64 = 1500.0;
+const MAX_ITERATIONS: u32 = 10_000;
+const CONVERGENCE_TOLERANCE: f64 = 1e-9;
+/// Number of virtual drawn games added to every matchup.
+/// This keeps the ratings of agents that never won (or never lost) finite.
+const PRIOR_DRAWS_COUNT: f64 = 1.0;
+
+/// Fits Elo ratings to the results of all matchups at once, using the minorization-maximization algorithm
+/// for the Bradley-Terry model with every draw counting as half a win for both agents.
+/// The ratings are centered so that their average is 1500.
+pub fn calculate_elo_ratings(matchup_records: &Vec<Vec<MatchupRecord>>) -> Vec<f64> {
+    let agents_count = matchup_records.len();
+
+    let mut games_counts = vec![vec![0.0; agents_count]; agents_count];
+    let mut scores = vec![0.0; agents_count];
+    for i in 0..agents_count {
+        for j in 0..agents_count {
+            if i == j {
+                continue;
+            }
+
+            let matchup_record = &matchup_records[i][j];
+            games_counts[i][j] = (matchup_record.wins_count
+                + matchup_record.draws_count
+                + matchup_record.losses_count) as f64
+                + PRIOR_DRAWS_COUNT;
+            scores[i] += matchup_record.wins_count as f64
+                + 0.5 * matchup_record.draws_count as f64
+                + 0.5 * PRIOR_DRAWS_COUNT;
+        }
+    }
+
+    let mut strengths = vec![1.0; agents_count];
+    for _ in 0..MAX_ITERATIONS {
+        let mut new_strengths: Vec<f64> = (0..agents_count)
+            .map(|i| {
+                let mut denominator = 0.0;
+                for j in 0..agents_count {
+                    if i != j {
+                        denominator += games_counts[i][j] / (strengths[i] + strengths[j]);
+                    }
+                }
+
+                return if denominator > 0.0 {
+                    scores[i] / denominator
+                } else {
+                    strengths[i]
+                };
+            })
+            .collect();
+
+        // Strengths are only defined up to a common factor, so keep their geometric mean at 1
+        let log_strengths_mean = new_strengths
+            .iter()
+            .map(|strength| strength.ln())
+            .sum::<f64>()
+            / agents_count as f64;
+        for strength in new_strengths.iter_mut() {
+            *strength /= log_strengths_mean.exp();
+        }
+
+        let max_change = new_strengths
+            .iter()
+            .zip(strengths.iter())
+            .map(|(new_strength, strength)| (new_strength - strength).abs())
+            .fold(0.0, f64::max);
+        strengths = new_strengths;
+
+        if max_change < CONVERGENCE_TOLERANCE {
+            break;
+        }
+    }
+
+    return strengths
+        .iter()
+        .map(|strength| AVERAGE_RATING + 400.0 * strength.log10())
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds the matchup records of the agents from the number of games each agent won against each other agent.
+    fn create_matchup_records(wins_counts: &Vec<Vec<u32>>) -> Vec<Vec<MatchupRecord>> {
+        return (0..wins_counts.len())
+            .map(|i| {
+                return (0..wins_counts.len())
+                    .map(|j| MatchupRecord {
+                        draws_count: 0,
+                        losses_count: wins_counts[j][i],
+                        wins_count: wins_counts[i][j],
+                    })
+                    .collect();
+            })
+            .collect();
+    }
+
+    #[test]
+    fn test_symmetric_results_give_equal_ratings() {
+        let elo_ratings = calculate_elo_ratings(&create_matchup_records(&vec![
+            vec![0, 5, 5],
+            vec![5, 0, 5],
+            vec![5, 5, 0],
+        ]));
+
+        for elo_rating in elo_ratings.iter() {
+            assert!((elo_rating - AVERAGE_RATING).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_dominant_agent_rated_highest() {
+        let elo_ratings = calculate_elo_ratings(&create_matchup_records(&vec![
+            vec![0, 9, 10],
+            vec![1, 0, 6],
+            vec![0, 4, 0],
+        ]));
+
+        assert!(elo_ratings[0] > elo_ratings[1]);
+        assert!(elo_ratings[1] > elo_ratings[2]);
+    }
+
+    #[test]
+    fn test_ratings_average_stays_at_1500() {
+        let elo_ratings = calculate_elo_ratings(&create_matchup_records(&vec![
+            vec![0, 7, 3, 10],
+            vec![3, 0, 8, 2],
+            vec![7, 2, 0, 5],
+            vec![0, 8, 5, 0],
+        ]));
+
+        let elo_ratings_mean = elo_ratings.iter().sum::<f64>() / elo_ratings.len() as f64;
+        assert!((elo_ratings_mean - AVERAGE_RATING).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,5 @@
+mod calculate_elo_ratings;
+pub use calculate_elo_ratings::calculate_elo_ratings;
+
+mod round_robin_tournament;
+pub use round_robin_tournament::RoundRobinTournament;
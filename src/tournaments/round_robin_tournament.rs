@@ -0,0 +1,122 @@
+use crate::structs::{MatchupRecord, TournamentAgent, TournamentResults};
+use crate::traits::{BasicGameState, BasicSerializedGameState, GameRunner};
+use std::io::{stdout, Write};
+
+/// Plays every pairing of agents against each other, with each agent of a pairing
+/// playing the same number of games as the first player and as the second player.
+pub struct RoundRobinTournament<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+> {
+    game_runner: &'a dyn GameRunner<GameState, SerializedGameState>,
+    is_verbose: bool,
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
+    RoundRobinTournament<'a, GameState, SerializedGameState>
+{
+    pub fn new(
+        game_runner: &'a dyn GameRunner<GameState, SerializedGameState>,
+        is_verbose: bool,
+    ) -> RoundRobinTournament<'a, GameState, SerializedGameState> {
+        return RoundRobinTournament {
+            game_runner: game_runner,
+            is_verbose: is_verbose,
+        };
+    }
+
+    pub fn run_tournament(
+        &self,
+        agents: &Vec<TournamentAgent<GameState>>,
+        create_initial_game_state: &dyn Fn() -> GameState,
+        number_of_games_per_side: u32,
+        max_number_of_turns: i32,
+        is_reaching_max_number_of_turns_a_draw: bool,
+    ) -> TournamentResults {
+        let mut matchup_records = vec![vec![MatchupRecord::default(); agents.len()]; agents.len()];
+        let mut inconclusive_games_count = 0;
+
+        let mut stdout = stdout();
+
+        for first_agent_index in 0..agents.len() {
+            for second_agent_index in first_agent_index + 1..agents.len() {
+                // Every agent gets to play both sides of the pairing
+                for agent_indices_by_player_index in [
+                    [first_agent_index, second_agent_index],
+                    [second_agent_index, first_agent_index],
+                ]
+                .iter()
+                {
+                    let turn_takers = vec![
+                        agents[agent_indices_by_player_index[0]].turn_takers[0],
+                        agents[agent_indices_by_player_index[1]].turn_takers[1],
+                    ];
+
+                    for i in 0..number_of_games_per_side {
+                        if self.is_verbose {
+                            print!(
+                                "\r{} vs {} ({} playing first): game {} of {}",
+                                agents[first_agent_index].name,
+                                agents[second_agent_index].name,
+                                agents[agent_indices_by_player_index[0]].name,
+                                i + 1,
+                                number_of_games_per_side
+                            );
+                            stdout.flush().unwrap();
+                        }
+
+                        let run_game_result = self.game_runner.run_game(
+                            create_initial_game_state(),
+                            &turn_takers,
+                            max_number_of_turns,
+                            is_reaching_max_number_of_turns_a_draw,
+                        );
+
+                        let winning_player_index = match run_game_result {
+                            Ok(Some(game_report)) => game_report.winning_player_index,
+                            Ok(None) => {
+                                inconclusive_games_count += 1;
+                                continue;
+                            }
+                            Err(err) => {
+                                println!(
+                                    "\nFailed to run game with {} playing first: {:?}",
+                                    agents[agent_indices_by_player_index[0]].name, err
+                                );
+                                inconclusive_games_count += 1;
+                                continue;
+                            }
+                        };
+
+                        for player_index in 0..2 {
+                            let agent_index = agent_indices_by_player_index[player_index];
+                            let opponent_agent_index =
+                                agent_indices_by_player_index[1 - player_index];
+                            let matchup_record =
+                                &mut matchup_records[agent_index][opponent_agent_index];
+
+                            if winning_player_index == -1 {
+                                matchup_record.draws_count += 1;
+                            } else if winning_player_index == player_index as i32 {
+                                matchup_record.wins_count += 1;
+                            } else {
+                                matchup_record.losses_count += 1;
+                            }
+                        }
+                    }
+                }
+
+                if self.is_verbose {
+                    println!();
+                }
+            }
+        }
+
+        return TournamentResults {
+            agent_names: agents.iter().map(|agent| agent.name.clone()).collect(),
+            inconclusive_games_count: inconclusive_games_count,
+            matchup_records: matchup_records,
+        };
+    }
+}
@@ -1,2 +1,8 @@
+mod policy_value_torch_net_trainer;
+pub use policy_value_torch_net_trainer::PolicyValueTorchNetTrainer;
+
+mod replay_buffer;
+pub use replay_buffer::ReplayBuffer;
+
 mod torch_net_trainer;
 pub use torch_net_trainer::TorchNetTrainer;
@@ -0,0 +1,298 @@
+use crate::internal::utility_functions;
+use crate::structs::{GameReport, ReplayBufferTrainingParameters};
+use crate::training::ReplayBuffer;
+use crate::traits::{
+    BasicGameState, BasicSerializedGameState, GameReportsProcessor, GameStateDeserializer,
+    GameStateSerializer, PendingUpdatesManager, PolicyValueNet, SearchPoliciesRecorder,
+};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use tch::{nn, nn::OptimizerConfig, Kind, Tensor};
+
+const MAX_PENDING_UPDATES_COUNT: u32 = 1000;
+const MAX_PENDING_SEARCH_POLICIES_COUNT: usize = 100_000;
+
+/// A move policy stored as pairs of move policy index and probability, leaving out the moves that weren't searched.
+type SparseMovePolicy = Vec<(usize, f32)>;
+
+struct PendingSearchPolicies<SerializedGameState: BasicSerializedGameState> {
+    count: usize,
+    search_policies_by_serialized_game_state:
+        HashMap<SerializedGameState, VecDeque<SparseMovePolicy>>,
+}
+
+/// Trains a policy and value network, AlphaZero style, on the visit distributions of searches
+/// and on the outcomes of the games those searches were made in.
+/// Search policies are recorded while games are still being played and only become training samples
+/// once a game report going through their position arrives, as that's when the outcome for the player to move is known.
+/// Positions are matched by their serialized game state, so when several games are played at once
+/// a search policy may be paired with the outcome of another game that went through the same position.
+pub struct PolicyValueTorchNetTrainer<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+> {
+    file_name: &'a str,
+    find_move_policy_index: &'a (dyn Fn(i32, &GameState, &GameState) -> Option<usize> + Sync),
+    games_since_training_round_count: AtomicU32,
+    game_state_deserializer: &'a (dyn GameStateDeserializer<GameState, SerializedGameState> + Sync),
+    game_state_serializer: &'a (dyn GameStateSerializer<GameState, SerializedGameState> + Sync),
+    move_policy_size: usize,
+    optimizer_mutex: Mutex<nn::Optimizer<nn::Adam>>,
+    pending_search_policies_mutex: Mutex<PendingSearchPolicies<SerializedGameState>>,
+    pending_updates_count: AtomicU32,
+    policy_value_net: &'a (dyn PolicyValueNet + Sync),
+    /// Triplets of game state tensors (kept on the CPU), search policies and outcomes for the player to move.
+    replay_buffer_mutex: Mutex<ReplayBuffer<(Tensor, SparseMovePolicy, f32)>>,
+    training_parameters: ReplayBufferTrainingParameters,
+    transform_game_state_to_tensor: &'a (dyn Fn(i32, &GameState) -> Tensor + Sync),
+    var_store: &'a nn::VarStore,
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
+    PolicyValueTorchNetTrainer<'a, GameState, SerializedGameState>
+{
+    pub fn new(
+        file_name: &'a str,
+        find_move_policy_index: &'a (dyn Fn(i32, &GameState, &GameState) -> Option<usize> + Sync),
+        game_state_deserializer: &'a (dyn GameStateDeserializer<GameState, SerializedGameState>
+                 + Sync),
+        game_state_serializer: &'a (dyn GameStateSerializer<GameState, SerializedGameState> + Sync),
+        move_policy_size: usize,
+        policy_value_net: &'a (dyn PolicyValueNet + Sync),
+        training_parameters: ReplayBufferTrainingParameters,
+        var_store: &'a nn::VarStore,
+        transform_game_state_to_tensor: &'a (dyn Fn(i32, &GameState) -> Tensor + Sync),
+    ) -> PolicyValueTorchNetTrainer<'a, GameState, SerializedGameState> {
+        return PolicyValueTorchNetTrainer {
+            file_name: file_name,
+            find_move_policy_index: find_move_policy_index,
+            games_since_training_round_count: AtomicU32::new(0),
+            game_state_deserializer: game_state_deserializer,
+            game_state_serializer: game_state_serializer,
+            move_policy_size: move_policy_size,
+            optimizer_mutex: Mutex::new(
+                nn::Adam::default()
+                    .build(var_store, training_parameters.learning_rate)
+                    .unwrap(),
+            ),
+            pending_search_policies_mutex: Mutex::new(PendingSearchPolicies {
+                count: 0,
+                search_policies_by_serialized_game_state: HashMap::new(),
+            }),
+            pending_updates_count: AtomicU32::new(0),
+            policy_value_net: policy_value_net,
+            replay_buffer_mutex: Mutex::new(ReplayBuffer::new(
+                training_parameters.replay_buffer_capacity,
+            )),
+            training_parameters: training_parameters,
+            transform_game_state_to_tensor: transform_game_state_to_tensor,
+            var_store: var_store,
+        };
+    }
+
+    /// Takes the configured number of optimizer steps, each on a minibatch drawn at random from the replay buffer.
+    /// The loss is the sum of the mean squared error of the values and the cross-entropy of the policies.
+    fn run_training_round(&self) {
+        let replay_buffer = self.replay_buffer_mutex.lock().unwrap();
+        if replay_buffer.is_empty() {
+            return;
+        }
+
+        let device = self.var_store.device();
+        let mut optimizer = self.optimizer_mutex.lock().unwrap();
+        for _ in 0..self.training_parameters.training_steps_per_round {
+            let minibatch = replay_buffer.sample_minibatch(self.training_parameters.minibatch_size);
+
+            let mut game_state_tensors: Vec<&Tensor> = vec![];
+            let mut policy_values = vec![0_f32; minibatch.len() * self.move_policy_size];
+            let mut result_values: Vec<f32> = vec![];
+            for (sample_index, (game_state_tensor, move_policy, result_value)) in
+                minibatch.iter().enumerate()
+            {
+                game_state_tensors.push(game_state_tensor);
+                for (move_policy_index, probability) in move_policy.iter() {
+                    policy_values[sample_index * self.move_policy_size + move_policy_index] =
+                        *probability;
+                }
+                result_values.push(*result_value);
+            }
+
+            let game_states_tensor = Tensor::stack(&game_state_tensors, 0).to(device);
+            let policies_tensor = Tensor::of_slice(&policy_values)
+                .view([-1, self.move_policy_size as i64])
+                .to(device);
+            let results_tensor = Tensor::of_slice(&result_values).view([-1, 1]).to(device);
+
+            let (predicted_policy_logits_tensor, predicted_values_tensor) = self
+                .policy_value_net
+                .forward_policy_and_value(&game_states_tensor);
+
+            let value_loss_tensor = (results_tensor - predicted_values_tensor)
+                .pow(2)
+                .mean(Kind::Float);
+            let policy_loss_tensor = -(policies_tensor
+                * predicted_policy_logits_tensor.log_softmax(1, Kind::Float))
+            .sum(Kind::Float)
+                / minibatch.len() as f64;
+
+            optimizer.backward_step(&(value_loss_tensor + policy_loss_tensor));
+        }
+    }
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
+    SearchPoliciesRecorder<GameState>
+    for PolicyValueTorchNetTrainer<'a, GameState, SerializedGameState>
+{
+    fn record_search_policy(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GameState,
+        available_next_game_states: &Vec<GameState>,
+        search_probabilities: &Vec<f32>,
+    ) {
+        let mut move_policy: SparseMovePolicy = vec![];
+        for (available_next_game_state, search_probability) in available_next_game_states
+            .iter()
+            .zip(search_probabilities.iter())
+        {
+            if let Some(move_policy_index) = (self.find_move_policy_index)(
+                current_player_index,
+                current_game_state,
+                available_next_game_state,
+            ) {
+                move_policy.push((move_policy_index, *search_probability));
+            }
+        }
+
+        let probabilities_sum: f32 = move_policy.iter().map(|(_, probability)| probability).sum();
+        if probabilities_sum <= 0.0 {
+            return;
+        }
+        for (_, probability) in move_policy.iter_mut() {
+            *probability /= probabilities_sum;
+        }
+
+        let serialized_game_state = self
+            .game_state_serializer
+            .serialize_game_state(current_player_index, current_game_state);
+
+        let mut pending_search_policies = self.pending_search_policies_mutex.lock().unwrap();
+        if pending_search_policies.count >= MAX_PENDING_SEARCH_POLICIES_COUNT {
+            // Way more search policies than games in progress could account for, so these must be from games that were never reported
+            println!("Dropping search policies that were never matched to a game report.");
+            pending_search_policies
+                .search_policies_by_serialized_game_state
+                .clear();
+            pending_search_policies.count = 0;
+        }
+
+        pending_search_policies
+            .search_policies_by_serialized_game_state
+            .entry(serialized_game_state)
+            .or_insert_with(VecDeque::new)
+            .push_back(move_policy);
+        pending_search_policies.count += 1;
+    }
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
+    GameReportsProcessor<SerializedGameState, ()>
+    for PolicyValueTorchNetTrainer<'a, GameState, SerializedGameState>
+{
+    fn process_game_report(
+        &self,
+        game_report: &mut GameReport<SerializedGameState>,
+    ) -> Result<(), ()> {
+        let mut samples: Vec<(Tensor, SparseMovePolicy, f32)> = vec![];
+
+        {
+            let mut pending_search_policies = self.pending_search_policies_mutex.lock().unwrap();
+            for game_state_update in game_report.game_state_updates.iter() {
+                let (_, game_state) = self
+                    .game_state_deserializer
+                    .deserialize_game_state(&game_state_update.new_serialized_game_state);
+                let current_player_index = (game_state_update.responsible_player_index + 1)
+                    % game_report.number_of_players;
+                let serialized_game_state = self
+                    .game_state_serializer
+                    .serialize_game_state(current_player_index, &game_state);
+
+                let move_policy = match pending_search_policies
+                    .search_policies_by_serialized_game_state
+                    .get_mut(&serialized_game_state)
+                {
+                    Some(move_policies) => move_policies.pop_front(),
+                    None => None,
+                };
+                let move_policy = match move_policy {
+                    Some(move_policy) => move_policy,
+                    // Not every position is decided by a search
+                    None => continue,
+                };
+                pending_search_policies.count -= 1;
+
+                let result_value = utility_functions::calculate_result_value(
+                    &utility_functions::find_player_game_state_record(
+                        &game_report.player_ranks,
+                        current_player_index,
+                    ),
+                );
+
+                samples.push((
+                    (self.transform_game_state_to_tensor)(current_player_index, &game_state),
+                    move_policy,
+                    result_value,
+                ));
+            }
+
+            pending_search_policies
+                .search_policies_by_serialized_game_state
+                .retain(|_, move_policies| !move_policies.is_empty());
+        }
+
+        let mut replay_buffer = self.replay_buffer_mutex.lock().unwrap();
+        for sample in samples.into_iter() {
+            replay_buffer.push(sample);
+        }
+        drop(replay_buffer);
+
+        let games_since_training_round_count = self
+            .games_since_training_round_count
+            .fetch_add(1, Ordering::SeqCst)
+            + 1;
+        if games_since_training_round_count >= self.training_parameters.games_per_training_round {
+            self.games_since_training_round_count
+                .store(0, Ordering::SeqCst);
+            self.run_training_round();
+        }
+
+        let pending_updates_count = self.pending_updates_count.fetch_add(1, Ordering::SeqCst) + 1;
+        if pending_updates_count >= MAX_PENDING_UPDATES_COUNT {
+            self.try_commit_pending_updates_in_background(0);
+        }
+
+        return Ok(());
+    }
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
+    PendingUpdatesManager for PolicyValueTorchNetTrainer<'a, GameState, SerializedGameState>
+{
+    fn try_commit_pending_updates_in_background(
+        &self,
+        _max_number_to_commit: usize,
+    ) -> std::thread::JoinHandle<()> {
+        match self.var_store.save(self.file_name) {
+            Ok(_) => {
+                self.pending_updates_count.store(0, Ordering::SeqCst);
+            }
+            Err(_) => (),
+        };
+
+        return thread::spawn(|| {});
+    }
+}
@@ -0,0 +1,45 @@
+use rand::seq::index;
+use std::collections::VecDeque;
+
+/// A bounded buffer of training samples.
+/// Once the buffer is full, every newly pushed sample evicts the oldest one.
+pub struct ReplayBuffer<Sample> {
+    capacity: usize,
+    samples: VecDeque<Sample>,
+}
+
+impl<Sample> ReplayBuffer<Sample> {
+    pub fn new(capacity: usize) -> ReplayBuffer<Sample> {
+        return ReplayBuffer {
+            capacity: capacity,
+            samples: VecDeque::with_capacity(capacity),
+        };
+    }
+
+    pub fn push(&mut self, sample: Sample) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+
+        self.samples.push_back(sample);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.samples.is_empty();
+    }
+
+    /// Picks up to minibatch_size distinct samples uniformly at random, in random order.
+    pub fn sample_minibatch(&self, minibatch_size: usize) -> Vec<&Sample> {
+        let mut rng = rand::thread_rng();
+        let amount = minibatch_size.min(self.samples.len());
+
+        return index::sample(&mut rng, self.samples.len(), amount)
+            .into_iter()
+            .map(|sample_index| &self.samples[sample_index])
+            .collect();
+    }
+}
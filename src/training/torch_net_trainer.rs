@@ -1,11 +1,14 @@
-use crate::structs::GameReport;
+use crate::internal::utility_functions;
+use crate::structs::{GameReport, ReplayBufferTrainingParameters};
+use crate::training::ReplayBuffer;
 use crate::traits::{
     BasicGameState, BasicSerializedGameState, GameReportsProcessor, GameStateDeserializer,
     PendingUpdatesManager,
 };
-use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
 use std::thread;
-use tch::{nn, nn::OptimizerConfig, Device, Tensor};
+use tch::{nn, nn::OptimizerConfig, Device, Kind, Tensor};
 
 const MAX_PENDING_UPDATES_COUNT: u32 = 1000;
 
@@ -16,11 +19,15 @@ pub struct TorchNetTrainer<
 > {
     device: Device,
     file_name: &'a str,
-    game_state_deserializer: &'a dyn GameStateDeserializer<GameState, SerializedGameState>,
-    optimizer_ref_cell: RefCell<nn::Optimizer<nn::Adam>>,
-    pending_updates_count_cell: Cell<u32>,
-    torch_net: &'a dyn nn::Module,
-    transform_game_state_to_tensor: &'a dyn Fn(i32, &GameState) -> Tensor,
+    games_since_training_round_count: AtomicU32,
+    game_state_deserializer: &'a (dyn GameStateDeserializer<GameState, SerializedGameState> + Sync),
+    optimizer_mutex: Mutex<nn::Optimizer<nn::Adam>>,
+    pending_updates_count: AtomicU32,
+    /// Pairs of game state tensors (kept on the CPU) and the outcome for the responsible player.
+    replay_buffer_mutex: Mutex<ReplayBuffer<(Tensor, f32)>>,
+    torch_net: &'a (dyn nn::Module + Sync),
+    training_parameters: ReplayBufferTrainingParameters,
+    transform_game_state_to_tensor: &'a (dyn Fn(i32, &GameState) -> Tensor + Sync),
     var_store: &'a nn::VarStore,
 }
 
@@ -30,22 +37,66 @@ impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameStat
     pub fn new(
         device: Device,
         file_name: &'a str,
-        game_state_deserializer: &'a dyn GameStateDeserializer<GameState, SerializedGameState>,
-        torch_net: &'a dyn nn::Module,
+        game_state_deserializer: &'a (dyn GameStateDeserializer<GameState, SerializedGameState>
+                 + Sync),
+        torch_net: &'a (dyn nn::Module + Sync),
+        training_parameters: ReplayBufferTrainingParameters,
         var_store: &'a nn::VarStore,
-        transform_game_state_to_tensor: &'a dyn Fn(i32, &GameState) -> Tensor,
+        transform_game_state_to_tensor: &'a (dyn Fn(i32, &GameState) -> Tensor + Sync),
     ) -> TorchNetTrainer<'a, GameState, SerializedGameState> {
         return TorchNetTrainer {
             device: device,
             file_name: file_name,
+            games_since_training_round_count: AtomicU32::new(0),
             game_state_deserializer: game_state_deserializer,
-            optimizer_ref_cell: RefCell::new(nn::Adam::default().build(var_store, 1e-4).unwrap()),
-            pending_updates_count_cell: Cell::new(0),
+            optimizer_mutex: Mutex::new(
+                nn::Adam::default()
+                    .build(var_store, training_parameters.learning_rate)
+                    .unwrap(),
+            ),
+            pending_updates_count: AtomicU32::new(0),
+            replay_buffer_mutex: Mutex::new(ReplayBuffer::new(
+                training_parameters.replay_buffer_capacity,
+            )),
             torch_net: torch_net,
+            training_parameters: training_parameters,
             transform_game_state_to_tensor: transform_game_state_to_tensor,
             var_store: var_store,
         };
     }
+
+    /// Takes the configured number of optimizer steps, each on a minibatch drawn at random from the replay buffer.
+    fn run_training_round(&self) {
+        let replay_buffer = self.replay_buffer_mutex.lock().unwrap();
+        if replay_buffer.is_empty() {
+            return;
+        }
+
+        let mut optimizer = self.optimizer_mutex.lock().unwrap();
+        for _ in 0..self.training_parameters.training_steps_per_round {
+            let minibatch = replay_buffer.sample_minibatch(self.training_parameters.minibatch_size);
+
+            let game_state_tensors: Vec<&Tensor> = minibatch
+                .iter()
+                .map(|(game_state_tensor, _)| game_state_tensor)
+                .collect();
+            let result_values: Vec<f32> = minibatch
+                .iter()
+                .map(|(_, result_value)| *result_value)
+                .collect();
+
+            let game_states_tensor = Tensor::stack(&game_state_tensors, 0).to(self.device);
+            let results_tensor = Tensor::of_slice(&result_values)
+                .view([-1, 1])
+                .to(self.device);
+
+            let predictions_tensor = self.torch_net.forward(&game_states_tensor);
+            let loss_tensor = (results_tensor - predictions_tensor)
+                .pow(2)
+                .mean(Kind::Float);
+            optimizer.backward_step(&loss_tensor);
+        }
+    }
 }
 
 impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
@@ -56,11 +107,12 @@ impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameStat
         &self,
         game_report: &mut GameReport<SerializedGameState>,
     ) -> Result<(), ()> {
-        let did_draw = game_report.winning_player_index == -1;
-
-        while let Some(game_state_update) = game_report.game_state_updates.pop() {
-            let did_win =
-                game_state_update.responsible_player_index == game_report.winning_player_index;
+        let mut replay_buffer = self.replay_buffer_mutex.lock().unwrap();
+        for game_state_update in game_report.game_state_updates.iter() {
+            if game_state_update.responsible_player_index < 0 {
+                // The initial game state isn't the result of any player's decision
+                continue;
+            }
 
             let (_, game_state) = self
                 .game_state_deserializer
@@ -70,27 +122,29 @@ impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameStat
                 &game_state,
             );
 
-            let result_value = if did_win {
-                1
-            } else if did_draw {
-                0
-            } else {
-                -1
-            };
-            let result_tensor = Tensor::of_slice(&[result_value as f32])
-                .view([1, 1])
-                .to(self.device);
+            let result_value = utility_functions::calculate_result_value(
+                &utility_functions::find_player_game_state_record(
+                    &game_report.player_ranks,
+                    game_state_update.responsible_player_index,
+                ),
+            );
+
+            replay_buffer.push((game_state_tensor, result_value));
+        }
+        drop(replay_buffer);
 
-            let prediction_tensor = self.torch_net.forward(&game_state_tensor.to(self.device));
-            let loss_tensor = (result_tensor - prediction_tensor).pow(2);
-            self.optimizer_ref_cell
-                .borrow_mut()
-                .backward_step(&loss_tensor);
+        let games_since_training_round_count = self
+            .games_since_training_round_count
+            .fetch_add(1, Ordering::SeqCst)
+            + 1;
+        if games_since_training_round_count >= self.training_parameters.games_per_training_round {
+            self.games_since_training_round_count
+                .store(0, Ordering::SeqCst);
+            self.run_training_round();
         }
 
-        self.pending_updates_count_cell
-            .set(self.pending_updates_count_cell.get() + 1);
-        if self.pending_updates_count_cell.get() >= MAX_PENDING_UPDATES_COUNT {
+        let pending_updates_count = self.pending_updates_count.fetch_add(1, Ordering::SeqCst) + 1;
+        if pending_updates_count >= MAX_PENDING_UPDATES_COUNT {
             self.try_commit_pending_updates_in_background(0);
         }
 
@@ -107,7 +161,7 @@ impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameStat
     ) -> std::thread::JoinHandle<()> {
         match self.var_store.save(self.file_name) {
             Ok(_) => {
-                self.pending_updates_count_cell.set(0);
+                self.pending_updates_count.store(0, Ordering::SeqCst);
             }
             Err(_) => (),
         };
@@ -1,15 +1,176 @@
 use crate::enums::{DecideNextStateError, RunGameError};
+#[cfg(feature = "torch")]
+use crate::structs::NeuralNetDefinition;
 use crate::structs::{GameReport, GameStateRecord, IncrementPersistedGameStateRecordValuesTask};
 use std::hash::Hash;
+#[cfg(feature = "torch")]
+use tch::Tensor;
 
 pub trait BasicGameState: Clone {}
 
 pub trait BasicSerializedGameState: Clone + Eq + Hash + PartialEq {}
 
+/// A serialized game state that can be written down as a byte array and read back, e.g. to be stored in a database.
+pub trait ByteArrayConvertibleSerializedGameState: BasicSerializedGameState {
+    fn to_byte_array(&self) -> Vec<u8>;
+
+    /// Returns None if the byte array isn't one of a serialized game state of this kind.
+    fn from_byte_array(byte_array: &[u8]) -> Option<Self>;
+}
+
 pub trait CLIGameStateFormatter<GameState: BasicGameState> {
     fn format_game_state_for_cli(&self, game_state: &GameState) -> String;
 }
 
+/// Describes the moves of a game as actions, which can be told apart, written down and mapped to policy tensor indices.
+pub trait GameActionsAuthority<GameState: BasicGameState> {
+    type Action: Clone;
+
+    /// Finds the actions available to the given player,
+    /// which lead to the same game states the game rules authority finds for the player.
+    fn find_legal_actions(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GameState,
+    ) -> Vec<Self::Action>;
+
+    fn apply_action(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GameState,
+        action: &Self::Action,
+    ) -> GameState;
+
+    /// Writes the action down the way the game's user input game state creator reads it.
+    fn format_action(&self, action: &Self::Action) -> String;
+
+    /// The number of indices the actions are mapped to, which is the size of a policy tensor.
+    fn get_number_of_action_indices(&self) -> usize;
+
+    /// Maps the action to its index in a policy tensor, below get_number_of_action_indices.
+    fn find_action_index(&self, current_player_index: i32, action: &Self::Action) -> usize;
+}
+
+/// The part of a GameActionsAuthority that doesn't depend on the action type,
+/// which lets game runners record the actions of any game.
+pub trait GameActionNotationsFinder<GameState: BasicGameState> {
+    /// Finds the notation of each action available to the given player along with the game state it leads to.
+    fn find_legal_action_notations(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GameState,
+    ) -> Vec<(String, GameState)> {
+        return self
+            .find_legal_action_notations_and_indices(current_player_index, current_game_state)
+            .into_iter()
+            .map(|(action_notation, _, next_game_state)| (action_notation, next_game_state))
+            .collect();
+    }
+
+    /// Finds the notation and the policy tensor index of each action available to the given player
+    /// along with the game state it leads to.
+    fn find_legal_action_notations_and_indices(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GameState,
+    ) -> Vec<(String, usize, GameState)>;
+}
+
+impl<GameState: BasicGameState, Authority: GameActionsAuthority<GameState>>
+    GameActionNotationsFinder<GameState> for Authority
+{
+    fn find_legal_action_notations_and_indices(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GameState,
+    ) -> Vec<(String, usize, GameState)> {
+        return self
+            .find_legal_actions(current_player_index, current_game_state)
+            .iter()
+            .map(|action| {
+                let action_index = self.find_action_index(current_player_index, action);
+                debug_assert!(action_index < self.get_number_of_action_indices());
+                return (
+                    self.format_action(action),
+                    action_index,
+                    self.apply_action(current_player_index, current_game_state, action),
+                );
+            })
+            .collect();
+    }
+}
+
+/// Bundles everything the commands need to know about a game in order to play it.
+pub trait GameDefinition<GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>:
+    Sync
+{
+    /// The name the game is known by on the command line and in the database.
+    fn get_name(&self) -> &str;
+
+    /// The version of the serialized game states the game state records of the game are stored by.
+    /// Bumped whenever the game state serializer changes, so that records of different serializations never mix.
+    fn get_game_state_records_version(&self) -> i32 {
+        return 1;
+    }
+
+    fn get_number_of_players(&self) -> i32 {
+        return 2;
+    }
+
+    fn create_initial_game_state(&self) -> GameState;
+
+    fn get_cli_game_state_formatter(&self) -> &dyn CLIGameStateFormatter<GameState>;
+
+    fn get_game_rules_authority(&self) -> &(dyn GameRulesAuthority<GameState> + Sync);
+
+    /// Games that describe their moves as actions get the actions of their games recorded,
+    /// and their moves written down when dividing perft counts.
+    fn get_game_action_notations_finder(
+        &self,
+    ) -> Option<&(dyn GameActionNotationsFinder<GameState> + Sync)> {
+        return None;
+    }
+
+    fn get_game_state_deserializer(
+        &self,
+    ) -> &(dyn GameStateDeserializer<GameState, SerializedGameState> + Sync);
+
+    fn get_game_state_serializer(
+        &self,
+    ) -> &(dyn GameStateSerializer<GameState, SerializedGameState> + Sync);
+
+    /// Games with symmetries share the game state records of the game states that are equivalent by them.
+    fn get_game_state_symmetries_finder(
+        &self,
+    ) -> Option<&(dyn GameStateSymmetriesFinder<GameState> + Sync)> {
+        return None;
+    }
+
+    fn get_user_input_game_state_creator(
+        &self,
+    ) -> &dyn UserInputGameStateCreator<GameState, String>;
+
+    /// Whether the whole game tree of the game is small enough to be searched, so that its game states can be solved.
+    /// Only two player games in which the players take turns can be solved.
+    fn is_solvable(&self) -> bool {
+        return false;
+    }
+
+    /// Games in which all players move at once provide the rules for resolving their joint moves,
+    /// and get run by the simultaneous game runner instead of the turn based one.
+    fn get_simultaneous_game_rules_authority(
+        &self,
+    ) -> Option<&(dyn SimultaneousGameRulesAuthority<GameState> + Sync)> {
+        return None;
+    }
+
+    /// Games without neural nets are played based on the recorded game outcomes only.
+    #[cfg(feature = "torch")]
+    fn get_neural_net_definition(&self) -> Option<&NeuralNetDefinition<GameState>> {
+        return None;
+    }
+}
+
 pub trait GameReportsProcessor<SerializedGameState: BasicSerializedGameState, ErrorType> {
     fn process_game_report(
         &self,
@@ -28,11 +189,84 @@ pub trait GameRulesAuthority<GameState: BasicGameState> {
         next_player_index: i32,
     ) -> Option<i32>;
 
+    /// Analyzes the whole history of a game, from its initial game state to its current one, to determine if it is over,
+    /// returning the same as analyze_game_state_for_terminality does for the current game state.
+    /// This is how the game runners judge games, letting rules such as draws by repetition come into play.
+    /// Searching turn takers only get to see single game states though, so they can't foresee such draws.
+    /// By default only the current game state matters.
+    fn analyze_game_history_for_terminality(
+        &self,
+        game_states_history: &Vec<GameState>,
+        next_player_index: i32,
+    ) -> Option<i32> {
+        return self.analyze_game_state_for_terminality(
+            &game_states_history[game_states_history.len() - 1],
+            next_player_index,
+        );
+    }
+
     fn find_available_next_game_states(
         &self,
         current_player_index: i32,
         current_game_state: &GameState,
     ) -> Vec<GameState>;
+
+    /// Finds the possible outcomes of the chance event (such as a roll of the dice) that has to play out
+    /// before the given player gets to move, each along with its probability.
+    /// Returns an empty list when no chance event is pending, which is always the case for games without randomness.
+    fn find_chance_outcomes(
+        &self,
+        _current_player_index: i32,
+        _current_game_state: &GameState,
+    ) -> Vec<(GameState, f32)> {
+        return vec![];
+    }
+
+    /// Hides away the parts of the game state the given player isn't allowed to see, such as the cards in the other players' hands.
+    /// The turn taker of a player only ever gets to decide from that player's observation of the game state,
+    /// and their decisions are recorded as their observation of the game state they lead to,
+    /// so all the game states that look the same to a player (their information set) share the same records.
+    /// A player's own move must not reveal anything new to them, as the observation of the game state it leads to
+    /// has to be found from the observation they decided from.
+    /// A player index of -1 observes the whole game state, and by default so does every player.
+    fn observe_game_state(
+        &self,
+        _observing_player_index: i32,
+        game_state: &GameState,
+    ) -> GameState {
+        return game_state.clone();
+    }
+
+    /// Ranks the players of a terminal game state, 0 being the best rank and players who tied sharing the same rank.
+    /// By default the winning player found by analyze_game_state_for_terminality ranks ahead of all the other players,
+    /// while a draw leaves all the players tied.
+    fn rank_players(
+        &self,
+        _game_state: &GameState,
+        winning_player_index: i32,
+        number_of_players: i32,
+    ) -> Vec<i32> {
+        return (0..number_of_players)
+            .map(|player_index| {
+                if winning_player_index == -1 || player_index == winning_player_index {
+                    0
+                } else {
+                    1
+                }
+            })
+            .collect();
+    }
+
+    /// Determines if the given player has to pass their turn, leaving the game state as it is.
+    /// This is only ever asked of game states that aren't terminal.
+    /// Most games are over once the player to move runs out of moves, so by default no player ever has to pass.
+    fn is_passing_forced(
+        &self,
+        _current_player_index: i32,
+        _current_game_state: &GameState,
+    ) -> bool {
+        return false;
+    }
 }
 
 pub trait GameRunner<GameState: BasicGameState, SerializedGameState: BasicSerializedGameState> {
@@ -87,6 +321,18 @@ pub trait GameStateSerializer<
     ) -> SerializedGameState;
 }
 
+/// Finds the game states equivalent to a game state by the symmetries of the game,
+/// e.g. the rotations and reflections of its board.
+pub trait GameStateSymmetriesFinder<GameState: BasicGameState> {
+    /// Finds every game state equivalent to the given one, the given one included,
+    /// along with the index of the player responsible for it, as a symmetry may swap the roles of the players.
+    fn find_symmetric_game_states(
+        &self,
+        responsible_player_index: i32,
+        game_state: &GameState,
+    ) -> Vec<(i32, GameState)>;
+}
+
 pub trait GameStateWeightsCalculator<GameState: BasicGameState> {
     fn weigh_game_states(
         &self,
@@ -102,6 +348,49 @@ pub trait PendingUpdatesManager {
     ) -> std::thread::JoinHandle<()>;
 }
 
+pub trait PolicyValueCalculator<GameState: BasicGameState> {
+    /// Calculates the prior probability of each of the available next game states
+    /// along with the expected outcome (in [-1, 1]) for the player about to move.
+    fn calculate_policy_and_value(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GameState,
+        available_next_game_states: &Vec<GameState>,
+    ) -> (Vec<f32>, f32);
+}
+
+#[cfg(feature = "torch")]
+pub trait PolicyValueNet: std::fmt::Debug + Send {
+    /// Returns the policy logits and the values for a batch of game state tensors.
+    fn forward_policy_and_value(&self, tensor: &Tensor) -> (Tensor, Tensor);
+}
+
+pub trait SearchPoliciesRecorder<GameState: BasicGameState> {
+    /// Records the share of the search that went into each of the available next game states.
+    fn record_search_policy(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GameState,
+        available_next_game_states: &Vec<GameState>,
+        search_probabilities: &Vec<f32>,
+    );
+}
+
+/// Rules of a game in which all players move at once, each of them deciding without knowing what the others chose.
+/// The available next game states of a player are the game states they can move to on their own,
+/// and the game state a round leads to is only known once the moves of all the players are resolved together.
+pub trait SimultaneousGameRulesAuthority<GameState: BasicGameState>:
+    GameRulesAuthority<GameState>
+{
+    /// Resolves the game states each player moved to from the given game state, indexed by player index,
+    /// into the game state the round leads to.
+    fn resolve_joint_game_states(
+        &self,
+        current_game_state: &GameState,
+        players_next_game_states: &Vec<GameState>,
+    ) -> GameState;
+}
+
 pub trait TurnTaker<GameState: BasicGameState> {
     fn decide_next_game_state(
         &self,
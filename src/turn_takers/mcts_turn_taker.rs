@@ -0,0 +1,499 @@
+use crate::enums::DecideNextStateError;
+use crate::traits::{
+    BasicGameState, GameRulesAuthority, GameStateWeightsCalculator, PolicyValueCalculator,
+    SearchPoliciesRecorder, TurnTaker,
+};
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+
+/// A single node of the search tree.
+/// All values stored on a node are from the perspective of the player responsible for reaching it.
+struct MctsNode<GameState: BasicGameState> {
+    game_state: GameState,
+    responsible_player_index: i32,
+    child_node_indices: Vec<usize>,
+    is_expanded: bool,
+    /// Whether the children of the node are the outcomes of a chance event rather than the moves of the next player.
+    /// The outcomes of a chance event share the responsible player of the node they happen in.
+    is_chance_node: bool,
+    /// None until the node has been reached by a simulation, then Some(terminality analysis result).
+    terminality: Option<Option<i32>>,
+    prior_probability: f32,
+    static_evaluation: f32,
+    total_value: f32,
+    visits_count: u32,
+}
+
+impl<GameState: BasicGameState> MctsNode<GameState> {
+    fn new(
+        game_state: GameState,
+        responsible_player_index: i32,
+        prior_probability: f32,
+        static_evaluation: f32,
+    ) -> MctsNode<GameState> {
+        return MctsNode {
+            game_state: game_state,
+            responsible_player_index: responsible_player_index,
+            child_node_indices: vec![],
+            is_expanded: false,
+            is_chance_node: false,
+            terminality: None,
+            prior_probability: prior_probability,
+            static_evaluation: static_evaluation,
+            total_value: 0.0,
+            visits_count: 0,
+        };
+    }
+
+    fn mean_value(&self) -> f32 {
+        if self.visits_count == 0 {
+            return self.static_evaluation;
+        }
+
+        return self.total_value / self.visits_count as f32;
+    }
+}
+
+/// Picks the next game state by running a Monte Carlo Tree Search from the current game state.
+/// Children are selected with the PUCT formula used by AlphaZero and leaves are valued by the given weights calculator
+/// rather than by random rollouts, so the weights calculator is expected to produce values roughly in [-1, 1]
+/// (as the CnnGameStateWeightsCalculator does when trained against game outcomes).
+/// Any weight outside of that range is clamped into it.
+/// When a policy value calculator is set, it is used instead to provide both the priors of the children of a node
+/// and the value of the node itself with a single evaluation.
+/// Only two player games are supported, as the value of a node for one player is taken to be the opposite of its value for the other.
+/// The outcomes of chance events are sampled by their probabilities, so that the values of chance nodes converge to their expected values.
+pub struct MctsTurnTaker<'a, GameState: BasicGameState> {
+    game_rules_authority: &'a dyn GameRulesAuthority<GameState>,
+    game_state_weights_calculator: &'a dyn GameStateWeightsCalculator<GameState>,
+    player_index: i32,
+    number_of_simulations: u32,
+    exploration_constant: f32,
+    policy_value_calculator: Option<&'a dyn PolicyValueCalculator<GameState>>,
+    search_policies_recorder: Option<&'a dyn SearchPoliciesRecorder<GameState>>,
+    /// When 0, the most visited move is always picked.
+    /// Otherwise, the move is sampled proportionally to visits_count^(1 / temperature).
+    temperature: f32,
+}
+
+impl<'a, GameState: BasicGameState> MctsTurnTaker<'a, GameState> {
+    pub fn new(
+        game_rules_authority: &'a dyn GameRulesAuthority<GameState>,
+        game_state_weights_calculator: &'a dyn GameStateWeightsCalculator<GameState>,
+        player_index: i32,
+        number_of_simulations: u32,
+        exploration_constant: f32,
+        temperature: f32,
+    ) -> MctsTurnTaker<'a, GameState> {
+        return MctsTurnTaker {
+            game_rules_authority: game_rules_authority,
+            game_state_weights_calculator: game_state_weights_calculator,
+            player_index: player_index,
+            number_of_simulations: number_of_simulations,
+            exploration_constant: exploration_constant,
+            policy_value_calculator: None,
+            search_policies_recorder: None,
+            temperature: temperature,
+        };
+    }
+
+    #[cfg(feature = "torch")]
+    pub fn set_policy_value_calculator(
+        &mut self,
+        policy_value_calculator: &'a dyn PolicyValueCalculator<GameState>,
+    ) {
+        self.policy_value_calculator = Some(policy_value_calculator);
+    }
+
+    /// Sets a recorder to be given the visit distribution of the root children after every search.
+    #[cfg(feature = "torch")]
+    pub fn set_search_policies_recorder(
+        &mut self,
+        search_policies_recorder: &'a dyn SearchPoliciesRecorder<GameState>,
+    ) {
+        self.search_policies_recorder = Some(search_policies_recorder);
+    }
+
+    /// Adds the children of the given node to the tree.
+    /// Returns the value of the node for its responsible player if the node itself was evaluated while expanding it.
+    fn expand_node(&self, nodes: &mut Vec<MctsNode<GameState>>, node_index: usize) -> Option<f32> {
+        let next_player_index = get_next_player_index(nodes[node_index].responsible_player_index);
+
+        let chance_outcomes = self
+            .game_rules_authority
+            .find_chance_outcomes(next_player_index, &nodes[node_index].game_state);
+        if !chance_outcomes.is_empty() {
+            nodes[node_index].is_expanded = true;
+            nodes[node_index].is_chance_node = true;
+
+            // Whatever the outcome, the node is still as good for its responsible player as it was evaluated to be
+            let responsible_player_index = nodes[node_index].responsible_player_index;
+            let static_evaluation = nodes[node_index].static_evaluation;
+            for (chance_outcome, probability) in chance_outcomes.into_iter() {
+                let child_node_index = nodes.len();
+                nodes.push(MctsNode::new(
+                    chance_outcome,
+                    responsible_player_index,
+                    probability,
+                    static_evaluation,
+                ));
+                nodes[node_index].child_node_indices.push(child_node_index);
+            }
+
+            return None;
+        }
+
+        let available_next_game_states = self
+            .game_rules_authority
+            .find_available_next_game_states(next_player_index, &nodes[node_index].game_state);
+
+        nodes[node_index].is_expanded = true;
+        if available_next_game_states.is_empty() {
+            if self
+                .game_rules_authority
+                .is_passing_forced(next_player_index, &nodes[node_index].game_state)
+            {
+                // Passing is the only child, and it starts out at the value of the node for the player reaching it
+                let pass_game_state = nodes[node_index].game_state.clone();
+                let static_evaluation = -nodes[node_index].static_evaluation;
+                let child_node_index = nodes.len();
+                nodes.push(MctsNode::new(
+                    pass_game_state,
+                    next_player_index,
+                    1.0,
+                    static_evaluation,
+                ));
+                nodes[node_index].child_node_indices.push(child_node_index);
+            }
+
+            return None;
+        }
+
+        let (prior_probabilities, static_evaluations, node_value) =
+            match self.policy_value_calculator {
+                Some(policy_value_calculator) => {
+                    let (prior_probabilities, value) = policy_value_calculator
+                        .calculate_policy_and_value(
+                            next_player_index,
+                            &nodes[node_index].game_state,
+                            &available_next_game_states,
+                        );
+                    let value = value.max(-1.0).min(1.0);
+
+                    // The children haven't been evaluated on their own, so they start out at the value of the node for the player reaching them
+                    (
+                        prior_probabilities,
+                        vec![value; available_next_game_states.len()],
+                        Some(-value),
+                    )
+                }
+                None => {
+                    // All children are weighed at once so that the weights calculator can batch its work
+                    let weights_for_available_next_game_states = self
+                        .game_state_weights_calculator
+                        .weigh_game_states(next_player_index, &available_next_game_states);
+
+                    (
+                        vec![
+                            1.0 / available_next_game_states.len() as f32;
+                            available_next_game_states.len()
+                        ],
+                        weights_for_available_next_game_states
+                            .into_iter()
+                            .map(|weight| weight.max(-1.0).min(1.0))
+                            .collect(),
+                        None,
+                    )
+                }
+            };
+
+        for ((available_next_game_state, prior_probability), static_evaluation) in
+            available_next_game_states
+                .into_iter()
+                .zip(prior_probabilities.into_iter())
+                .zip(static_evaluations.into_iter())
+        {
+            let child_node_index = nodes.len();
+            nodes.push(MctsNode::new(
+                available_next_game_state,
+                next_player_index,
+                prior_probability,
+                static_evaluation,
+            ));
+            nodes[node_index].child_node_indices.push(child_node_index);
+        }
+
+        return node_value;
+    }
+
+    fn select_child_node_index(
+        &self,
+        nodes: &Vec<MctsNode<GameState>>,
+        node_index: usize,
+    ) -> usize {
+        let node = &nodes[node_index];
+        if node.is_chance_node {
+            // Sampling the outcomes by their probabilities makes the value of the node converge to their expected value
+            let probabilities: Vec<f32> = node
+                .child_node_indices
+                .iter()
+                .map(|child_node_index| nodes[*child_node_index].prior_probability)
+                .collect();
+            return match WeightedIndex::new(&probabilities) {
+                Ok(dist) => node.child_node_indices[dist.sample(&mut rand::thread_rng())],
+                Err(_) => node.child_node_indices[0],
+            };
+        }
+
+        let visits_count_sqrt = (node.visits_count.max(1) as f32).sqrt();
+
+        let mut best_child_node_index = node.child_node_indices[0];
+        let mut best_score = f32::MIN;
+        for child_node_index in node.child_node_indices.iter() {
+            let child_node = &nodes[*child_node_index];
+            let exploration_score =
+                self.exploration_constant * child_node.prior_probability * visits_count_sqrt
+                    / (1.0 + child_node.visits_count as f32);
+            let score = child_node.mean_value() + exploration_score;
+
+            if score > best_score {
+                best_score = score;
+                best_child_node_index = *child_node_index;
+            }
+        }
+
+        return best_child_node_index;
+    }
+
+    fn run_simulation(&self, nodes: &mut Vec<MctsNode<GameState>>) {
+        let mut path: Vec<usize> = vec![0];
+        let mut node_index = 0;
+
+        let leaf_value: f32;
+        loop {
+            if node_index != 0 {
+                if nodes[node_index].terminality.is_none() {
+                    let next_player_index =
+                        get_next_player_index(nodes[node_index].responsible_player_index);
+                    nodes[node_index].terminality = Some(
+                        self.game_rules_authority
+                            .analyze_game_state_for_terminality(
+                                &nodes[node_index].game_state,
+                                next_player_index,
+                            ),
+                    );
+                }
+
+                if let Some(Some(winning_player_index)) = nodes[node_index].terminality {
+                    leaf_value = if winning_player_index == -1 {
+                        0.0
+                    } else if winning_player_index == nodes[node_index].responsible_player_index {
+                        1.0
+                    } else {
+                        -1.0
+                    };
+                    break;
+                }
+
+                if nodes[node_index].visits_count == 0 {
+                    // First visit of a non-terminal node - trust its evaluation and expand it for future simulations
+                    let static_evaluation = nodes[node_index].static_evaluation;
+                    leaf_value = self
+                        .expand_node(nodes, node_index)
+                        .unwrap_or(static_evaluation);
+                    break;
+                }
+            }
+
+            if !nodes[node_index].is_expanded {
+                self.expand_node(nodes, node_index);
+            }
+
+            if nodes[node_index].child_node_indices.is_empty() {
+                // Not terminal, yet no moves are available - nothing better to do than to call it even
+                leaf_value = 0.0;
+                break;
+            }
+
+            node_index = self.select_child_node_index(nodes, node_index);
+            path.push(node_index);
+        }
+
+        let leaf_responsible_player_index = nodes[node_index].responsible_player_index;
+        for path_node_index in path.iter() {
+            let path_node = &mut nodes[*path_node_index];
+            path_node.visits_count += 1;
+            if path_node.responsible_player_index == leaf_responsible_player_index {
+                path_node.total_value += leaf_value;
+            } else {
+                path_node.total_value -= leaf_value;
+            }
+        }
+    }
+}
+
+impl<'a, GameState: BasicGameState> TurnTaker<GameState> for MctsTurnTaker<'a, GameState> {
+    fn decide_next_game_state(
+        &self,
+        current_game_state: &GameState,
+    ) -> Result<GameState, DecideNextStateError> {
+        let mut nodes: Vec<MctsNode<GameState>> = vec![MctsNode::new(
+            current_game_state.clone(),
+            get_next_player_index(self.player_index),
+            1.0,
+            0.0,
+        )];
+
+        self.expand_node(&mut nodes, 0);
+        if nodes[0].child_node_indices.is_empty() {
+            return Err(DecideNextStateError::NoAvailableStatesError);
+        }
+
+        for _ in 0..self.number_of_simulations {
+            self.run_simulation(&mut nodes);
+        }
+
+        let root_child_node_indices = &nodes[0].child_node_indices;
+
+        if let Some(search_policies_recorder) = self.search_policies_recorder {
+            let root_child_game_states: Vec<GameState> = root_child_node_indices
+                .iter()
+                .map(|child_node_index| nodes[*child_node_index].game_state.clone())
+                .collect();
+            let root_visits_count = root_child_node_indices
+                .iter()
+                .map(|child_node_index| nodes[*child_node_index].visits_count)
+                .sum::<u32>()
+                .max(1);
+            let search_probabilities: Vec<f32> = root_child_node_indices
+                .iter()
+                .map(|child_node_index| {
+                    nodes[*child_node_index].visits_count as f32 / root_visits_count as f32
+                })
+                .collect();
+
+            search_policies_recorder.record_search_policy(
+                self.player_index,
+                current_game_state,
+                &root_child_game_states,
+                &search_probabilities,
+            );
+        }
+
+        if self.temperature <= 0.0 {
+            let mut best_child_node_index = root_child_node_indices[0];
+            for child_node_index in root_child_node_indices.iter() {
+                let child_node = &nodes[*child_node_index];
+                let best_child_node = &nodes[best_child_node_index];
+                if child_node.visits_count > best_child_node.visits_count
+                    || (child_node.visits_count == best_child_node.visits_count
+                        && child_node.mean_value() > best_child_node.mean_value())
+                {
+                    best_child_node_index = *child_node_index;
+                }
+            }
+
+            return Ok(nodes[best_child_node_index].game_state.clone());
+        }
+
+        let selection_weights: Vec<f64> = root_child_node_indices
+            .iter()
+            .map(|child_node_index| {
+                (nodes[*child_node_index].visits_count as f64).powf(1.0 / self.temperature as f64)
+            })
+            .collect();
+
+        match WeightedIndex::new(&selection_weights) {
+            Ok(dist) => {
+                let mut rng = rand::thread_rng();
+                let selected_child_node_index = root_child_node_indices[dist.sample(&mut rng)];
+
+                return Ok(nodes[selected_child_node_index].game_state.clone());
+            }
+            Err(_) => return Err(DecideNextStateError::Unknown),
+        }
+    }
+}
+
+fn get_next_player_index(player_index: i32) -> i32 {
+    return (player_index + 1) % 2;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::tic_tac_toe::{
+        GameRulesAuthority as TicTacToeGameRulesAuthority, GameStateType,
+    };
+
+    /// Leaves every game state at an even value, so that only the outcomes found by the search tell the moves apart.
+    struct ZeroGameStateWeightsCalculator {}
+
+    impl GameStateWeightsCalculator<GameStateType> for ZeroGameStateWeightsCalculator {
+        fn weigh_game_states(
+            &self,
+            _responsible_player_index: i32,
+            game_states: &Vec<GameStateType>,
+        ) -> Vec<f32> {
+            return vec![0.0; game_states.len()];
+        }
+    }
+
+    #[test]
+    fn test_picking_immediate_win_and_block() {
+        let game_rules_authority = TicTacToeGameRulesAuthority {};
+        let weights_calculator = ZeroGameStateWeightsCalculator {};
+        let turn_taker = MctsTurnTaker::new(
+            &game_rules_authority,
+            &weights_calculator,
+            0,
+            1000,
+            1.5,
+            0.0,
+        );
+
+        // X completes the top row rather than blocking the middle row
+        let game_state = vec![vec![1, 1, 0], vec![2, 2, 0], vec![0, 0, 0]];
+        let next_game_state = turn_taker.decide_next_game_state(&game_state).unwrap();
+        assert_eq!(next_game_state[0][2], 1);
+
+        // X has no win of their own, so they block the middle row
+        let game_state = vec![vec![1, 0, 0], vec![2, 2, 0], vec![1, 0, 0]];
+        let next_game_state = turn_taker.decide_next_game_state(&game_state).unwrap();
+        assert_eq!(next_game_state[1][2], 1);
+    }
+
+    #[test]
+    fn test_backpropagating_terminal_values() {
+        let game_rules_authority = TicTacToeGameRulesAuthority {};
+        let weights_calculator = ZeroGameStateWeightsCalculator {};
+        let turn_taker =
+            MctsTurnTaker::new(&game_rules_authority, &weights_calculator, 0, 1, 1.5, 0.0);
+
+        // X's only move completes the main diagonal, a win for X and so a loss for O, who is responsible for the root
+        let mut nodes = vec![MctsNode::new(
+            vec![vec![1, 2, 1], vec![2, 1, 2], vec![2, 1, 0]],
+            1,
+            1.0,
+            0.0,
+        )];
+        turn_taker.expand_node(&mut nodes, 0);
+        turn_taker.run_simulation(&mut nodes);
+        assert_eq!(nodes[1].terminality, Some(Some(0)));
+        assert_eq!((nodes[1].visits_count, nodes[1].total_value), (1, 1.0));
+        assert_eq!((nodes[0].visits_count, nodes[0].total_value), (1, -1.0));
+
+        // X's only move fills the board without a line, a draw for both
+        let mut nodes = vec![MctsNode::new(
+            vec![vec![1, 2, 1], vec![1, 2, 2], vec![2, 1, 0]],
+            1,
+            1.0,
+            0.0,
+        )];
+        turn_taker.expand_node(&mut nodes, 0);
+        turn_taker.run_simulation(&mut nodes);
+        assert_eq!(nodes[1].terminality, Some(Some(-1)));
+        assert_eq!((nodes[1].visits_count, nodes[1].total_value), (1, 0.0));
+        assert_eq!((nodes[0].visits_count, nodes[0].total_value), (1, 0.0));
+    }
+}
@@ -0,0 +1,477 @@
+use crate::enums::{DecideNextStateError, MinimaxSearchLimit};
+use crate::traits::{BasicGameState, GameRulesAuthority, GameStateWeightsCalculator, TurnTaker};
+use std::cmp::Ordering;
+use std::time::Instant;
+
+/// Score of a won game. Wins found closer to the root score slightly higher so that the quickest win is preferred.
+/// Static weights are expected to stay well below this value.
+const WIN_SCORE: f32 = 1_000_000.0;
+
+struct SearchContext {
+    deadline: Option<Instant>,
+    is_aborted: bool,
+}
+
+impl SearchContext {
+    fn should_abort(&mut self) -> bool {
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                self.is_aborted = true;
+            }
+        }
+
+        return self.is_aborted;
+    }
+}
+
+/// Picks the next game state with a negamax search using alpha-beta pruning.
+/// Terminal states are scored exactly using the game rules authority
+/// while states at the search horizon are scored by the given weights calculator.
+/// At every node the available next game states are weighed at once,
+/// which both provides the horizon scores and orders the moves to make the pruning more effective.
+/// Only two player games are supported, as a score for one player is taken to be the opposite of the score for the other.
+/// Chance events are searched through as expectiminimax does, scoring them with the expected score of their outcomes.
+pub struct MinimaxTurnTaker<'a, GameState: BasicGameState> {
+    game_rules_authority: &'a dyn GameRulesAuthority<GameState>,
+    game_state_weights_calculator: &'a dyn GameStateWeightsCalculator<GameState>,
+    player_index: i32,
+    search_limit: MinimaxSearchLimit,
+}
+
+impl<'a, GameState: BasicGameState> MinimaxTurnTaker<'a, GameState> {
+    pub fn new(
+        game_rules_authority: &'a dyn GameRulesAuthority<GameState>,
+        game_state_weights_calculator: &'a dyn GameStateWeightsCalculator<GameState>,
+        player_index: i32,
+        search_limit: MinimaxSearchLimit,
+    ) -> MinimaxTurnTaker<'a, GameState> {
+        return MinimaxTurnTaker {
+            game_rules_authority: game_rules_authority,
+            game_state_weights_calculator: game_state_weights_calculator,
+            player_index: player_index,
+            search_limit: search_limit,
+        };
+    }
+
+    /// Scores every root move to the given depth, following the given order.
+    /// Returns None if the search was aborted before completing.
+    fn search_root(
+        &self,
+        available_next_game_states: &Vec<GameState>,
+        static_weights: &Vec<f32>,
+        ordered_indices: &Vec<usize>,
+        depth: u32,
+        search_context: &mut SearchContext,
+    ) -> Option<Vec<(usize, f32)>> {
+        let mut scored_indices: Vec<(usize, f32)> = vec![];
+        let mut alpha = f32::MIN;
+
+        for i in ordered_indices.iter() {
+            let score = self.score_game_state(
+                &available_next_game_states[*i],
+                static_weights[*i],
+                self.player_index,
+                depth - 1,
+                alpha,
+                f32::MAX,
+                1,
+                search_context,
+            );
+            if search_context.is_aborted {
+                return None;
+            }
+
+            scored_indices.push((*i, score));
+            if score > alpha {
+                alpha = score;
+            }
+        }
+
+        return Some(scored_indices);
+    }
+
+    /// Scores a game state from the perspective of the player responsible for reaching it.
+    fn score_game_state(
+        &self,
+        game_state: &GameState,
+        static_weight: f32,
+        responsible_player_index: i32,
+        remaining_depth: u32,
+        alpha: f32,
+        beta: f32,
+        ply: u32,
+        search_context: &mut SearchContext,
+    ) -> f32 {
+        let next_player_index = get_next_player_index(responsible_player_index);
+
+        match self
+            .game_rules_authority
+            .analyze_game_state_for_terminality(game_state, next_player_index)
+        {
+            None => (),
+            Some(-1) => return 0.0,
+            Some(winning_player_index) => {
+                let win_score = WIN_SCORE - ply as f32;
+                return if winning_player_index == responsible_player_index {
+                    win_score
+                } else {
+                    -win_score
+                };
+            }
+        }
+
+        if remaining_depth == 0 {
+            return static_weight;
+        }
+
+        return -self.negamax(
+            game_state,
+            next_player_index,
+            remaining_depth,
+            -beta,
+            -alpha,
+            ply,
+            search_context,
+        );
+    }
+
+    /// Scores a game state from the perspective of the player about to move.
+    fn negamax(
+        &self,
+        game_state: &GameState,
+        current_player_index: i32,
+        depth: u32,
+        mut alpha: f32,
+        beta: f32,
+        ply: u32,
+        search_context: &mut SearchContext,
+    ) -> f32 {
+        if search_context.should_abort() {
+            return 0.0;
+        }
+
+        let chance_outcomes = self
+            .game_rules_authority
+            .find_chance_outcomes(current_player_index, game_state);
+        if !chance_outcomes.is_empty() {
+            // The player only gets to move once the chance event has played out, so the score is the expected score of its outcomes.
+            // Bounds found for one outcome say nothing of the others, so each outcome is searched with a full window
+            let mut expected_score = 0.0;
+            for (chance_outcome, probability) in chance_outcomes.iter() {
+                let score = self.negamax(
+                    chance_outcome,
+                    current_player_index,
+                    depth,
+                    f32::MIN,
+                    f32::MAX,
+                    ply,
+                    search_context,
+                );
+                if search_context.is_aborted {
+                    return 0.0;
+                }
+
+                expected_score += probability * score;
+            }
+
+            return expected_score;
+        }
+
+        let available_next_game_states = self
+            .game_rules_authority
+            .find_available_next_game_states(current_player_index, game_state);
+        if available_next_game_states.is_empty() {
+            if self
+                .game_rules_authority
+                .is_passing_forced(current_player_index, game_state)
+            {
+                // Passing leaves nothing to choose from, so it doesn't use up any of the depth
+                return -self.negamax(
+                    game_state,
+                    get_next_player_index(current_player_index),
+                    depth,
+                    -beta,
+                    -alpha,
+                    ply + 1,
+                    search_context,
+                );
+            }
+
+            // Not terminal, yet no moves are available - nothing better to do than to call it even
+            return 0.0;
+        }
+
+        let static_weights = self
+            .game_state_weights_calculator
+            .weigh_game_states(current_player_index, &available_next_game_states);
+
+        let mut best_score = f32::MIN;
+        for i in order_indices_by_descending_weight(&static_weights).iter() {
+            let score = self.score_game_state(
+                &available_next_game_states[*i],
+                static_weights[*i],
+                current_player_index,
+                depth - 1,
+                alpha,
+                beta,
+                ply + 1,
+                search_context,
+            );
+            if search_context.is_aborted {
+                return 0.0;
+            }
+
+            if score > best_score {
+                best_score = score;
+            }
+            if score > alpha {
+                alpha = score;
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        return best_score;
+    }
+}
+
+impl<'a, GameState: BasicGameState> TurnTaker<GameState> for MinimaxTurnTaker<'a, GameState> {
+    fn decide_next_game_state(
+        &self,
+        current_game_state: &GameState,
+    ) -> Result<GameState, DecideNextStateError> {
+        let available_next_game_states = self
+            .game_rules_authority
+            .find_available_next_game_states(self.player_index, &current_game_state);
+
+        if available_next_game_states.is_empty() {
+            return Err(DecideNextStateError::NoAvailableStatesError);
+        }
+
+        let static_weights = self
+            .game_state_weights_calculator
+            .weigh_game_states(self.player_index, &available_next_game_states);
+        let mut ordered_indices = order_indices_by_descending_weight(&static_weights);
+
+        let (max_depth, deadline) = match self.search_limit {
+            MinimaxSearchLimit::FixedDepth(depth) => (depth.max(1), None),
+            MinimaxSearchLimit::IterativeDeepening {
+                max_depth,
+                time_budget,
+            } => (max_depth.max(1), Some(Instant::now() + time_budget)),
+        };
+        let first_depth = if deadline.is_some() { 1 } else { max_depth };
+
+        for depth in first_depth..max_depth + 1 {
+            let mut search_context = SearchContext {
+                // the shallowest search always completes so that there is a result to fall back on
+                deadline: if depth == first_depth { None } else { deadline },
+                is_aborted: false,
+            };
+
+            match self.search_root(
+                &available_next_game_states,
+                &static_weights,
+                &ordered_indices,
+                depth,
+                &mut search_context,
+            ) {
+                Some(mut scored_indices) => {
+                    // stable sort so that equally scored moves keep the order of the previous iteration
+                    scored_indices.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+                    ordered_indices = scored_indices.iter().map(|(i, _)| *i).collect();
+
+                    if scored_indices[0].1.abs() >= WIN_SCORE - depth as f32 {
+                        // the outcome is already decided within the searched depth so searching deeper won't help
+                        break;
+                    }
+                }
+                None => break,
+            }
+        }
+
+        return Ok(available_next_game_states[ordered_indices[0]].clone());
+    }
+}
+
+fn get_next_player_index(player_index: i32) -> i32 {
+    return (player_index + 1) % 2;
+}
+
+fn order_indices_by_descending_weight(weights: &Vec<f32>) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..weights.len()).collect();
+    indices.sort_by(|a, b| {
+        weights[*b]
+            .partial_cmp(&weights[*a])
+            .unwrap_or(Ordering::Equal)
+    });
+    return indices;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::simplified_backgammon::{
+        GameRulesAuthority as SimplifiedBackgammonGameRulesAuthority,
+        GameStateType as SimplifiedBackgammonGameState,
+    };
+    use crate::games::tic_tac_toe::{
+        create_initial_game_state, GameRulesAuthority as TicTacToeGameRulesAuthority,
+    };
+    use std::time::Duration;
+
+    /// Leaves every game state at an even score, so that only the outcomes found by the search tell the moves apart.
+    struct ZeroGameStateWeightsCalculator {}
+
+    impl<GameState: BasicGameState> GameStateWeightsCalculator<GameState>
+        for ZeroGameStateWeightsCalculator
+    {
+        fn weigh_game_states(
+            &self,
+            _responsible_player_index: i32,
+            game_states: &Vec<GameState>,
+        ) -> Vec<f32> {
+            return vec![0.0; game_states.len()];
+        }
+    }
+
+    #[test]
+    fn test_winning_in_one_and_in_two() {
+        let game_rules_authority = TicTacToeGameRulesAuthority {};
+        let weights_calculator = ZeroGameStateWeightsCalculator {};
+
+        // X completes the top row
+        let turn_taker = MinimaxTurnTaker::new(
+            &game_rules_authority,
+            &weights_calculator,
+            0,
+            MinimaxSearchLimit::FixedDepth(1),
+        );
+        let game_state = vec![vec![1, 1, 0], vec![2, 2, 0], vec![0, 0, 0]];
+        let next_game_state = turn_taker.decide_next_game_state(&game_state).unwrap();
+        assert_eq!(next_game_state[0][2], 1);
+
+        // Only taking the center threatens both the middle row and the middle column, which O can't both block
+        let turn_taker = MinimaxTurnTaker::new(
+            &game_rules_authority,
+            &weights_calculator,
+            0,
+            MinimaxSearchLimit::FixedDepth(3),
+        );
+        let game_state = vec![vec![0, 0, 0], vec![0, 0, 1], vec![2, 1, 2]];
+        let next_game_state = turn_taker.decide_next_game_state(&game_state).unwrap();
+        assert_eq!(next_game_state[1][1], 1);
+    }
+
+    #[test]
+    fn test_preferring_faster_wins() {
+        let game_rules_authority = TicTacToeGameRulesAuthority {};
+        let weights_calculator = ZeroGameStateWeightsCalculator {};
+        let turn_taker = MinimaxTurnTaker::new(
+            &game_rules_authority,
+            &weights_calculator,
+            0,
+            MinimaxSearchLimit::FixedDepth(3),
+        );
+
+        // X wins right away by completing the middle row, or a move later by blocking the bottom row
+        let game_state = vec![vec![0, 0, 0], vec![0, 1, 1], vec![0, 2, 2]];
+        let winning_now_game_state = vec![vec![0, 0, 0], vec![1, 1, 1], vec![0, 2, 2]];
+        let winning_later_game_state = vec![vec![0, 0, 0], vec![0, 1, 1], vec![1, 2, 2]];
+
+        let mut search_context = SearchContext {
+            deadline: None,
+            is_aborted: false,
+        };
+        let winning_now_score = turn_taker.score_game_state(
+            &winning_now_game_state,
+            0.0,
+            0,
+            2,
+            f32::MIN,
+            f32::MAX,
+            1,
+            &mut search_context,
+        );
+        let winning_later_score = turn_taker.score_game_state(
+            &winning_later_game_state,
+            0.0,
+            0,
+            2,
+            f32::MIN,
+            f32::MAX,
+            1,
+            &mut search_context,
+        );
+        assert_eq!(winning_now_score, WIN_SCORE - 1.0);
+        assert_eq!(winning_later_score, WIN_SCORE - 3.0);
+
+        assert_eq!(
+            turn_taker.decide_next_game_state(&game_state).unwrap(),
+            winning_now_game_state
+        );
+    }
+
+    #[test]
+    fn test_iterative_deepening_out_of_time() {
+        let game_rules_authority = TicTacToeGameRulesAuthority {};
+        let weights_calculator = ZeroGameStateWeightsCalculator {};
+        let turn_taker = MinimaxTurnTaker::new(
+            &game_rules_authority,
+            &weights_calculator,
+            0,
+            MinimaxSearchLimit::IterativeDeepening {
+                max_depth: 9,
+                time_budget: Duration::from_millis(0),
+            },
+        );
+
+        // The shallowest search completes even without any time left, so a legal move is still picked
+        let game_state = create_initial_game_state();
+        let next_game_state = turn_taker.decide_next_game_state(&game_state).unwrap();
+        assert!(game_rules_authority
+            .find_available_next_game_states(0, &game_state)
+            .contains(&next_game_state));
+    }
+
+    #[test]
+    fn test_scoring_chance_events_by_expectation() {
+        let game_rules_authority = SimplifiedBackgammonGameRulesAuthority {};
+        let weights_calculator = ZeroGameStateWeightsCalculator {};
+        let turn_taker = MinimaxTurnTaker::new(
+            &game_rules_authority,
+            &weights_calculator,
+            0,
+            MinimaxSearchLimit::FixedDepth(3),
+        );
+
+        // Each player has a single checker left to bear off. The first player bears it off with a roll of 3 or more,
+        // otherwise the second player bears theirs off with any roll
+        let mut points = [0; 12];
+        points[9] = 1;
+        points[0] = -1;
+        let game_state = SimplifiedBackgammonGameState {
+            points: points,
+            bar_counts: [0, 0],
+            borne_off_counts: [4, 4],
+            die_roll: 0,
+        };
+
+        let mut search_context = SearchContext {
+            deadline: None,
+            is_aborted: false,
+        };
+        let score = turn_taker.negamax(
+            &game_state,
+            0,
+            3,
+            f32::MIN,
+            f32::MAX,
+            0,
+            &mut search_context,
+        );
+        let expected_score = (4.0 * (WIN_SCORE - 1.0) - 2.0 * (WIN_SCORE - 2.0)) / 6.0;
+        assert!((score - expected_score).abs() < 0.5);
+    }
+}
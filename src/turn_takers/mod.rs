@@ -4,5 +4,11 @@ pub use best_weight_selection_turn_taker::BestWeightSelectionTurnTaker;
 mod cli_input_player_turn_taker;
 pub use cli_input_player_turn_taker::CLIInputPlayerTurnTaker;
 
+mod mcts_turn_taker;
+pub use mcts_turn_taker::MctsTurnTaker;
+
+mod minimax_turn_taker;
+pub use minimax_turn_taker::MinimaxTurnTaker;
+
 mod weighted_random_selection_turn_taker;
 pub use weighted_random_selection_turn_taker::WeightedRandomSelectionTurnTaker;
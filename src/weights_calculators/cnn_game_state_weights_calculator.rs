@@ -33,17 +33,23 @@ impl<'a, GameState: BasicGameState> GameStateWeightsCalculator<GameState>
         responsible_player_index: i32,
         game_states: &Vec<GameState>,
     ) -> Vec<f32> {
-        let mut weights: Vec<f32> = vec![];
-
-        for game_state in game_states.iter() {
-            let game_state_tensor =
-                (self.transform_game_state_to_tensor)(responsible_player_index, game_state);
-            let result_tensor = self.torch_net.forward(&game_state_tensor.to(self.device));
-            let weight = result_tensor.double_value(&[0]) as f32;
-
-            weights.push(weight);
+        if game_states.is_empty() {
+            return vec![];
         }
 
-        return weights;
+        // All game states go through the net as a single batch
+        let game_state_tensors: Vec<Tensor> = game_states
+            .iter()
+            .map(|game_state| {
+                (self.transform_game_state_to_tensor)(responsible_player_index, game_state)
+            })
+            .collect();
+        let result_tensor = tch::no_grad(|| {
+            return self
+                .torch_net
+                .forward(&Tensor::stack(&game_state_tensors, 0).to(self.device));
+        });
+
+        return Vec::<f32>::from(&result_tensor.view([-1]).to(Device::Cpu));
     }
 }
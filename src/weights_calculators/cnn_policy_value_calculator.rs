@@ -0,0 +1,115 @@
+use crate::traits::{
+    BasicGameState, GameStateWeightsCalculator, PolicyValueCalculator, PolicyValueNet,
+};
+use tch::{Device, Tensor};
+
+/// Calculates move priors and position values with a single pass of a policy and value network.
+/// As a weights calculator, it weighs each game state by negating its value for the opponent about to move,
+/// so it assumes a two player game.
+pub struct CnnPolicyValueCalculator<'a, GameState: BasicGameState> {
+    device: Device,
+    find_move_policy_index: &'a dyn Fn(i32, &GameState, &GameState) -> Option<usize>,
+    policy_value_net: &'a dyn PolicyValueNet,
+    transform_game_state_to_tensor: &'a dyn Fn(i32, &GameState) -> Tensor,
+}
+
+impl<'a, GameState: BasicGameState> CnnPolicyValueCalculator<'a, GameState> {
+    pub fn new(
+        device: Device,
+        find_move_policy_index: &'a dyn Fn(i32, &GameState, &GameState) -> Option<usize>,
+        policy_value_net: &'a dyn PolicyValueNet,
+        transform_game_state_to_tensor: &'a dyn Fn(i32, &GameState) -> Tensor,
+    ) -> CnnPolicyValueCalculator<'a, GameState> {
+        return CnnPolicyValueCalculator {
+            device: device,
+            find_move_policy_index: find_move_policy_index,
+            policy_value_net: policy_value_net,
+            transform_game_state_to_tensor: transform_game_state_to_tensor,
+        };
+    }
+}
+
+impl<'a, GameState: BasicGameState> PolicyValueCalculator<GameState>
+    for CnnPolicyValueCalculator<'a, GameState>
+{
+    fn calculate_policy_and_value(
+        &self,
+        current_player_index: i32,
+        current_game_state: &GameState,
+        available_next_game_states: &Vec<GameState>,
+    ) -> (Vec<f32>, f32) {
+        let game_state_tensor =
+            (self.transform_game_state_to_tensor)(current_player_index, current_game_state);
+        let (policy_tensor, value_tensor) = tch::no_grad(|| {
+            return self
+                .policy_value_net
+                .forward_policy_and_value(&game_state_tensor.to(self.device));
+        });
+
+        let policy_logits = Vec::<f32>::from(&policy_tensor.view([-1]).to(Device::Cpu));
+        let value = value_tensor.double_value(&[0, 0]) as f32;
+
+        // Only the logits of the available moves matter, so the softmax is taken over those alone.
+        // A move missing from the encoding gets a neutral logit so that the search can still explore it.
+        let available_moves_logits: Vec<f32> = available_next_game_states
+            .iter()
+            .map(|available_next_game_state| {
+                match (self.find_move_policy_index)(
+                    current_player_index,
+                    current_game_state,
+                    available_next_game_state,
+                ) {
+                    Some(move_policy_index) => policy_logits[move_policy_index],
+                    None => 0.0,
+                }
+            })
+            .collect();
+
+        let max_logit = available_moves_logits
+            .iter()
+            .cloned()
+            .fold(f32::MIN, f32::max);
+        let exponents: Vec<f32> = available_moves_logits
+            .iter()
+            .map(|logit| (logit - max_logit).exp())
+            .collect();
+        let exponents_sum: f32 = exponents.iter().sum();
+
+        let priors = exponents
+            .iter()
+            .map(|exponent| exponent / exponents_sum)
+            .collect();
+
+        return (priors, value);
+    }
+}
+
+impl<'a, GameState: BasicGameState> GameStateWeightsCalculator<GameState>
+    for CnnPolicyValueCalculator<'a, GameState>
+{
+    fn weigh_game_states(
+        &self,
+        responsible_player_index: i32,
+        game_states: &Vec<GameState>,
+    ) -> Vec<f32> {
+        if game_states.is_empty() {
+            return vec![];
+        }
+
+        let next_player_index = (responsible_player_index + 1) % 2;
+        let game_state_tensors: Vec<Tensor> = game_states
+            .iter()
+            .map(|game_state| (self.transform_game_state_to_tensor)(next_player_index, game_state))
+            .collect();
+        let (_, values_tensor) = tch::no_grad(|| {
+            return self
+                .policy_value_net
+                .forward_policy_and_value(&Tensor::stack(&game_state_tensors, 0).to(self.device));
+        });
+
+        return Vec::<f32>::from(&values_tensor.view([-1]).to(Device::Cpu))
+            .into_iter()
+            .map(|value| -value)
+            .collect();
+    }
+}
@@ -1,5 +1,18 @@
+#[cfg(feature = "torch")]
 mod cnn_game_state_weights_calculator;
+#[cfg(feature = "torch")]
 pub use cnn_game_state_weights_calculator::CnnGameStateWeightsCalculator;
 
+#[cfg(feature = "torch")]
+mod cnn_policy_value_calculator;
+#[cfg(feature = "torch")]
+pub use cnn_policy_value_calculator::CnnPolicyValueCalculator;
+
 mod record_values_weighted_sum_game_state_weights_calculator;
 pub use record_values_weighted_sum_game_state_weights_calculator::RecordValuesWeightedSumGameStateWeightsCalculator;
+
+mod solved_game_state_weights_calculator;
+pub use solved_game_state_weights_calculator::SolvedGameStateWeightsCalculator;
+
+mod uniform_game_state_weights_calculator;
+pub use uniform_game_state_weights_calculator::UniformGameStateWeightsCalculator;
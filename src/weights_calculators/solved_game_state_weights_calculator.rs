@@ -0,0 +1,126 @@
+use crate::traits::{
+    BasicGameState, BasicSerializedGameState, GameRulesAuthority, GameStateSerializer,
+    GameStateWeightsCalculator,
+};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Weighs game states by their game-theoretic values, found by searching the whole game tree below them.
+/// A game state weighs 1 when the player responsible for it wins with perfect play from both players,
+/// -1 when they lose and 0 when the game is drawn, while chance events weigh in with the expected value of their outcomes.
+/// Only two player games in which the players take turns and that are small enough to be enumerated can be solved.
+/// Game states are told to be terminal by analyze_game_state_for_terminality alone, so draws by the history of the game are overlooked.
+/// The values of all the game states searched are remembered by their serialized game states,
+/// so that later weighings only search the game states that haven't been searched yet.
+pub struct SolvedGameStateWeightsCalculator<
+    'a,
+    GameState: BasicGameState,
+    SerializedGameState: BasicSerializedGameState,
+> {
+    game_rules_authority: &'a (dyn GameRulesAuthority<GameState> + Sync),
+    game_state_serializer: &'a (dyn GameStateSerializer<GameState, SerializedGameState> + Sync),
+    game_state_values: Mutex<HashMap<SerializedGameState, f32>>,
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
+    SolvedGameStateWeightsCalculator<'a, GameState, SerializedGameState>
+{
+    pub fn new(
+        game_rules_authority: &'a (dyn GameRulesAuthority<GameState> + Sync),
+        game_state_serializer: &'a (dyn GameStateSerializer<GameState, SerializedGameState> + Sync),
+    ) -> SolvedGameStateWeightsCalculator<'a, GameState, SerializedGameState> {
+        return SolvedGameStateWeightsCalculator {
+            game_rules_authority: game_rules_authority,
+            game_state_serializer: game_state_serializer,
+            game_state_values: Mutex::new(HashMap::new()),
+        };
+    }
+
+    /// Finds the value of the given game state for the player responsible for it by negamax.
+    fn solve_game_state(&self, responsible_player_index: i32, game_state: &GameState) -> f32 {
+        let serialized_game_state = self
+            .game_state_serializer
+            .serialize_game_state(responsible_player_index, game_state);
+        if let Some(value) = self
+            .game_state_values
+            .lock()
+            .unwrap()
+            .get(&serialized_game_state)
+        {
+            return *value;
+        }
+
+        let next_player_index = (responsible_player_index + 1) % 2;
+        let value = match self
+            .game_rules_authority
+            .analyze_game_state_for_terminality(game_state, next_player_index)
+        {
+            Some(-1) => 0.0,
+            Some(winning_player_index) if winning_player_index == responsible_player_index => 1.0,
+            Some(_) => -1.0,
+            None => self.solve_non_terminal_game_state(
+                responsible_player_index,
+                next_player_index,
+                game_state,
+            ),
+        };
+
+        self.game_state_values
+            .lock()
+            .unwrap()
+            .insert(serialized_game_state, value);
+        return value;
+    }
+
+    fn solve_non_terminal_game_state(
+        &self,
+        responsible_player_index: i32,
+        next_player_index: i32,
+        game_state: &GameState,
+    ) -> f32 {
+        let chance_outcomes = self
+            .game_rules_authority
+            .find_chance_outcomes(next_player_index, game_state);
+        if !chance_outcomes.is_empty() {
+            return chance_outcomes
+                .iter()
+                .map(|(chance_outcome, probability)| {
+                    return probability
+                        * self.solve_game_state(responsible_player_index, chance_outcome);
+                })
+                .sum();
+        }
+
+        // the next player passing makes them responsible for the same game state
+        if self
+            .game_rules_authority
+            .is_passing_forced(next_player_index, game_state)
+        {
+            return -self.solve_game_state(next_player_index, game_state);
+        }
+
+        // the next player picks the move that is best for them, which is the worst for the responsible player
+        return -self
+            .game_rules_authority
+            .find_available_next_game_states(next_player_index, game_state)
+            .iter()
+            .map(|next_game_state| self.solve_game_state(next_player_index, next_game_state))
+            .fold(f32::NEG_INFINITY, f32::max);
+    }
+}
+
+impl<'a, GameState: BasicGameState, SerializedGameState: BasicSerializedGameState>
+    GameStateWeightsCalculator<GameState>
+    for SolvedGameStateWeightsCalculator<'a, GameState, SerializedGameState>
+{
+    fn weigh_game_states(
+        &self,
+        responsible_player_index: i32,
+        game_states: &Vec<GameState>,
+    ) -> Vec<f32> {
+        return game_states
+            .iter()
+            .map(|game_state| self.solve_game_state(responsible_player_index, game_state))
+            .collect();
+    }
+}
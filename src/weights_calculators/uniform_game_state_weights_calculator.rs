@@ -0,0 +1,16 @@
+use crate::traits::{BasicGameState, GameStateWeightsCalculator};
+
+/// Gives every game state the same weight, making weighted random selection pick moves uniformly at random.
+pub struct UniformGameStateWeightsCalculator {}
+
+impl<GameState: BasicGameState> GameStateWeightsCalculator<GameState>
+    for UniformGameStateWeightsCalculator
+{
+    fn weigh_game_states(
+        &self,
+        _responsible_player_index: i32,
+        game_states: &Vec<GameState>,
+    ) -> Vec<f32> {
+        return vec![1.0; game_states.len()];
+    }
+}